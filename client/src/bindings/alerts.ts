@@ -0,0 +1,68 @@
+import type { VehicleType } from "$bindings/vehicles";
+
+export enum AlertParameter {
+    IndicatedAirspeed = "IndicatedAirspeed",
+    GroundSpeed = "GroundSpeed",
+    AltitudeAmsl = "AltitudeAmsl",
+    Climb = "Climb",
+    Throttle = "Throttle",
+    AltitudeError = "AltitudeError",
+    AirspeedError = "AirspeedError",
+    XtrackError = "XtrackError",
+    SnsFix = "SnsFix",
+    SatellitesVisible = "SatellitesVisible",
+    BatteryVoltage = "BatteryVoltage",
+    BatteryCurrent = "BatteryCurrent",
+    BatteryRemaining = "BatteryRemaining",
+    RadioRssi = "RadioRssi",
+    RadioRemoteRssi = "RadioRemoteRssi",
+    BatteryEndurance = "BatteryEndurance"
+}
+
+export enum AlertCondition {
+    Less = "Less",
+    Greater = "Greater"
+}
+
+export enum AlertSeverity {
+    Info = "Info",
+    Warning = "Warning",
+    Critical = "Critical"
+}
+
+export interface AlertRuleScope {
+    All?: {};
+    VehicleType?: { vehicle_type: VehicleType };
+    Vehicle?: { vehicle_id: string };
+}
+
+export interface AlertRule {
+    id: string,
+    name: string,
+    scope: AlertRuleScope,
+    parameter: AlertParameter,
+    condition: AlertCondition,
+    threshold: number,
+    duration: number,
+    severity: AlertSeverity,
+    enabled: boolean
+}
+
+export enum AlertState {
+    Raised = "Raised",
+    Acknowledged = "Acknowledged",
+    Cleared = "Cleared"
+}
+
+export interface Alert {
+    id: string,
+    rule_id: string,
+    vehicle_id: string,
+    severity: AlertSeverity,
+    message: string,
+    value: number,
+    state: AlertState,
+    raised: number,
+    acknowledged?: number,
+    cleared?: number
+}
@@ -0,0 +1,20 @@
+import type { Geodetic } from "$bindings/spatial";
+
+export interface CapturedImage {
+    id: string,
+    vehicle_id: string,
+    payload_id: string,
+    mission_id?: string,
+    flight_session_id?: string,
+    timestamp: number,
+
+    index: number,
+    position: Geodetic,
+    relative_altitude: number,
+    roll: number,
+    pitch: number,
+    yaw: number,
+
+    success: boolean,
+    file_url: string
+}
@@ -1,5 +1,7 @@
 import { type Geodetic } from "$bindings/spatial";
-import type { VehicleMode } from "$bindings/vehicles";
+import type { PreflightReport } from "$bindings/preflight";
+import type { CameraMode } from "$bindings/telemetry";
+import type { VehicleFeatures, VehicleMode, VehicleType } from "$bindings/vehicles";
 
 export enum Calibration {
     GroundPressure = "GroundPressure",
@@ -8,6 +10,17 @@ export enum Calibration {
     Temperature = "Temperature",
 }
 
+export enum ParachuteAction {
+    Disable = "Disable",
+    Enable = "Enable",
+    Release = "Release"
+}
+
+export enum VtolTarget {
+    Multicopter = "Multicopter",
+    FixedWing = "FixedWing"
+}
+
 export interface Command {
     ArmDisarm?: { arm: boolean };
     SetMode?: { mode: VehicleMode };
@@ -15,7 +28,7 @@ export interface Command {
     SetHome?: { position: Geodetic };
 
     ReturnToLaunch?: {};
-    NavTo?: { position: Geodetic };
+    NavTo?: { position: Geodetic; loiter_radius?: number; ground_speed?: number; switch_to_guided?: boolean };
 
     SetAltitude?: { altitude: number };
     SetLoiterRadius?: { radius: number };
@@ -32,6 +45,24 @@ export interface Command {
     Takeoff?: { altitude: number };
     Land?: { position: Geodetic, abort_altitude?: number },
     GoAround?: {};
+    VtolTransition?: { to: VtolTarget };
+
+    Parachute?: { action: ParachuteAction };
+    EngineControl?: { start: boolean };
+    FlightTermination?: {};
+
+    CameraTrigger?: {};
+    CameraRecord?: { record: boolean };
+    StartImageCapture?: { interval: number, total_images: number };
+    StopImageCapture?: {};
+    SetCameraMode?: { mode: CameraMode };
+    RequestCameraInformation?: {};
+    SetGimbalPitchYaw?: { pitch: number, yaw: number };
+    SetGimbalRates?: { pitch_rate: number, yaw_rate: number };
+    SetGimbalRoi?: { position: Geodetic };
+    ResetGimbalRoi?: {};
+    GimbalRetract?: {};
+    GimbalNeutral?: {};
 }
 
 export interface CommandState {
@@ -51,6 +82,24 @@ export interface CommandExecutor {
     Payload?: { vehicle_id: string, payload_id: string };
 }
 
+export interface CommandRejection {
+    VehicleNotFound?: { vehicle_id: string };
+    PayloadNotFound?: { payload_id: string };
+    ModeUnavailable?: { mode: VehicleMode };
+    ModeUnsupported?: { mode: VehicleMode, vehicle_type: VehicleType };
+    CommandUnsupported?: { vehicle_type: VehicleType };
+    FeatureMissing?: { feature: VehicleFeatures };
+    ConfirmationRequired?: {};
+    ConfirmationInvalid?: { token: string };
+    PreflightFailed?: { report: PreflightReport };
+}
+
+export interface CommandConfirmation {
+    id: string,
+    request: ExecuteCommandRequest,
+    expires: number
+}
+
 export interface ExecuteCommandRequest {
     command: Command,
     executor: CommandExecutor
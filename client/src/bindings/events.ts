@@ -1,8 +1,19 @@
 import type { LinkDescription, LinkStatus } from "$bindings/communication";
 import type { VehicleDescription, VehicleStatus } from "$bindings/vehicles";
-import type { Flight, Navigation, RawSns, System } from "$bindings/telemetry";
+import type { VehicleMessage } from "$bindings/messages";
+import type { Payload } from "$bindings/payloads";
+import type { PreflightReport, PreflightSettings } from "$bindings/preflight";
+import type { MissionValidationReport, MissionValidationSettings } from "$bindings/mission_validation";
+import type { MissionPattern } from "$bindings/mission_patterns";
+import type { Alert, AlertRule } from "$bindings/alerts";
+import type { FailsafeEvent, FailsafeStatus } from "$bindings/failsafe";
+import type { FlightSession } from "$bindings/flights";
+import type { MaintenanceEntry, MaintenanceInterval, VehicleCounters } from "$bindings/maintenance";
+import type { Battery, Camera, Engine, Flight, Gimbal, Navigation, RawSns, System } from "$bindings/telemetry";
 import type { CommandExecution } from "$bindings/commands";
-import type { Mission, MissionStatus, MissionRoute, MissionRouteItem } from "$bindings/mission";
+import type { Mission, MissionPlan, MissionRevision, MissionStatus, MissionRoute, MissionRouteItem } from "$bindings/mission";
+import type { CapturedImage } from "$bindings/captures";
+import type { TerrainReport } from "$bindings/terrain";
 
 export interface ServerEvent {
     // Communication
@@ -14,12 +25,51 @@ export interface ServerEvent {
     VehicleUpserted?: { vehicle: VehicleDescription };
     VehicleRemoved?: { vehicle_id: string };
     VehicleStatusUpdated?: { status: VehicleStatus };
+    VehicleMessageAdded?: { message: VehicleMessage };
+    VehicleMessagesCleared?: { vehicle_id: string };
+
+    // Preflight
+    PreflightSettingsUpdated?: { settings: PreflightSettings };
+    PreflightReportUpdated?: { report: PreflightReport };
+
+    // Alerts
+    AlertRuleUpserted?: { rule: AlertRule };
+    AlertRuleRemoved?: { rule_id: string };
+    AlertRaised?: { alert: Alert };
+    AlertAcknowledged?: { alert: Alert };
+    AlertCleared?: { alert: Alert };
+
+    // Failsafe
+    FailsafeStatusUpdated?: { status: FailsafeStatus };
+    FailsafeTriggered?: { event: FailsafeEvent };
+
+    // Flights
+    FlightSessionUpdated?: { session: FlightSession };
+    FlightSessionRemoved?: { session_id: string };
+
+    // Maintenance
+    VehicleCountersUpdated?: { counters: VehicleCounters };
+    MaintenanceIntervalUpserted?: { interval: MaintenanceInterval };
+    MaintenanceIntervalRemoved?: { interval_id: string };
+    MaintenanceEntryAdded?: { entry: MaintenanceEntry };
+    MaintenanceEntryRemoved?: { entry_id: string };
+
+    // Payloads
+    PayloadUpserted?: { payload: Payload };
+    PayloadRemoved?: { payload_id: string };
 
     // Telemetry
     FlightUpdated?: { vehicle_id: string, flight: Flight };
     NavigationUpdated?: { vehicle_id: string, navigation: Navigation };
     RawSnsUpdated?: { vehicle_id: string, raw_sns: RawSns };
     SystemUpdated?: { vehicle_id: string, system: System };
+    BatteryUpdated?: { vehicle_id: string, battery: Battery };
+    EngineUpdated?: { vehicle_id: string, engine: Engine };
+    GimbalUpdated?: { vehicle_id: string, gimbal: Gimbal };
+    CameraUpdated?: { vehicle_id: string, camera: Camera };
+
+    // Terrain
+    TerrainReportUpdated?: { report: TerrainReport };
 
     // Commands
     CommandExecutionUpserted?: { execution: CommandExecution };
@@ -32,4 +82,16 @@ export interface ServerEvent {
     MissionRouteUpdated?: { route: MissionRoute };
     MissionRouteItemUpserted?: { mission_id: string, index: number, item: MissionRouteItem };
     MissionRouteItemRemoved?: { mission_id: string, index: number };
+    MissionRevisionUpserted?: { revision: MissionRevision };
+    MissionRevisionRemoved?: { mission_id: string, revision_id: string };
+    MissionPlanUpserted?: { plan: MissionPlan };
+    MissionPlanRemoved?: { plan_id: string };
+    MissionPatternUpserted?: { pattern: MissionPattern };
+    MissionPatternRemoved?: { pattern_id: string };
+    MissionValidationSettingsUpdated?: { settings: MissionValidationSettings };
+    MissionValidationReportUpdated?: { report: MissionValidationReport };
+
+    // Captures
+    ImageCaptured?: { image: CapturedImage };
+    CapturedImageRemoved?: { capture_id: string };
 }
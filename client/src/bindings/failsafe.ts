@@ -0,0 +1,33 @@
+import type { Command, CommandRejection } from "$bindings/commands";
+
+export enum FailsafeTrigger {
+    LinkLoss = "LinkLoss",
+    LowBattery = "LowBattery"
+}
+
+export interface FailsafePolicy {
+    enabled: boolean,
+    delay: number,
+    link_loss_timeout: number,
+    link_loss_action?: Command,
+    battery_threshold: number,
+    battery_hysteresis: number,
+    battery_action?: Command
+}
+
+export interface FailsafeStatus {
+    id: string,
+    overridden: boolean,
+    triggered: Array<FailsafeTrigger>
+}
+
+export interface FailsafeEvent {
+    id: string,
+    vehicle_id: string,
+    timestamp: number,
+    trigger: FailsafeTrigger,
+    command?: Command,
+    command_id?: string,
+    overridden: boolean,
+    rejection?: CommandRejection
+}
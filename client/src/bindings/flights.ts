@@ -0,0 +1,16 @@
+import type { Geodetic } from "$bindings/spatial";
+
+export interface FlightSession {
+    id: string,
+    vehicle_id: string,
+    mission_id?: string,
+
+    takeoff_time: number,
+    landing_time?: number,
+    duration: number,
+    distance: number,
+    max_altitude: number,
+
+    takeoff_position: Geodetic,
+    landing_position?: Geodetic
+}
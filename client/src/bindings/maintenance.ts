@@ -0,0 +1,41 @@
+export interface VehicleCounters {
+    id: string,
+    flight_time: number,
+    cycles: number,
+    arm_time: number
+}
+
+export interface MaintenanceInterval {
+    id: string,
+    vehicle_id: string,
+    name: string,
+    flight_hours?: number,
+    cycles?: number,
+    days?: number,
+    created: number
+}
+
+export interface MaintenanceEntry {
+    id: string,
+    vehicle_id: string,
+    interval_id?: string,
+    timestamp: number,
+    notes: string,
+    flight_time: number,
+    cycles: number
+}
+
+export enum MaintenanceState {
+    Due = "Due",
+    Overdue = "Overdue"
+}
+
+export interface MaintenanceNotice {
+    interval_id: string,
+    vehicle_id: string,
+    name: string,
+    state: MaintenanceState,
+    remaining_hours?: number,
+    remaining_cycles?: number,
+    remaining_days?: number
+}
@@ -0,0 +1,18 @@
+export enum MessageSeverity {
+    Emergency = "Emergency",
+    Alert = "Alert",
+    Critical = "Critical",
+    Error = "Error",
+    Warning = "Warning",
+    Notice = "Notice",
+    Info = "Info",
+    Debug = "Debug"
+}
+
+export interface VehicleMessage {
+    id: string,
+    vehicle_id: string,
+    timestamp: number,
+    severity: MessageSeverity,
+    text: string
+}
@@ -6,9 +6,27 @@ export enum MissionRouteItemType {
     Takeoff = "Takeoff",
     LandStart = "LandStart",
     Landing = "Landing",
+    VtolTakeoff = "VtolTakeoff",
+    VtolLand = "VtolLand",
+    ReturnToLaunch = "ReturnToLaunch",
     LoiterTrn = "LoiterTrn",
     LoiterAlt = "LoiterAlt",
+    Delay = "Delay",
     TriggerCam = "TriggerCam",
+    DoSetRoi = "DoSetRoi",
+    RoiNone = "RoiNone",
+    DoGimbalControl = "DoGimbalControl",
+    DoChangeSpeed = "DoChangeSpeed",
+    DoJump = "DoJump",
+    ConditionYaw = "ConditionYaw",
+    DoSetServo = "DoSetServo",
+}
+
+export enum SpeedType {
+    Airspeed = "Airspeed",
+    Groundspeed = "Groundspeed",
+    ClimbSpeed = "ClimbSpeed",
+    DescentSpeed = "DescentSpeed"
 }
 
 export interface MissionRouteItem {
@@ -27,6 +45,18 @@ export interface MissionRouteItem {
     distance?: number;
     shutter?: number;
     trigger?: boolean;
+    approach_altitude?: number | null;
+    seconds?: number;
+    roll?: number;
+    speed_type?: SpeedType;
+    speed?: number;
+    throttle?: number | null;
+    target?: number;
+    repeat?: number;
+    angle?: number;
+    relative?: boolean;
+    servo?: number;
+    pwm?: number;
 }
 
 export interface MissionRoute {
@@ -34,6 +64,40 @@ export interface MissionRoute {
     items: MissionRouteItem[];
 }
 
+export interface RouteEdit {
+    Replace?: { items: MissionRouteItem[] };
+    Insert?: { index: number, items: MissionRouteItem[] };
+    Move?: { from: number, count: number, to: number };
+    Reorder?: { order: number[] };
+    Duplicate?: { from: number, count: number, to: number };
+}
+
+export interface RouteItemChange {
+    Added?: { index: number, item: MissionRouteItem };
+    Removed?: { index: number, item: MissionRouteItem };
+    Changed?: { index: number, before: MissionRouteItem, after: MissionRouteItem };
+}
+
+export interface RouteDiff {
+    changes: RouteItemChange[];
+}
+
+export enum RevisionAuthor {
+    Operator = "Operator",
+    Vehicle = "Vehicle"
+}
+
+export interface MissionRevision {
+    id: string;
+    mission_id: string;
+    number: number;
+    timestamp: number;
+    author: RevisionAuthor;
+    items: MissionRouteItem[];
+    diff: RouteDiff;
+    undone: boolean;
+}
+
 export interface MissionUpdateState {
     NotActual?: {};
     PrepareDownload?: {};
@@ -55,9 +119,26 @@ export interface MissionStatus {
     progress: MissionProgress;
 }
 
+export interface MissionAssignment {
+    id: string;
+    mission_id: string;
+    vehicle_id: string;
+    plan_id?: string;
+    active: boolean;
+}
+
 export interface Mission {
     id: string;
     vehicle_id: string;
+    plan_id?: string;
+    active: boolean;
     route: MissionRoute;
     status: MissionStatus;
 }
+
+export interface MissionPlan {
+    id: string;
+    name: string;
+    items: MissionRouteItem[];
+    updated: number;
+}
@@ -0,0 +1,77 @@
+import type { MissionRouteItem } from "$bindings/mission";
+import type { Geodetic, GeodeticFrame } from "$bindings/spatial";
+
+export interface SurveyCamera {
+    sensor_width: number;
+    sensor_height: number;
+    focal_length: number;
+    image_width: number;
+    image_height: number;
+}
+
+export interface SurveyResolution {
+    Gsd?: { gsd: number };
+    Altitude?: { altitude: number };
+}
+
+export interface SurveyRequest {
+    polygon: Geodetic[];
+    camera: SurveyCamera;
+    resolution: SurveyResolution;
+    frame: GeodeticFrame;
+    front_overlap: number;
+    side_overlap: number;
+    heading: number;
+    turnaround: number;
+    entry?: Geodetic | null;
+    exit?: Geodetic | null;
+}
+
+export interface CorridorRequest {
+    path: Geodetic[];
+    width: number;
+    camera: SurveyCamera;
+    resolution: SurveyResolution;
+    frame: GeodeticFrame;
+    front_overlap: number;
+    side_overlap: number;
+    turnaround: number;
+    entry?: Geodetic | null;
+    exit?: Geodetic | null;
+}
+
+export interface StructureRequest {
+    center: Geodetic;
+    radius: number;
+    bottom: number;
+    top: number;
+    camera: SurveyCamera;
+    front_overlap: number;
+    side_overlap: number;
+    clockwise: boolean;
+    entry?: Geodetic | null;
+}
+
+export interface PatternParameters {
+    Survey?: { request: SurveyRequest };
+    Corridor?: { request: CorridorRequest };
+    Structure?: { request: StructureRequest };
+}
+
+export interface GeneratedPattern {
+    items: MissionRouteItem[];
+    altitude: number;
+    gsd: number;
+    line_spacing: number;
+    trigger_distance: number;
+    lines: number;
+    length: number;
+    images: number;
+}
+
+export interface MissionPattern {
+    id: string;
+    mission_id: string;
+    parameters: PatternParameters;
+    items: MissionRouteItem[];
+}
@@ -0,0 +1,51 @@
+import type { VehicleType } from "$bindings/vehicles";
+
+export interface MissionValidationSettings {
+    id: string,
+    vehicle_type: VehicleType,
+    blocking: boolean,
+
+    min_altitude: number,
+    max_leg_length: number,
+    cruise_speed: number,
+    cruise_power: number,
+    battery_energy: number,
+    battery_reserve: number
+}
+
+export enum MissionIssueKind {
+    EmptyRoute = "EmptyRoute",
+    NoTakeoff = "NoTakeoff",
+    NoLanding = "NoLanding",
+    Gap = "Gap",
+    MixedFrames = "MixedFrames",
+    LowAltitude = "LowAltitude",
+    LongLeg = "LongLeg",
+    InsufficientEnergy = "InsufficientEnergy"
+}
+
+export enum MissionIssueSeverity {
+    Warning = "Warning",
+    Error = "Error"
+}
+
+export interface MissionIssue {
+    kind: MissionIssueKind,
+    severity: MissionIssueSeverity,
+    index?: number,
+    description: string
+}
+
+export interface MissionEstimate {
+    distance: number,
+    duration: number,
+    energy: number
+}
+
+export interface MissionValidationReport {
+    id: string,
+    timestamp: number,
+    issues: Array<MissionIssue>,
+    estimate: MissionEstimate,
+    passed: boolean
+}
@@ -0,0 +1,19 @@
+export enum PayloadType {
+    Unknown = "Unknown",
+    Camera = "Camera",
+    Gimbal = "Gimbal"
+}
+
+export interface MavlinkComponentId { comp_id: number }
+
+export type PayloadProtocolId = {
+    MavlinkComponent?: MavlinkComponentId
+};
+
+export interface Payload {
+    id: string,
+    vehicle_id: string,
+    name: string,
+    payload_type: PayloadType,
+    protocol_id: PayloadProtocolId
+}
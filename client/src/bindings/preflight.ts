@@ -0,0 +1,36 @@
+import type { VehicleType } from "$bindings/vehicles";
+
+export enum PreflightCheck {
+    Sensors = "Sensors",
+    ArmReady = "ArmReady",
+    Battery = "Battery",
+    SnsFix = "SnsFix",
+    HomePosition = "HomePosition",
+    MissionActual = "MissionActual",
+    LinkOnline = "LinkOnline"
+}
+
+export interface PreflightSettings {
+    id: string,
+    vehicle_type: VehicleType,
+    checks: Array<PreflightCheck>,
+    blocking: boolean,
+
+    min_battery_voltage: number,
+    min_battery_remaining: number,
+    min_sns_fix: number,
+    min_satellites: number
+}
+
+export interface PreflightCheckResult {
+    check: PreflightCheck,
+    passed: boolean,
+    reason?: string
+}
+
+export interface PreflightReport {
+    id: string,
+    timestamp: number,
+    results: Array<PreflightCheckResult>,
+    passed: boolean
+}
@@ -19,3 +19,26 @@ export interface Geodetic {
 }
 
 export const nullGeodetic = { latitude: NaN, longitude: NaN, altitude: 0, frame: GeodeticFrame.None }
+
+export interface Ned {
+    north: number,
+    east: number,
+    down: number
+}
+
+export interface Enu {
+    east: number,
+    north: number,
+    up: number
+}
+
+export interface Measure {
+    distance: number,
+    bearing: number,
+    offset: Ned
+}
+
+export interface TrackOffset {
+    cross_track: number,
+    along_track: number
+}
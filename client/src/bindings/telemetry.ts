@@ -26,6 +26,8 @@ export interface Navigation {
     position: Geodetic,
     target_position: Geodetic,
     home_position: Geodetic,
+    relative_altitude: number,
+    terrain_altitude?: number,
 
     desired_pitch: number,
     desired_roll: number,
@@ -90,3 +92,113 @@ export interface System {
     radio_rssi: number,
     radio_remote_rssi: number,
 }
+
+export enum GimbalFailure {
+    AtRollLimit = "AtRollLimit",
+    AtPitchLimit = "AtPitchLimit",
+    AtYawLimit = "AtYawLimit",
+    Encoder = "Encoder",
+    Power = "Power",
+    Motor = "Motor",
+    Software = "Software",
+    Comms = "Comms",
+    Calibrating = "Calibrating",
+    NoManager = "NoManager"
+}
+
+export interface Gimbal {
+    id: string,
+    timestamp: number,
+
+    pitch: number,
+    roll: number,
+    yaw: number,
+
+    pitch_rate: number,
+    roll_rate: number,
+    yaw_rate: number,
+
+    yaw_in_earth_frame: boolean,
+    retracted: boolean,
+    neutral: boolean,
+    failures: Array<GimbalFailure>,
+
+    primary_control_sysid: number,
+    primary_control_compid: number,
+}
+
+export enum CameraMode {
+    Unknown = "Unknown",
+    Image = "Image",
+    Video = "Video",
+    Survey = "Survey"
+}
+
+export interface Camera {
+    id: string,
+    timestamp: number,
+
+    vendor_name: string,
+    model_name: string,
+    firmware_version: number,
+    focal_length: number,
+    sensor_size_h: number,
+    sensor_size_v: number,
+    resolution_h: number,
+    resolution_v: number,
+    can_capture_image: boolean,
+    can_capture_video: boolean,
+
+    mode: CameraMode,
+    image_capturing: boolean,
+    image_interval: number,
+    image_count: number,
+    video_recording: boolean,
+    recording_time_ms: number,
+    available_capacity: number,
+}
+
+export enum BatteryFunction {
+    Unknown = "Unknown",
+    All = "All",
+    Propulsion = "Propulsion",
+    Avionics = "Avionics",
+    Payload = "Payload"
+}
+
+export interface Battery {
+    id: string,
+    timestamp: number,
+    vehicle_id: string,
+    battery_id: number,
+    function: BatteryFunction,
+
+    voltage: number,
+    cell_voltages: Array<number>,
+    current?: number,
+    consumed_mah?: number,
+    consumed_energy?: number,
+    temperature?: number,
+    remaining?: number,
+    time_remaining?: number,
+
+    consumption_rate?: number,
+    estimated_endurance?: number,
+}
+
+export interface Engine {
+    id: string,
+    timestamp: number,
+
+    healthy: boolean,
+    ignition: boolean,
+    running: boolean,
+    rpm: number,
+    engine_load: number,
+    throttle_position: number,
+    cylinder_head_temperature: number,
+    exhaust_gas_temperature: number,
+    fuel_consumed: number,
+    fuel_flow: number,
+    fuel_level?: number,
+}
@@ -0,0 +1,44 @@
+import type { Geodetic, GeodeticFrame } from "$bindings/spatial";
+
+export interface TerrainElevation {
+    latitude: number,
+    longitude: number,
+    elevation?: number
+}
+
+export interface TerrainProfilePoint {
+    latitude: number,
+    longitude: number,
+    distance: number,
+    elevation?: number
+}
+
+export interface TerrainProfile {
+    points: Array<TerrainProfilePoint>,
+    min_elevation?: number,
+    max_elevation?: number
+}
+
+export interface TerrainProfileRequest {
+    path: Array<Geodetic>,
+    step: number
+}
+
+export interface AltitudeConversionRequest {
+    position: Geodetic,
+    frame: GeodeticFrame,
+    home?: Geodetic
+}
+
+export interface TerrainReport {
+    id: string,
+    timestamp: number,
+    latitude: number,
+    longitude: number,
+    terrain_height: number,
+    current_height: number,
+    spacing: number,
+    pending: number,
+    loaded: number,
+    local_elevation?: number
+}
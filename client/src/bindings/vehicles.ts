@@ -1,4 +1,5 @@
 import { EntityColor } from "$bindings/colors";
+import type { FailsafePolicy } from "$bindings/failsafe";
 
 export enum VehicleType {
     Unknown = "Unknown",
@@ -86,6 +87,24 @@ export interface VehicleDescription {
     protocol_id: ProtocolId,
     features: Array<VehicleFeatures>,
     available_modes: Array<VehicleMode>,
+    failsafe?: FailsafePolicy,
+    fuel_tank_capacity?: number,
+}
+
+export enum VtolState {
+    Undefined = "Undefined",
+    TransitionToFixedWing = "TransitionToFixedWing",
+    TransitionToMulticopter = "TransitionToMulticopter",
+    Multicopter = "Multicopter",
+    FixedWing = "FixedWing"
+}
+
+export enum LandedState {
+    Undefined = "Undefined",
+    OnGround = "OnGround",
+    InAir = "InAir",
+    Takeoff = "Takeoff",
+    Landing = "Landing"
 }
 
 export interface VehicleStatus {
@@ -93,5 +112,7 @@ export interface VehicleStatus {
     last_heartbeat: number,
     armed: false,
     mode: VehicleMode,
-    state: VehicleState
+    state: VehicleState,
+    vtol_state: VtolState,
+    landed_state: LandedState
 }
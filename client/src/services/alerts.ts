@@ -0,0 +1,40 @@
+import type { Alert, AlertRule } from "$bindings/alerts";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class AlertsService {
+    static async getAlertRule(ruleId: string): Promise<AlertRule | null> {
+        return await send_request("/alerts/rules/rule/" + ruleId, { method: "GET" }) || null;
+    }
+
+    static async getAlertRules(): Promise<Array<AlertRule> | null> {
+        return await send_request("/alerts/rules", { method: "GET" }) || null;
+    }
+
+    static async saveAlertRule(rule: AlertRule): Promise<AlertRule | null> {
+        return await send_request("/alerts/rules/save", {
+            method: "POST",
+            body: JSON.stringify(rule),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async removeAlertRule(ruleId: string): Promise<string | null> {
+        return await send_request("/alerts/rules/remove/" + ruleId, { method: "DELETE" }) || null;
+    }
+
+    static async getAlert(alertId: string): Promise<Alert | null> {
+        return await send_request("/alerts/alert/" + alertId, { method: "GET" }) || null;
+    }
+
+    static async getActiveAlerts(): Promise<Array<Alert> | null> {
+        return await send_request("/alerts/active", { method: "GET" }) || null;
+    }
+
+    static async getVehicleAlerts(vehicleId: string): Promise<Array<Alert> | null> {
+        return await send_request("/alerts/vehicle/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async acknowledgeAlert(alertId: string): Promise<Alert | null> {
+        return await send_request("/alerts/acknowledge/" + alertId, { method: "PUT" }) || null;
+    }
+}
@@ -0,0 +1,24 @@
+import type { CapturedImage } from "$bindings/captures";
+import { send_request } from "$datasource/rest";
+
+export class CapturesService {
+    static async getCapturedImage(captureId: string): Promise<CapturedImage | null> {
+        return await send_request("/captures/image/" + captureId, { method: "GET" }) || null;
+    }
+
+    static async getMissionCapturedImages(missionId: string): Promise<Array<CapturedImage> | null> {
+        return await send_request("/captures/mission/" + missionId, { method: "GET" }) || null;
+    }
+
+    static async getFlightCapturedImages(sessionId: string): Promise<Array<CapturedImage> | null> {
+        return await send_request("/captures/flight/" + sessionId, { method: "GET" }) || null;
+    }
+
+    static async getVehicleCapturedImages(vehicleId: string): Promise<Array<CapturedImage> | null> {
+        return await send_request("/captures/vehicle/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async removeCapturedImage(captureId: string): Promise<string | null> {
+        return await send_request("/captures/remove/" + captureId, { method: "DELETE" }) || null;
+    }
+}
@@ -1,4 +1,4 @@
-import type { ExecuteCommandRequest, CommandExecution } from "$bindings/commands";
+import type { ExecuteCommandRequest, CommandExecution, CommandConfirmation } from "$bindings/commands";
 import { send_request, default_headers } from "$datasource/rest";
 
 export class CommandService {
@@ -10,6 +10,18 @@ export class CommandService {
         }) || null;
     }
 
+    static async requestConfirmation(request: ExecuteCommandRequest): Promise<CommandConfirmation | null> {
+        return await send_request("/commands/confirmation/request/", {
+            method: "POST",
+            body: JSON.stringify(request),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async confirmCommand(token: string): Promise<string | null> {
+        return await send_request("/commands/confirmation/confirm/" + token, { method: "POST" }) || null;
+    }
+
     static async cancelCommand(command_id: string): Promise<string | null> {
         return await send_request("/commands/cancel/", {
             method: "PUT",
@@ -0,0 +1,20 @@
+import type { FailsafeEvent, FailsafeStatus } from "$bindings/failsafe";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class FailsafeService {
+    static async getFailsafeStatus(vehicleId: string): Promise<FailsafeStatus | null> {
+        return await send_request("/failsafe/status/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async overrideFailsafe(vehicleId: string, overridden: boolean): Promise<FailsafeStatus | null> {
+        return await send_request("/failsafe/override/" + vehicleId, {
+            method: "PUT",
+            body: JSON.stringify(overridden),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async getFailsafeEvents(vehicleId: string): Promise<Array<FailsafeEvent> | null> {
+        return await send_request("/failsafe/events/" + vehicleId, { method: "GET" }) || null;
+    }
+}
@@ -0,0 +1,20 @@
+import type { FlightSession } from "$bindings/flights";
+import { send_request } from "$datasource/rest";
+
+export class FlightsService {
+    static async getFlightSession(sessionId: string): Promise<FlightSession | null> {
+        return await send_request("/flights/flight/" + sessionId, { method: "GET" }) || null;
+    }
+
+    static async getVehicleFlightSessions(vehicleId: string): Promise<Array<FlightSession> | null> {
+        return await send_request("/flights/vehicle/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async getFlightSessions(): Promise<Array<FlightSession> | null> {
+        return await send_request("/flights", { method: "GET" }) || null;
+    }
+
+    static async removeFlightSession(sessionId: string): Promise<string | null> {
+        return await send_request("/flights/remove/" + sessionId, { method: "DELETE" }) || null;
+    }
+}
@@ -0,0 +1,44 @@
+import type { MaintenanceEntry, MaintenanceInterval, MaintenanceNotice, VehicleCounters } from "$bindings/maintenance";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class MaintenanceService {
+    static async getVehicleCounters(vehicleId: string): Promise<VehicleCounters | null> {
+        return await send_request("/maintenance/counters/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async saveInterval(interval: MaintenanceInterval): Promise<MaintenanceInterval | null> {
+        return await send_request("/maintenance/intervals/save", {
+            method: "POST",
+            body: JSON.stringify(interval),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async removeInterval(intervalId: string): Promise<string | null> {
+        return await send_request("/maintenance/intervals/remove/" + intervalId, { method: "DELETE" }) || null;
+    }
+
+    static async getIntervals(vehicleId: string): Promise<Array<MaintenanceInterval> | null> {
+        return await send_request("/maintenance/intervals/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async addEntry(entry: MaintenanceEntry): Promise<MaintenanceEntry | null> {
+        return await send_request("/maintenance/entries/add", {
+            method: "POST",
+            body: JSON.stringify(entry),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async removeEntry(entryId: string): Promise<string | null> {
+        return await send_request("/maintenance/entries/remove/" + entryId, { method: "DELETE" }) || null;
+    }
+
+    static async getEntries(vehicleId: string): Promise<Array<MaintenanceEntry> | null> {
+        return await send_request("/maintenance/entries/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async getNotices(vehicleId: string): Promise<Array<MaintenanceNotice> | null> {
+        return await send_request("/maintenance/notices/" + vehicleId, { method: "GET" }) || null;
+    }
+}
@@ -0,0 +1,12 @@
+import type { VehicleMessage } from "$bindings/messages";
+import { send_request } from "$datasource/rest";
+
+export class MessagesService {
+    static async getVehicleMessages(vehicleId: string): Promise<Array<VehicleMessage> | null> {
+        return await send_request("/messages/vehicle/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async clearVehicleMessages(vehicleId: string): Promise<string | null> {
+        return await send_request("/messages/vehicle/" + vehicleId, { method: "DELETE" }) || null;
+    }
+}
@@ -1,4 +1,4 @@
-import type { Mission, MissionRoute, MissionRouteItem } from "$bindings/mission";
+import type { Mission, MissionAssignment, MissionRevision, MissionRoute, MissionRouteItem, RouteDiff, RouteEdit } from "$bindings/mission";
 import { send_request, default_headers } from "$datasource/rest";
 
 export class MissionService {
@@ -26,6 +26,34 @@ export class MissionService {
         }) || null;
     }
 
+    static async editRoute(mission_id: string, edit: RouteEdit): Promise<MissionRoute | null> {
+        return await send_request("/missions/" + mission_id + "/edit_route", {
+            method: "POST",
+            body: JSON.stringify(edit),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async getRevisions(mission_id: string): Promise<MissionRevision[] | null> {
+        return await send_request("/missions/" + mission_id + "/revisions", { method: "GET" }) || null;
+    }
+
+    static async undoRoute(mission_id: string): Promise<MissionRoute | null> {
+        return await send_request("/missions/" + mission_id + "/undo", { method: "POST" }) || null;
+    }
+
+    static async redoRoute(mission_id: string): Promise<MissionRoute | null> {
+        return await send_request("/missions/" + mission_id + "/redo", { method: "POST" }) || null;
+    }
+
+    static async restoreRevision(mission_id: string, revision_id: string): Promise<MissionRoute | null> {
+        return await send_request("/missions/" + mission_id + "/restore/" + revision_id, { method: "POST" }) || null;
+    }
+
+    static async getVehicleDiff(mission_id: string): Promise<RouteDiff | null> {
+        return await send_request("/missions/" + mission_id + "/vehicle_diff", { method: "GET" }) || null;
+    }
+
     static async downloadMission(missionId: string): Promise<string | null> {
         return await send_request("/missions/download/" + missionId, { method: "PUT" }) || null;
     }
@@ -49,4 +77,16 @@ export class MissionService {
     static async getMissions(): Promise<Array<Mission> | null> {
         return await send_request("/missions/missions", { method: "GET" }) || null;
     }
+
+    static async removeMission(missionId: string): Promise<string | null> {
+        return await send_request("/missions/remove/" + missionId, { method: "DELETE" }) || null;
+    }
+
+    static async getVehicleAssignments(vehicleId: string): Promise<Array<MissionAssignment> | null> {
+        return await send_request("/missions/assignments/vehicle/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async activateMission(missionId: string): Promise<Mission | null> {
+        return await send_request("/missions/assignments/activate/" + missionId, { method: "PUT" }) || null;
+    }
 }
@@ -0,0 +1,36 @@
+import type { GeneratedPattern, MissionPattern, PatternParameters } from "$bindings/mission_patterns";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class MissionPatternService {
+    static async previewPattern(parameters: PatternParameters): Promise<GeneratedPattern | null> {
+        return await send_request("/missions/patterns/preview", {
+            method: "POST",
+            body: JSON.stringify(parameters),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async addPattern(missionId: string, parameters: PatternParameters): Promise<MissionPattern | null> {
+        return await send_request("/missions/" + missionId + "/patterns", {
+            method: "POST",
+            body: JSON.stringify(parameters),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async getPatterns(missionId: string): Promise<MissionPattern[] | null> {
+        return await send_request("/missions/" + missionId + "/patterns", { method: "GET" }) || null;
+    }
+
+    static async regeneratePattern(patternId: string, parameters: PatternParameters): Promise<MissionPattern | null> {
+        return await send_request("/missions/patterns/" + patternId + "/regenerate", {
+            method: "POST",
+            body: JSON.stringify(parameters),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async removePattern(patternId: string): Promise<string | null> {
+        return await send_request("/missions/patterns/" + patternId, { method: "DELETE" }) || null;
+    }
+}
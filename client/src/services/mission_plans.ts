@@ -0,0 +1,48 @@
+import type { Mission, MissionPlan } from "$bindings/mission";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class MissionPlanService {
+    static async savePlan(plan: MissionPlan): Promise<MissionPlan | null> {
+        return await send_request("/missions/plans/save", {
+            method: "POST",
+            body: JSON.stringify(plan),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async removePlan(planId: string): Promise<string | null> {
+        return await send_request("/missions/plans/remove/" + planId, { method: "DELETE" }) || null;
+    }
+
+    static async getPlan(planId: string): Promise<MissionPlan | null> {
+        return await send_request("/missions/plans/plan/" + planId, { method: "GET" }) || null;
+    }
+
+    static async getPlans(): Promise<MissionPlan[] | null> {
+        return await send_request("/missions/plans", { method: "GET" }) || null;
+    }
+
+    static async saveMissionAsPlan(missionId: string, name: string): Promise<MissionPlan | null> {
+        return await send_request("/missions/plans/from_mission/" + missionId, {
+            method: "POST",
+            body: JSON.stringify(name),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async clonePlan(planId: string, name: string): Promise<MissionPlan | null> {
+        return await send_request("/missions/plans/clone/" + planId, {
+            method: "POST",
+            body: JSON.stringify(name),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async assignPlan(planId: string, vehicleId: string): Promise<Mission | null> {
+        return await send_request("/missions/plans/assign/" + planId + "/" + vehicleId, { method: "POST" }) || null;
+    }
+
+    static async swapPlan(planId: string, vehicleId: string): Promise<Mission | null> {
+        return await send_request("/missions/plans/swap/" + planId + "/" + vehicleId, { method: "POST" }) || null;
+    }
+}
@@ -0,0 +1,24 @@
+import type { MissionValidationReport, MissionValidationSettings } from "$bindings/mission_validation";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class MissionValidationService {
+    static async runValidation(missionId: string): Promise<MissionValidationReport | null> {
+        return await send_request("/missions/validation/run/" + missionId, { method: "POST" }) || null;
+    }
+
+    static async getValidationReport(missionId: string): Promise<MissionValidationReport | null> {
+        return await send_request("/missions/validation/report/" + missionId, { method: "GET" }) || null;
+    }
+
+    static async getValidationSettings(): Promise<Array<MissionValidationSettings> | null> {
+        return await send_request("/missions/validation/settings", { method: "GET" }) || null;
+    }
+
+    static async saveValidationSettings(settings: MissionValidationSettings): Promise<MissionValidationSettings | null> {
+        return await send_request("/missions/validation/settings/save", {
+            method: "POST",
+            body: JSON.stringify(settings),
+            headers: default_headers
+        }) || null;
+    }
+}
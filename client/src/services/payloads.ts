@@ -0,0 +1,28 @@
+import type { Payload } from "$bindings/payloads";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class PayloadsService {
+    static async getPayload(payloadId: string): Promise<Payload | null> {
+        return await send_request("/payloads/payload/" + payloadId, { method: "GET" }) || null;
+    }
+
+    static async getVehiclePayloads(vehicleId: string): Promise<Array<Payload> | null> {
+        return await send_request("/payloads/vehicle/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async getPayloads(): Promise<Array<Payload> | null> {
+        return await send_request("/payloads/payloads", { method: "GET" }) || null;
+    }
+
+    static async savePayload(payload: Payload): Promise<Payload | null> {
+        return await send_request("/payloads/save", {
+            method: "POST",
+            body: JSON.stringify(payload),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async removePayload(payloadId: string): Promise<string | null> {
+        return await send_request("/payloads/remove/" + payloadId, { method: "DELETE" }) || null;
+    }
+}
@@ -0,0 +1,28 @@
+import type { PreflightReport, PreflightSettings } from "$bindings/preflight";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class PreflightService {
+    static async runPreflight(vehicleId: string): Promise<PreflightReport | null> {
+        return await send_request("/preflight/run/" + vehicleId, { method: "POST" }) || null;
+    }
+
+    static async getPreflightReport(vehicleId: string): Promise<PreflightReport | null> {
+        return await send_request("/preflight/report/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async getPreflightSettings(): Promise<Array<PreflightSettings> | null> {
+        return await send_request("/preflight/settings", { method: "GET" }) || null;
+    }
+
+    static async getVehiclePreflightSettings(vehicleId: string): Promise<PreflightSettings | null> {
+        return await send_request("/preflight/settings/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async savePreflightSettings(settings: PreflightSettings): Promise<PreflightSettings | null> {
+        return await send_request("/preflight/settings/save", {
+            method: "POST",
+            body: JSON.stringify(settings),
+            headers: default_headers
+        }) || null;
+    }
+}
@@ -0,0 +1,44 @@
+import type { Geodetic, GeodeticFrame, Measure, Ned, TrackOffset } from "$bindings/spatial";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class SpatialService {
+    static async convertForVehicle(vehicleId: string, position: Geodetic, frame: GeodeticFrame): Promise<Geodetic | null> {
+        return await send_request("/spatial/convert/" + vehicleId, {
+            method: "POST",
+            body: JSON.stringify({ position: position, frame: frame }),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async measure(from: Geodetic, to: Geodetic): Promise<Measure | null> {
+        return await send_request("/spatial/measure", {
+            method: "POST",
+            body: JSON.stringify({ from: from, to: to }),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async trackOffset(position: Geodetic, start: Geodetic, end: Geodetic): Promise<TrackOffset | null> {
+        return await send_request("/spatial/track", {
+            method: "POST",
+            body: JSON.stringify({ position: position, start: start, end: end }),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async destination(from: Geodetic, bearing: number, distance: number): Promise<Geodetic | null> {
+        return await send_request("/spatial/destination", {
+            method: "POST",
+            body: JSON.stringify({ from: from, bearing: bearing, distance: distance }),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async offset(from: Geodetic, offset: Ned): Promise<Geodetic | null> {
+        return await send_request("/spatial/offset", {
+            method: "POST",
+            body: JSON.stringify({ from: from, offset: offset }),
+            headers: default_headers
+        }) || null;
+    }
+}
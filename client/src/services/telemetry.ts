@@ -0,0 +1,12 @@
+import type { Battery, Engine } from "$bindings/telemetry";
+import { send_request } from "$datasource/rest";
+
+export class TelemetryService {
+    static async getBatteries(vehicleId: string): Promise<Array<Battery> | null> {
+        return await send_request("/telemetry/batteries/" + vehicleId, { method: "GET" }) || null;
+    }
+
+    static async getEngine(vehicleId: string): Promise<Engine | null> {
+        return await send_request("/telemetry/engine/" + vehicleId, { method: "GET" }) || null;
+    }
+}
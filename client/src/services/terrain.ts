@@ -0,0 +1,39 @@
+import type { Geodetic, GeodeticFrame } from "$bindings/spatial";
+import type { AltitudeConversionRequest, TerrainElevation, TerrainProfile, TerrainProfileRequest, TerrainReport } from "$bindings/terrain";
+import { send_request, default_headers } from "$datasource/rest";
+
+export class TerrainService {
+    static async getElevation(latitude: number, longitude: number): Promise<TerrainElevation | null> {
+        return await send_request("/terrain/elevation/" + latitude + "/" + longitude, { method: "GET" }) || null;
+    }
+
+    static async getProfile(path: Array<Geodetic>, step: number): Promise<TerrainProfile | null> {
+        const request: TerrainProfileRequest = { path: path, step: step };
+        return await send_request("/terrain/profile", {
+            method: "POST",
+            body: JSON.stringify(request),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async convertAltitude(position: Geodetic, frame: GeodeticFrame, home?: Geodetic): Promise<Geodetic | null> {
+        const request: AltitudeConversionRequest = { position: position, frame: frame, home: home };
+        return await send_request("/terrain/convert", {
+            method: "POST",
+            body: JSON.stringify(request),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async checkTerrain(vehicleId: string, position: Geodetic): Promise<string | null> {
+        return await send_request("/terrain/check/" + vehicleId, {
+            method: "PUT",
+            body: JSON.stringify(position),
+            headers: default_headers
+        }) || null;
+    }
+
+    static async getTerrainReport(vehicleId: string): Promise<TerrainReport | null> {
+        return await send_request("/terrain/report/" + vehicleId, { method: "GET" }) || null;
+    }
+}
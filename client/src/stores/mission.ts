@@ -190,7 +190,7 @@ export const missions = function () {
 
 export const selectedVehicleMission = derived([missions, selectedVehicleId], ($data) => {
     for (let mission of $data[0].values()) {
-        if (mission.vehicle_id === $data[1]) {
+        if (mission.vehicle_id === $data[1] && mission.active) {
             return mission;
         }
     }
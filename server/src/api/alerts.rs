@@ -0,0 +1,112 @@
+use actix_web::{get, post, put, delete, web, Responder, HttpResponse};
+
+use crate::models::alerts::{AlertId, AlertRule, AlertRuleId};
+use crate::models::vehicles::VehicleId;
+use super::context::ApiContext;
+
+#[post("/alerts/rules/save")]
+pub async fn post_alert_rule(context: web::Data<ApiContext>, rule: web::Json<AlertRule>) -> impl Responder {
+    let rule = rule.into_inner();
+    let result = context.dal.save_alert_rule(rule).await;
+
+    match result {
+        Ok(rule) => HttpResponse::Ok().json(rule),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[delete("/alerts/rules/remove/{rule_id}")]
+pub async fn delete_alert_rule(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let rule_id: AlertRuleId = path.into_inner();
+
+    if let Err(err) = context.dal.delete_alert_rule(&rule_id).await {
+        log::warn!("REST error: {}", &err);
+        return HttpResponse::InternalServerError().json(err.to_string())
+    }
+    HttpResponse::Ok().json(rule_id)
+}
+
+#[get("/alerts/rules/rule/{rule_id}")]
+pub async fn get_alert_rule(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let rule_id: AlertRuleId = path.into_inner();
+    let result = context.dal.alert_rule(&rule_id).await;
+
+    match result {
+        Ok(rule) => HttpResponse::Ok().json(rule),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/alerts/rules")]
+pub async fn get_alert_rules(context: web::Data<ApiContext>) -> impl Responder {
+    let result = context.dal.all_alert_rules().await;
+
+    match result {
+        Ok(rules) => HttpResponse::Ok().json(rules),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/alerts/active")]
+pub async fn get_active_alerts(context: web::Data<ApiContext>) -> impl Responder {
+    let result = context.dal.active_alerts().await;
+
+    match result {
+        Ok(alerts) => HttpResponse::Ok().json(alerts),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/alerts/vehicle/{vehicle_id}")]
+pub async fn get_vehicle_alerts(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.vehicle_alerts(&vehicle_id).await;
+
+    match result {
+        Ok(alerts) => HttpResponse::Ok().json(alerts),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/alerts/alert/{alert_id}")]
+pub async fn get_alert(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let alert_id: AlertId = path.into_inner();
+    let result = context.dal.alert(&alert_id).await;
+
+    match result {
+        Ok(alert) => HttpResponse::Ok().json(alert),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[put("/alerts/acknowledge/{alert_id}")]
+pub async fn acknowledge_alert(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let alert_id: AlertId = path.into_inner();
+    let result = context.dal.acknowledge_alert(&alert_id).await;
+
+    match result {
+        Ok(alert) => HttpResponse::Ok().json(alert),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
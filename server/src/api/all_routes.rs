@@ -39,19 +39,99 @@ pub async fn serve(
             .service(super::vehicles::get_statuses)
             .service(super::vehicles::post_vehicle)
             .service(super::vehicles::delete_vehicle)
+            .service(super::messages::get_vehicle_messages)
+            .service(super::messages::clear_vehicle_messages)
+            .service(super::telemetry::get_batteries)
+            .service(super::telemetry::get_engine)
+            .service(super::payloads::get_payloads)
+            .service(super::payloads::get_payload)
+            .service(super::payloads::get_vehicle_payloads)
+            .service(super::payloads::post_payload)
+            .service(super::payloads::delete_payload)
+            .service(super::preflight::run_preflight)
+            .service(super::preflight::get_preflight_report)
+            .service(super::preflight::get_preflight_settings)
+            .service(super::preflight::get_vehicle_preflight_settings)
+            .service(super::preflight::post_preflight_settings)
+            .service(super::alerts::post_alert_rule)
+            .service(super::alerts::delete_alert_rule)
+            .service(super::alerts::get_alert_rule)
+            .service(super::alerts::get_alert_rules)
+            .service(super::alerts::get_active_alerts)
+            .service(super::alerts::get_vehicle_alerts)
+            .service(super::alerts::get_alert)
+            .service(super::alerts::acknowledge_alert)
+            .service(super::failsafe::get_failsafe_status)
+            .service(super::failsafe::override_failsafe)
+            .service(super::failsafe::get_failsafe_events)
+            .service(super::flights::get_flight_session)
+            .service(super::flights::get_vehicle_flight_sessions)
+            .service(super::flights::get_flight_sessions)
+            .service(super::flights::delete_flight_session)
+            .service(super::maintenance::get_vehicle_counters)
+            .service(super::maintenance::post_maintenance_interval)
+            .service(super::maintenance::delete_maintenance_interval)
+            .service(super::maintenance::get_maintenance_intervals)
+            .service(super::maintenance::post_maintenance_entry)
+            .service(super::maintenance::delete_maintenance_entry)
+            .service(super::maintenance::get_maintenance_entries)
+            .service(super::maintenance::get_maintenance_notices)
             .service(super::commands::execute_command)
+            .service(super::commands::request_command_confirmation)
+            .service(super::commands::confirm_command)
             .service(super::commands::cancel_command)
             .service(super::commands::get_command_execution)
             .service(super::commands::get_command_executions)
             .service(super::missions::create_mission)
             .service(super::missions::upsert_route_item)
             .service(super::missions::remove_route_item)
+            .service(super::missions::edit_route)
+            .service(super::missions::get_revisions)
+            .service(super::missions::undo_route)
+            .service(super::missions::redo_route)
+            .service(super::missions::restore_revision)
+            .service(super::missions::get_vehicle_diff)
             .service(super::missions::download_mission)
             .service(super::missions::upload_mission)
             .service(super::missions::clear_mission)
             .service(super::missions::cancel_mission_state)
             .service(super::missions::get_mission)
             .service(super::missions::get_missions)
+            .service(super::missions::remove_mission)
+            .service(super::mission_plans::save_plan)
+            .service(super::mission_plans::remove_plan)
+            .service(super::mission_plans::get_plan)
+            .service(super::mission_plans::get_plans)
+            .service(super::mission_plans::save_mission_as_plan)
+            .service(super::mission_plans::clone_plan)
+            .service(super::mission_plans::assign_plan)
+            .service(super::mission_plans::swap_plan)
+            .service(super::mission_assignments::get_vehicle_assignments)
+            .service(super::mission_assignments::activate_mission)
+            .service(super::mission_patterns::preview_pattern)
+            .service(super::mission_patterns::add_pattern)
+            .service(super::mission_patterns::get_patterns)
+            .service(super::mission_patterns::regenerate_pattern)
+            .service(super::mission_patterns::remove_pattern)
+            .service(super::mission_validation::run_validation)
+            .service(super::mission_validation::get_validation_report)
+            .service(super::mission_validation::get_validation_settings)
+            .service(super::mission_validation::post_validation_settings)
+            .service(super::terrain::get_elevation)
+            .service(super::terrain::get_profile)
+            .service(super::terrain::convert_altitude)
+            .service(super::terrain::check_terrain)
+            .service(super::terrain::get_terrain_report)
+            .service(super::spatial::convert_for_vehicle)
+            .service(super::spatial::measure)
+            .service(super::spatial::track_offset)
+            .service(super::spatial::destination)
+            .service(super::spatial::offset)
+            .service(super::captures::get_captured_image)
+            .service(super::captures::get_mission_captured_images)
+            .service(super::captures::get_flight_captured_images)
+            .service(super::captures::get_vehicle_captured_images)
+            .service(super::captures::delete_captured_image)
             .app_data(Data::new(context.clone()))
     }).bind(address)?.run();
 
@@ -0,0 +1,71 @@
+use actix_web::{get, delete, web, Responder, HttpResponse};
+
+use crate::models::{captures::CaptureId, flights::FlightSessionId, missions::MissionId, vehicles::VehicleId};
+use super::context::ApiContext;
+
+#[delete("/captures/remove/{capture_id}")]
+pub async fn delete_captured_image(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let capture_id: CaptureId = path.into_inner();
+
+    if let Err(err) = context.dal.delete_captured_image(&capture_id).await {
+        log::warn!("REST error: {}", &err);
+        return HttpResponse::InternalServerError().json(err.to_string())
+    }
+    HttpResponse::Ok().json(capture_id)
+}
+
+#[get("/captures/image/{capture_id}")]
+pub async fn get_captured_image(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let capture_id: CaptureId = path.into_inner();
+    let result = context.dal.captured_image(&capture_id).await;
+
+    match result {
+        Ok(image) => HttpResponse::Ok().json(image),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/captures/mission/{mission_id}")]
+pub async fn get_mission_captured_images(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let mission_id: MissionId = path.into_inner();
+    let result = context.dal.mission_captured_images(&mission_id).await;
+
+    match result {
+        Ok(images) => HttpResponse::Ok().json(images),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/captures/flight/{session_id}")]
+pub async fn get_flight_captured_images(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let session_id: FlightSessionId = path.into_inner();
+    let result = context.dal.flight_captured_images(&session_id).await;
+
+    match result {
+        Ok(images) => HttpResponse::Ok().json(images),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/captures/vehicle/{vehicle_id}")]
+pub async fn get_vehicle_captured_images(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.vehicle_captured_images(&vehicle_id).await;
+
+    match result {
+        Ok(images) => HttpResponse::Ok().json(images),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
@@ -1,11 +1,10 @@
 use actix_web::{get, post, put, web, Responder, HttpResponse};
 
 use crate::models::{commands::*, events::ClientEvent};
+use crate::services::{capabilities::validation, preflight::checklist};
 use super::context::ApiContext;
 
-#[post("/commands/execute/")]
-pub async fn execute_command(context: web::Data<ApiContext>, request: web::Json<ExecuteCommandRequest>) -> impl Responder {
-    let request = request.into_inner();
+fn publish_command(context: &ApiContext, request: ExecuteCommandRequest) -> HttpResponse {
     let command_id: CommandId = uuid::Uuid::new_v4().to_string();
 
     match context.client_bus.publish(ClientEvent::ExecuteCommand { request, command_id: command_id.clone() } ) {
@@ -17,6 +16,83 @@ pub async fn execute_command(context: web::Data<ApiContext>, request: web::Json<
     }
 }
 
+#[post("/commands/execute/")]
+pub async fn execute_command(context: web::Data<ApiContext>, request: web::Json<ExecuteCommandRequest>) -> impl Responder {
+    let request = request.into_inner();
+
+    match validation::check_command(&context.dal, &request).await {
+        Ok(None) => {},
+        Ok(Some(rejection)) => {
+            log::warn!("REST: command rejected {:?}", &rejection);
+            return HttpResponse::UnprocessableEntity().json(rejection)
+        },
+        Err(err) => {
+            log::warn!("REST: error {}", &err);
+            return HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+
+    if request.command.requires_confirmation() {
+        return HttpResponse::UnprocessableEntity().json(CommandRejection::ConfirmationRequired {})
+    }
+
+    match checklist::check_command(&context.dal, &request).await {
+        Ok(None) => {},
+        Ok(Some(rejection)) => {
+            log::warn!("REST: command rejected {:?}", &rejection);
+            return HttpResponse::UnprocessableEntity().json(rejection)
+        },
+        Err(err) => {
+            log::warn!("REST: error {}", &err);
+            return HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+
+    publish_command(&context, request)
+}
+
+#[post("/commands/confirmation/request/")]
+pub async fn request_command_confirmation(context: web::Data<ApiContext>, request: web::Json<ExecuteCommandRequest>) -> impl Responder {
+    let request = request.into_inner();
+
+    match validation::check_command(&context.dal, &request).await {
+        Ok(None) => {},
+        Ok(Some(rejection)) => {
+            log::warn!("REST: command rejected {:?}", &rejection);
+            return HttpResponse::UnprocessableEntity().json(rejection)
+        },
+        Err(err) => {
+            log::warn!("REST: error {}", &err);
+            return HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+
+    match context.dal.create_command_confirmation(request).await {
+        Ok(confirmation) => HttpResponse::Ok().json(confirmation),
+        Err(err) => {
+            log::warn!("REST: error {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/commands/confirmation/confirm/{token}")]
+pub async fn confirm_command(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let token: ConfirmationToken = path.into_inner();
+
+    match context.dal.take_command_confirmation(&token).await {
+        Ok(Some(confirmation)) => {
+            log::warn!("REST: confirmed command {:?}", &confirmation.request);
+            publish_command(&context, confirmation.request)
+        },
+        Ok(None) => HttpResponse::UnprocessableEntity().json(CommandRejection::ConfirmationInvalid { token }),
+        Err(err) => {
+            log::warn!("REST: error {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
 #[put("/commands/cancel/")]
 pub async fn cancel_command(context: web::Data<ApiContext>, request: web::Json<CommandId>) -> impl Responder {
     let command_id = request.into_inner();
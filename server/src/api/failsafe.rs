@@ -0,0 +1,50 @@
+use actix_web::{get, put, web, Responder, HttpResponse};
+
+use crate::models::vehicles::VehicleId;
+use super::context::ApiContext;
+
+#[get("/failsafe/status/{vehicle_id}")]
+pub async fn get_failsafe_status(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.failsafe_status(&vehicle_id).await;
+
+    match result {
+        Ok(status) => HttpResponse::Ok().json(status),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[put("/failsafe/override/{vehicle_id}")]
+pub async fn override_failsafe(context: web::Data<ApiContext>, path: web::Path<String>, overridden: web::Json<bool>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let overridden = overridden.into_inner();
+    let result = context.dal.set_failsafe_override(&vehicle_id, overridden).await;
+
+    match result {
+        Ok(status) => {
+            log::warn!("Failsafe override for vehicle {} set to {}", &vehicle_id, overridden);
+            HttpResponse::Ok().json(status)
+        },
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/failsafe/events/{vehicle_id}")]
+pub async fn get_failsafe_events(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.failsafe_events(&vehicle_id).await;
+
+    match result {
+        Ok(events) => HttpResponse::Ok().json(events),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
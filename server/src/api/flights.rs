@@ -0,0 +1,57 @@
+use actix_web::{get, delete, web, Responder, HttpResponse};
+
+use crate::models::flights::FlightSessionId;
+use crate::models::vehicles::VehicleId;
+use super::context::ApiContext;
+
+#[get("/flights/flight/{session_id}")]
+pub async fn get_flight_session(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let session_id: FlightSessionId = path.into_inner();
+    let result = context.dal.flight_session(&session_id).await;
+
+    match result {
+        Ok(session) => HttpResponse::Ok().json(session),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/flights/vehicle/{vehicle_id}")]
+pub async fn get_vehicle_flight_sessions(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.vehicle_flight_sessions(&vehicle_id).await;
+
+    match result {
+        Ok(sessions) => HttpResponse::Ok().json(sessions),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/flights")]
+pub async fn get_flight_sessions(context: web::Data<ApiContext>) -> impl Responder {
+    let result = context.dal.all_flight_sessions().await;
+
+    match result {
+        Ok(sessions) => HttpResponse::Ok().json(sessions),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[delete("/flights/remove/{session_id}")]
+pub async fn delete_flight_session(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let session_id: FlightSessionId = path.into_inner();
+
+    if let Err(err) = context.dal.delete_flight_session(&session_id).await {
+        log::warn!("REST error: {}", &err);
+        return HttpResponse::InternalServerError().json(err.to_string())
+    }
+    HttpResponse::Ok().json(session_id)
+}
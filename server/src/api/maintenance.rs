@@ -0,0 +1,112 @@
+use actix_web::{get, post, delete, web, Responder, HttpResponse};
+
+use crate::models::maintenance::*;
+use crate::models::vehicles::VehicleId;
+use crate::services::maintenance::schedule;
+use super::context::ApiContext;
+
+#[get("/maintenance/counters/{vehicle_id}")]
+pub async fn get_vehicle_counters(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.vehicle_counters(&vehicle_id).await;
+
+    match result {
+        Ok(counters) => HttpResponse::Ok().json(counters),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/maintenance/intervals/save")]
+pub async fn post_maintenance_interval(context: web::Data<ApiContext>, interval: web::Json<MaintenanceInterval>) -> impl Responder {
+    let interval = interval.into_inner();
+    let result = context.dal.save_maintenance_interval(interval).await;
+
+    match result {
+        Ok(interval) => HttpResponse::Ok().json(interval),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[delete("/maintenance/intervals/remove/{interval_id}")]
+pub async fn delete_maintenance_interval(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let interval_id: MaintenanceIntervalId = path.into_inner();
+
+    if let Err(err) = context.dal.delete_maintenance_interval(&interval_id).await {
+        log::warn!("REST error: {}", &err);
+        return HttpResponse::InternalServerError().json(err.to_string())
+    }
+    HttpResponse::Ok().json(interval_id)
+}
+
+#[get("/maintenance/intervals/{vehicle_id}")]
+pub async fn get_maintenance_intervals(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.vehicle_maintenance_intervals(&vehicle_id).await;
+
+    match result {
+        Ok(intervals) => HttpResponse::Ok().json(intervals),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/maintenance/entries/add")]
+pub async fn post_maintenance_entry(context: web::Data<ApiContext>, entry: web::Json<MaintenanceEntry>) -> impl Responder {
+    let entry = entry.into_inner();
+    let result = context.dal.add_maintenance_entry(entry).await;
+
+    match result {
+        Ok(entry) => HttpResponse::Ok().json(entry),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[delete("/maintenance/entries/remove/{entry_id}")]
+pub async fn delete_maintenance_entry(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let entry_id: MaintenanceEntryId = path.into_inner();
+
+    if let Err(err) = context.dal.delete_maintenance_entry(&entry_id).await {
+        log::warn!("REST error: {}", &err);
+        return HttpResponse::InternalServerError().json(err.to_string())
+    }
+    HttpResponse::Ok().json(entry_id)
+}
+
+#[get("/maintenance/entries/{vehicle_id}")]
+pub async fn get_maintenance_entries(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.vehicle_maintenance_entries(&vehicle_id).await;
+
+    match result {
+        Ok(entries) => HttpResponse::Ok().json(entries),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/maintenance/notices/{vehicle_id}")]
+pub async fn get_maintenance_notices(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = schedule::maintenance_notices(&context.dal, &vehicle_id).await;
+
+    match result {
+        Ok(notices) => HttpResponse::Ok().json(notices),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use actix_web::{get, delete, web, Responder, HttpResponse};
+
+use crate::models::vehicles::VehicleId;
+use super::context::ApiContext;
+
+#[get("/messages/vehicle/{vehicle_id}")]
+pub async fn get_vehicle_messages(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.vehicle_messages(&vehicle_id).await;
+
+    match result {
+        Ok(messages) => HttpResponse::Ok().json(messages),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[delete("/messages/vehicle/{vehicle_id}")]
+pub async fn clear_vehicle_messages(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+
+    if let Err(err) = context.dal.clear_vehicle_messages(&vehicle_id).await {
+        log::warn!("REST error: {}", &err);
+        return HttpResponse::InternalServerError().json(err.to_string())
+    }
+    HttpResponse::Ok().json(vehicle_id)
+}
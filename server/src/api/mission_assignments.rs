@@ -0,0 +1,33 @@
+use actix_web::{get, put, web, Responder, HttpResponse};
+
+use crate::models::missions::MissionId;
+use crate::models::vehicles::VehicleId;
+use super::context::ApiContext;
+
+#[get("/missions/assignments/vehicle/{vehicle_id}")]
+pub async fn get_vehicle_assignments(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.vehicle_mission_assignments(&vehicle_id).await;
+
+    match result {
+        Ok(assignments) => HttpResponse::Ok().json(assignments),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[put("/missions/assignments/activate/{mission_id}")]
+pub async fn activate_mission(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let mission_id: MissionId = path.into_inner();
+    let result = context.dal.activate_mission(&mission_id).await;
+
+    match result {
+        Ok(mission) => HttpResponse::Ok().json(mission),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
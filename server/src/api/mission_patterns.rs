@@ -0,0 +1,75 @@
+use actix_web::{get, post, delete, web, Responder, HttpResponse};
+
+use crate::models::missions::MissionId;
+use crate::models::mission_patterns::{MissionPatternId, PatternParameters};
+use crate::services::missions::patterns;
+use super::context::ApiContext;
+
+#[post("/missions/patterns/preview")]
+pub async fn preview_pattern(parameters: web::Json<PatternParameters>) -> impl Responder {
+    let result = patterns::generate_pattern(&parameters.into_inner());
+
+    match result {
+        Ok(pattern) => HttpResponse::Ok().json(pattern),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::UnprocessableEntity().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/{mission_id}/patterns")]
+pub async fn add_pattern(context: web::Data<ApiContext>, path: web::Path<MissionId>, parameters: web::Json<PatternParameters>) -> impl Responder {
+    let mission_id = path.into_inner();
+    let result = context.dal.add_mission_pattern(&mission_id, parameters.into_inner()).await;
+
+    match result {
+        Ok(pattern) => HttpResponse::Ok().json(pattern),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/missions/{mission_id}/patterns")]
+pub async fn get_patterns(context: web::Data<ApiContext>, path: web::Path<MissionId>) -> impl Responder {
+    let mission_id = path.into_inner();
+    let result = context.dal.mission_patterns(&mission_id).await;
+
+    match result {
+        Ok(patterns) => HttpResponse::Ok().json(patterns),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/patterns/{pattern_id}/regenerate")]
+pub async fn regenerate_pattern(context: web::Data<ApiContext>, path: web::Path<MissionPatternId>, parameters: web::Json<PatternParameters>) -> impl Responder {
+    let pattern_id = path.into_inner();
+    let result = context.dal.regenerate_mission_pattern(&pattern_id, parameters.into_inner()).await;
+
+    match result {
+        Ok(pattern) => HttpResponse::Ok().json(pattern),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[delete("/missions/patterns/{pattern_id}")]
+pub async fn remove_pattern(context: web::Data<ApiContext>, path: web::Path<MissionPatternId>) -> impl Responder {
+    let pattern_id = path.into_inner();
+    let result = context.dal.delete_mission_pattern(&pattern_id).await;
+
+    match result {
+        Ok(_) => HttpResponse::Ok().json(pattern_id),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
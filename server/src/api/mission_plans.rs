@@ -0,0 +1,115 @@
+use actix_web::{get, post, delete, web, Responder, HttpResponse};
+
+use crate::models::missions::{MissionId, MissionPlan, MissionPlanId};
+use crate::models::vehicles::VehicleId;
+use super::context::ApiContext;
+
+#[post("/missions/plans/save")]
+pub async fn save_plan(context: web::Data<ApiContext>, plan: web::Json<MissionPlan>) -> impl Responder {
+    let result = context.dal.save_mission_plan(plan.into_inner()).await;
+
+    match result {
+        Ok(plan) => HttpResponse::Ok().json(plan),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[delete("/missions/plans/remove/{plan_id}")]
+pub async fn remove_plan(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let plan_id: MissionPlanId = path.into_inner();
+    let result = context.dal.delete_mission_plan(&plan_id).await;
+
+    match result {
+        Ok(_) => HttpResponse::Ok().json(plan_id),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/missions/plans/plan/{plan_id}")]
+pub async fn get_plan(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let plan_id: MissionPlanId = path.into_inner();
+    let result = context.dal.mission_plan(&plan_id).await;
+
+    match result {
+        Ok(plan) => HttpResponse::Ok().json(plan),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/missions/plans")]
+pub async fn get_plans(context: web::Data<ApiContext>) -> impl Responder {
+    let result = context.dal.all_mission_plans().await;
+
+    match result {
+        Ok(plans) => HttpResponse::Ok().json(plans),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/plans/from_mission/{mission_id}")]
+pub async fn save_mission_as_plan(context: web::Data<ApiContext>, path: web::Path<String>, name: web::Json<String>) -> impl Responder {
+    let mission_id: MissionId = path.into_inner();
+    let result = context.dal.save_mission_as_plan(&mission_id, name.into_inner()).await;
+
+    match result {
+        Ok(plan) => HttpResponse::Ok().json(plan),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/plans/clone/{plan_id}")]
+pub async fn clone_plan(context: web::Data<ApiContext>, path: web::Path<String>, name: web::Json<String>) -> impl Responder {
+    let plan_id: MissionPlanId = path.into_inner();
+    let result = context.dal.clone_mission_plan(&plan_id, name.into_inner()).await;
+
+    match result {
+        Ok(plan) => HttpResponse::Ok().json(plan),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/plans/assign/{plan_id}/{vehicle_id}")]
+pub async fn assign_plan(context: web::Data<ApiContext>, path: web::Path<(MissionPlanId, VehicleId)>) -> impl Responder {
+    let (plan_id, vehicle_id) = path.into_inner();
+    let result = context.dal.assign_mission_plan(&plan_id, &vehicle_id).await;
+
+    match result {
+        Ok(mission) => HttpResponse::Ok().json(mission),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/plans/swap/{plan_id}/{vehicle_id}")]
+pub async fn swap_plan(context: web::Data<ApiContext>, path: web::Path<(MissionPlanId, VehicleId)>) -> impl Responder {
+    let (plan_id, vehicle_id) = path.into_inner();
+    let result = context.dal.swap_mission_plan(&plan_id, &vehicle_id).await;
+
+    match result {
+        Ok(mission) => HttpResponse::Ok().json(mission),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use actix_web::{get, post, web, Responder, HttpResponse};
+
+use crate::models::{missions::MissionId, mission_validation::MissionValidationSettings};
+use crate::services::missions::validation;
+use super::context::ApiContext;
+
+#[post("/missions/validation/run/{mission_id}")]
+pub async fn run_validation(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let mission_id: MissionId = path.into_inner();
+    let result = validation::run_validation(&context.dal, &mission_id).await;
+
+    match result {
+        Ok((report, _)) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/missions/validation/report/{mission_id}")]
+pub async fn get_validation_report(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let mission_id: MissionId = path.into_inner();
+    let result = context.dal.mission_validation_report(&mission_id).await;
+
+    match result {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/missions/validation/settings")]
+pub async fn get_validation_settings(context: web::Data<ApiContext>) -> impl Responder {
+    let result = context.dal.all_mission_validation_settings().await;
+
+    match result {
+        Ok(settings) => HttpResponse::Ok().json(settings),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/validation/settings/save")]
+pub async fn post_validation_settings(context: web::Data<ApiContext>, settings: web::Json<MissionValidationSettings>) -> impl Responder {
+    let settings = settings.into_inner();
+    let result = context.dal.save_mission_validation_settings(settings).await;
+
+    match result {
+        Ok(settings) => HttpResponse::Ok().json(settings),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use actix_web::{get, post, put, delete, web, Responder, HttpResponse};
 
 use crate::models::{events::ClientEvent, missions::*, vehicles::VehicleId};
+use crate::services::missions::validation;
 use super::context::ApiContext;
 
 #[post("/missions/create")]
@@ -17,6 +18,20 @@ pub async fn create_mission(context: web::Data<ApiContext>, vehicle_id: web::Jso
     }
 }
 
+#[delete("/missions/remove/{mission_id}")]
+pub async fn remove_mission(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let mission_id: MissionId = path.into_inner();
+    let result = context.dal.delete_mission(&mission_id).await;
+
+    match result {
+        Ok(_) => HttpResponse::Ok().json(mission_id),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
 #[post("/missions/{mission_id}/upsert_route_item/{index}")]
 pub async fn upsert_route_item(context: web::Data<ApiContext>, path: web::Path<(MissionId, u16)>, item: web::Json<MissionRouteItem>) -> impl Responder {
     let (mission_id, index) = path.into_inner();
@@ -32,6 +47,21 @@ pub async fn upsert_route_item(context: web::Data<ApiContext>, path: web::Path<(
     }
 }
 
+#[post("/missions/{mission_id}/edit_route")]
+pub async fn edit_route(context: web::Data<ApiContext>, path: web::Path<MissionId>, edit: web::Json<RouteEdit>) -> impl Responder {
+    let mission_id = path.into_inner();
+    let edit = edit.into_inner();
+    let result = context.dal.edit_route(&mission_id, edit).await;
+
+    match result {
+        Ok(route) => HttpResponse::Ok().json(route),
+        Err(err) => {
+            log::warn!("REST error: {}", &err); // TODO: add path here
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
 #[delete("/missions/{mission_id}/remove_route_item/{index}")]
 pub async fn remove_route_item(context: web::Data<ApiContext>, path: web::Path<(MissionId, u16)>) -> impl Responder {
     let (mission_id, index) = path.into_inner();
@@ -46,6 +76,76 @@ pub async fn remove_route_item(context: web::Data<ApiContext>, path: web::Path<(
     }
 }
 
+#[get("/missions/{mission_id}/revisions")]
+pub async fn get_revisions(context: web::Data<ApiContext>, path: web::Path<MissionId>) -> impl Responder {
+    let mission_id = path.into_inner();
+    let result = context.dal.mission_revisions(&mission_id).await;
+
+    match result {
+        Ok(revisions) => HttpResponse::Ok().json(revisions),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/{mission_id}/undo")]
+pub async fn undo_route(context: web::Data<ApiContext>, path: web::Path<MissionId>) -> impl Responder {
+    let mission_id = path.into_inner();
+    let result = context.dal.undo_route(&mission_id).await;
+
+    match result {
+        Ok(route) => HttpResponse::Ok().json(route),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/{mission_id}/redo")]
+pub async fn redo_route(context: web::Data<ApiContext>, path: web::Path<MissionId>) -> impl Responder {
+    let mission_id = path.into_inner();
+    let result = context.dal.redo_route(&mission_id).await;
+
+    match result {
+        Ok(route) => HttpResponse::Ok().json(route),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/missions/{mission_id}/restore/{revision_id}")]
+pub async fn restore_revision(context: web::Data<ApiContext>, path: web::Path<(MissionId, MissionRevisionId)>) -> impl Responder {
+    let (mission_id, revision_id) = path.into_inner();
+    let result = context.dal.restore_route_revision(&mission_id, &revision_id).await;
+
+    match result {
+        Ok(route) => HttpResponse::Ok().json(route),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/missions/{mission_id}/vehicle_diff")]
+pub async fn get_vehicle_diff(context: web::Data<ApiContext>, path: web::Path<MissionId>) -> impl Responder {
+    let mission_id = path.into_inner();
+    let result = context.dal.vehicle_route_diff(&mission_id).await;
+
+    match result {
+        Ok(diff) => HttpResponse::Ok().json(diff),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
 #[put("/missions/download/{mission_id}")]
 pub async fn download_mission(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
     let mission_id: MissionId = path.into_inner();
@@ -63,6 +163,15 @@ pub async fn download_mission(context: web::Data<ApiContext>, path: web::Path<St
 pub async fn upload_mission(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
     let mission_id: MissionId = path.into_inner();
 
+    match validation::check_upload(&context.dal, &mission_id).await {
+        Ok(Some(report)) => return HttpResponse::UnprocessableEntity().json(report),
+        Ok(None) => {},
+        Err(err) => {
+            log::warn!("REST: error {}", &err);
+            return HttpResponse::InternalServerError().json(err.to_string());
+        }
+    }
+
     match context.client_bus.publish(ClientEvent::UploadMission { mission_id: mission_id.clone() } ) {
         Ok(_) => HttpResponse::Ok().json(mission_id),
         Err(err) => {
@@ -83,14 +192,12 @@ pub async fn clear_mission(context: web::Data<ApiContext>, path: web::Path<Strin
     }
     let mut mission = mission.unwrap();
 
-    mission.route.items.clear();
-
-    let mission = context.dal.update_mission(mission).await;
-    if let Err(err) = mission {
+    let route = context.dal.edit_route(&mission_id, RouteEdit::Replace { items: Vec::new() }).await;
+    if let Err(err) = route {
         log::warn!("REST: error {}", &err);
         return HttpResponse::InternalServerError().json(err.to_string());
     }
-    let mission = mission.unwrap();
+    mission.route = route.unwrap();
 
     match context.client_bus.publish(ClientEvent::ClearMission { mission_id } ) {
         Ok(_) => HttpResponse::Ok().json(mission),
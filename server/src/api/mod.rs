@@ -3,6 +3,21 @@ mod context;
 
 mod communication;
 mod vehicles;
+mod payloads;
+mod telemetry;
+mod messages;
 mod commands;
+mod preflight;
+mod alerts;
+mod failsafe;
+mod flights;
+mod maintenance;
 mod missions;
+mod mission_plans;
+mod mission_assignments;
+mod mission_patterns;
+mod mission_validation;
+mod spatial;
+mod terrain;
+mod captures;
 mod websocket;
\ No newline at end of file
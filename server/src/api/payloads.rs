@@ -0,0 +1,70 @@
+use actix_web::{get, post, delete, web, Responder, HttpResponse};
+
+use crate::models::{payloads::Payload, vehicles::{PayloadId, VehicleId}};
+use super::context::ApiContext;
+
+#[post("/payloads/save")]
+pub async fn post_payload(context: web::Data<ApiContext>, payload: web::Json<Payload>) -> impl Responder {
+    let payload = payload.into_inner();
+    let result = context.dal.save_payload(payload).await;
+
+    match result {
+        Ok(payload) => HttpResponse::Ok().json(payload),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[delete("/payloads/remove/{payload_id}")]
+pub async fn delete_payload(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let payload_id: PayloadId = path.into_inner();
+
+    if let Err(err) = context.dal.delete_payload(&payload_id).await {
+        log::warn!("REST error: {}", &err);
+        return HttpResponse::InternalServerError().json(err.to_string())
+    }
+    HttpResponse::Ok().json(payload_id)
+}
+
+#[get("/payloads/payload/{payload_id}")]
+pub async fn get_payload(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let payload_id: PayloadId = path.into_inner();
+    let result = context.dal.payload(&payload_id).await;
+
+    match result {
+        Ok(payload) => HttpResponse::Ok().json(payload),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/payloads/vehicle/{vehicle_id}")]
+pub async fn get_vehicle_payloads(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.vehicle_payloads(&vehicle_id).await;
+
+    match result {
+        Ok(payloads) => HttpResponse::Ok().json(payloads),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/payloads/payloads")]
+pub async fn get_payloads(context: web::Data<ApiContext>) -> impl Responder {
+    let result = context.dal.all_payloads().await;
+
+    match result {
+        Ok(payloads) => HttpResponse::Ok().json(payloads),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
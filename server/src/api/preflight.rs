@@ -0,0 +1,77 @@
+use actix_web::{get, post, web, Responder, HttpResponse};
+
+use crate::models::{preflight::PreflightSettings, vehicles::VehicleId};
+use crate::services::preflight::checklist;
+use super::context::ApiContext;
+
+#[post("/preflight/run/{vehicle_id}")]
+pub async fn run_preflight(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = checklist::run_preflight(&context.dal, &vehicle_id).await;
+
+    match result {
+        Ok((report, _)) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/preflight/report/{vehicle_id}")]
+pub async fn get_preflight_report(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.preflight_report(&vehicle_id).await;
+
+    match result {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/preflight/settings")]
+pub async fn get_preflight_settings(context: web::Data<ApiContext>) -> impl Responder {
+    let result = context.dal.all_preflight_settings().await;
+
+    match result {
+        Ok(settings) => HttpResponse::Ok().json(settings),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/preflight/settings/{vehicle_id}")]
+pub async fn get_vehicle_preflight_settings(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = match context.dal.vehicle(&vehicle_id).await {
+        Ok(vehicle) => context.dal.preflight_settings(&vehicle.vehicle_type).await,
+        Err(err) => Err(err)
+    };
+
+    match result {
+        Ok(settings) => HttpResponse::Ok().json(settings),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/preflight/settings/save")]
+pub async fn post_preflight_settings(context: web::Data<ApiContext>, settings: web::Json<PreflightSettings>) -> impl Responder {
+    let settings = settings.into_inner();
+    let result = context.dal.save_preflight_settings(settings).await;
+
+    match result {
+        Ok(settings) => HttpResponse::Ok().json(settings),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use actix_web::{post, web, Responder, HttpResponse};
+
+use crate::models::spatial::*;
+use crate::models::vehicles::VehicleId;
+use crate::services::spatial::altitude;
+use super::context::ApiContext;
+
+#[post("/spatial/convert/{vehicle_id}")]
+pub async fn convert_for_vehicle(context: web::Data<ApiContext>, path: web::Path<String>, request: web::Json<FrameConversionRequest>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let request = request.into_inner();
+    let result = altitude::convert_for_vehicle(&context.dal, &vehicle_id, &request.position, request.frame).await;
+
+    match result {
+        Ok(Some(position)) => HttpResponse::Ok().json(position),
+        Ok(None) => HttpResponse::UnprocessableEntity().json("No terrain or home altitude for conversion"),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/spatial/measure")]
+pub async fn measure(request: web::Json<MeasureRequest>) -> impl Responder {
+    let MeasureRequest { from, to } = request.into_inner();
+    HttpResponse::Ok().json(Measure {
+        distance: from.distance_to(&to),
+        bearing: from.bearing_to(&to),
+        offset: from.ned_to(&to)
+    })
+}
+
+#[post("/spatial/track")]
+pub async fn track_offset(request: web::Json<TrackRequest>) -> impl Responder {
+    let TrackRequest { position, start, end } = request.into_inner();
+    HttpResponse::Ok().json(TrackOffset {
+        cross_track: position.cross_track_distance(&start, &end),
+        along_track: position.along_track_distance(&start, &end)
+    })
+}
+
+#[post("/spatial/destination")]
+pub async fn destination(request: web::Json<DestinationRequest>) -> impl Responder {
+    let request = request.into_inner();
+    HttpResponse::Ok().json(request.from.destination(request.bearing, request.distance))
+}
+
+#[post("/spatial/offset")]
+pub async fn offset(request: web::Json<OffsetRequest>) -> impl Responder {
+    let request = request.into_inner();
+    HttpResponse::Ok().json(request.from.offset_ned(&request.offset))
+}
@@ -0,0 +1,32 @@
+use actix_web::{get, web, Responder, HttpResponse};
+
+use crate::models::vehicles::VehicleId;
+use super::context::ApiContext;
+
+#[get("/telemetry/batteries/{vehicle_id}")]
+pub async fn get_batteries(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.telemetry_batteries(&vehicle_id).await;
+
+    match result {
+        Ok(batteries) => HttpResponse::Ok().json(batteries),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/telemetry/engine/{vehicle_id}")]
+pub async fn get_engine(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.telemetry_engine(&vehicle_id).await;
+
+    match result {
+        Ok(engine) => HttpResponse::Ok().json(engine),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use actix_web::{get, post, put, web, Responder, HttpResponse};
+
+use crate::models::{events::ClientEvent, spatial::Geodetic, vehicles::VehicleId};
+use crate::models::terrain::{AltitudeConversionRequest, TerrainProfileRequest};
+use super::context::ApiContext;
+
+// Terrain tiles are read from disk on first need, so queries run off the async workers
+
+#[get("/terrain/elevation/{latitude}/{longitude}")]
+pub async fn get_elevation(context: web::Data<ApiContext>, path: web::Path<(f64, f64)>) -> impl Responder {
+    let (latitude, longitude) = path.into_inner();
+    let terrain = context.dal.terrain.clone();
+    let result = web::block(move || terrain.point_elevation(latitude, longitude)).await;
+
+    match result {
+        Ok(elevation) => HttpResponse::Ok().json(elevation),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/terrain/profile")]
+pub async fn get_profile(context: web::Data<ApiContext>, request: web::Json<TerrainProfileRequest>) -> impl Responder {
+    let request = request.into_inner();
+    let terrain = context.dal.terrain.clone();
+    let result = web::block(move || terrain.profile(&request.path, request.step)).await;
+
+    match result {
+        Ok(profile) => HttpResponse::Ok().json(profile),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[post("/terrain/convert")]
+pub async fn convert_altitude(context: web::Data<ApiContext>, request: web::Json<AltitudeConversionRequest>) -> impl Responder {
+    let request = request.into_inner();
+    let terrain = context.dal.terrain.clone();
+    let result = web::block(move || terrain.convert(&request.position, request.frame, request.home.as_ref())).await;
+
+    match result {
+        Ok(Some(position)) => HttpResponse::Ok().json(position),
+        Ok(None) => HttpResponse::UnprocessableEntity().json("No terrain or home altitude for conversion"),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[put("/terrain/check/{vehicle_id}")]
+pub async fn check_terrain(context: web::Data<ApiContext>, path: web::Path<String>, position: web::Json<Geodetic>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let position = position.into_inner();
+
+    match context.client_bus.publish(ClientEvent::CheckTerrain { vehicle_id: vehicle_id.clone(), position }) {
+        Ok(_) => HttpResponse::Ok().json(vehicle_id),
+        Err(err) => {
+            log::warn!("REST: error {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
+
+#[get("/terrain/report/{vehicle_id}")]
+pub async fn get_terrain_report(context: web::Data<ApiContext>, path: web::Path<String>) -> impl Responder {
+    let vehicle_id: VehicleId = path.into_inner();
+    let result = context.dal.terrain_report(&vehicle_id).await;
+
+    match result {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::warn!("REST error: {}", &err);
+            HttpResponse::InternalServerError().json(err.to_string())
+        }
+    }
+}
@@ -1,11 +1,16 @@
 use actix_web::{get, post, delete, web, Responder, HttpResponse};
 
 use crate::models::vehicles::{VehicleId, VehicleDescription};
+use crate::services::capabilities::validation;
 use super::context::ApiContext;
 
 #[post("/vehicles/save")]
 pub async fn post_vehicle(context: web::Data<ApiContext>, vehicle: web::Json<VehicleDescription>) -> impl Responder {
     let vehicle = vehicle.into_inner();
+    if let Err(rejection) = validation::validate_failsafe(&vehicle.failsafe) {
+        log::warn!("REST: rejected failsafe policy {:?}: {:?}", &vehicle.failsafe, &rejection);
+        return HttpResponse::UnprocessableEntity().json(rejection)
+    }
     let result = context.dal.save_vehicle(vehicle).await;
 
     match result {
@@ -1,16 +1,41 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
 use crate::db::surreal_dao::Dao;
 use crate::bus::bus::EventBus;
 
 use crate::models::events::ServerEvent;
+use crate::models::{alerts::{AlertRule, AlertRuleId}, vehicles::VehicleId};
+use crate::services::terrain::provider::{Terrain, TERRAIN_DIRECTORY};
 
 #[derive(Clone)]
 pub struct Dal {
     pub dao: Dao,
-    pub bus: EventBus<ServerEvent>
+    pub bus: EventBus<ServerEvent>,
+    // Since when an alert rule condition holds for a vehicle, before the alert is raised
+    pub alert_violations: Arc<Mutex<HashMap<(AlertRuleId, VehicleId), i64>>>,
+    // Alert rules are checked on every telemetry save, so they are reloaded only after a rule changes
+    pub alert_rules: Arc<Mutex<Option<Vec<AlertRule>>>>,
+    // Serializes read-modify-write of mission routes
+    pub route_edits: Arc<tokio::sync::Mutex<()>>,
+    // Local DEM tiles, read from disk on first need
+    pub terrain: Terrain
 }
 
 impl Dal {
     pub fn new(dao: Dao, bus: EventBus<ServerEvent>) -> Self {
-        Self { dao, bus }
+        Self {
+            dao,
+            bus,
+            alert_violations: Arc::new(Mutex::new(HashMap::new())),
+            alert_rules: Arc::new(Mutex::new(None)),
+            route_edits: Arc::new(tokio::sync::Mutex::new(())),
+            terrain: Terrain::new(TERRAIN_DIRECTORY)
+        }
+    }
+
+    // Brings records stored by the previous versions to the current layout, run once before serving
+    pub async fn migrate(&self) -> anyhow::Result<()> {
+        self.migrate_mission_assignments().await
     }
 }
@@ -0,0 +1,253 @@
+use super::dal::Dal;
+
+use crate::models::alerts::*;
+use crate::models::events::ServerEvent;
+use crate::models::telemetry::{Battery, Flight, Navigation, RawSns, System};
+use crate::models::vehicles::VehicleId;
+
+const TB_ALERT_RULES: &str = "alert_rules";
+const TB_ALERTS: &str = "alerts";
+pub const MAX_CLEARED_ALERTS: usize = 100; // per vehicle, TODO: to settings
+
+pub type AlertValues = Vec<(AlertParameter, f32)>;
+
+pub fn flight_alert_values(flight: &Flight) -> AlertValues {
+    vec![
+        (AlertParameter::IndicatedAirspeed, flight.indicated_airspeed),
+        (AlertParameter::GroundSpeed, flight.ground_speed),
+        (AlertParameter::AltitudeAmsl, flight.altitude_amsl),
+        (AlertParameter::Climb, flight.climb),
+        (AlertParameter::Throttle, flight.throttle as f32),
+    ]
+}
+
+pub fn navigation_alert_values(navigation: &Navigation) -> AlertValues {
+    vec![
+        (AlertParameter::AltitudeError, navigation.altitiude_error),
+        (AlertParameter::AirspeedError, navigation.airspeed_error),
+        (AlertParameter::XtrackError, navigation.xtrack_error),
+    ]
+}
+
+pub fn raw_sns_alert_values(raw_sns: &RawSns) -> AlertValues {
+    vec![
+        (AlertParameter::SnsFix, raw_sns.fix as f32),
+        (AlertParameter::SatellitesVisible, raw_sns.satellites_visible as f32),
+    ]
+}
+
+pub fn system_alert_values(system: &System) -> AlertValues {
+    let mut values = vec![
+        (AlertParameter::BatteryVoltage, system.battery_voltage),
+        (AlertParameter::BatteryCurrent, system.battery_current),
+        (AlertParameter::RadioRssi, system.radio_rssi as f32),
+        (AlertParameter::RadioRemoteRssi, system.radio_remote_rssi as f32),
+    ];
+    // Negative remaining means it is not estimated by the autopilot
+    if system.battery_remaining >= 0 {
+        values.push((AlertParameter::BatteryRemaining, system.battery_remaining as f32));
+    }
+    values
+}
+
+// The shortest endurance of the vehicle batteries, in seconds
+pub fn batteries_alert_values(batteries: &[Battery]) -> AlertValues {
+    batteries.iter()
+        .filter_map(|battery| battery.estimated_endurance)
+        .reduce(f32::min)
+        .map(|endurance| vec![(AlertParameter::BatteryEndurance, endurance)])
+        .unwrap_or_default()
+}
+
+fn alert_message(rule: &AlertRule, value: f32) -> String {
+    let condition = match rule.condition {
+        AlertCondition::Less => "<",
+        AlertCondition::Greater => ">"
+    };
+    format!("{}: {:?} {} {} {}", rule.name, rule.parameter, value, condition, rule.threshold)
+}
+
+impl Dal {
+    pub async fn save_alert_rule(&self, rule: AlertRule) -> anyhow::Result<AlertRule> {
+        let rule = if rule.id.is_empty() {
+            self.dao.create(TB_ALERT_RULES, rule).await?
+        } else {
+            self.dao.update(TB_ALERT_RULES, rule).await?
+        };
+
+        *self.alert_rules.lock().unwrap() = None;
+
+        self.bus.publish(ServerEvent::AlertRuleUpserted { rule: rule.clone() })?;
+        Ok(rule)
+    }
+
+    pub async fn delete_alert_rule(&self, rule_id: &AlertRuleId) -> anyhow::Result<()> {
+        self.dao.delete(TB_ALERT_RULES, rule_id).await?;
+        *self.alert_rules.lock().unwrap() = None;
+        self.alert_violations.lock().unwrap().retain(|(id, _), _| id != rule_id);
+
+        let alerts: Vec<Alert> = self.dao.select_where(TB_ALERTS, "rule_id", rule_id).await?;
+        for alert in alerts.into_iter().filter(|alert| alert.state != AlertState::Cleared) {
+            self.clear_alert(alert).await?;
+        }
+
+        self.bus.publish(ServerEvent::AlertRuleRemoved { rule_id: rule_id.into() })?;
+        Ok(())
+    }
+
+    pub async fn alert_rule(&self, rule_id: &AlertRuleId) -> anyhow::Result<AlertRule> {
+        self.dao.select_one(TB_ALERT_RULES, rule_id).await
+    }
+
+    pub async fn all_alert_rules(&self) -> anyhow::Result<Vec<AlertRule>> {
+        if let Some(rules) = self.alert_rules.lock().unwrap().clone() {
+            return Ok(rules);
+        }
+
+        let rules: Vec<AlertRule> = self.dao.select_all(TB_ALERT_RULES).await?;
+        *self.alert_rules.lock().unwrap() = Some(rules.clone());
+        Ok(rules)
+    }
+
+    pub async fn alert(&self, alert_id: &AlertId) -> anyhow::Result<Alert> {
+        self.dao.select_one(TB_ALERTS, alert_id).await
+    }
+
+    pub async fn vehicle_alerts(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<Alert>> {
+        let mut alerts: Vec<Alert> = self.dao.select_where(TB_ALERTS, "vehicle_id", vehicle_id).await?;
+        alerts.sort_by_key(|alert| alert.raised);
+        Ok(alerts)
+    }
+
+    pub async fn active_alerts(&self) -> anyhow::Result<Vec<Alert>> {
+        let alerts: Vec<Alert> = self.dao.select_all(TB_ALERTS).await?;
+        Ok(alerts.into_iter().filter(|alert| alert.state != AlertState::Cleared).collect())
+    }
+
+    pub async fn acknowledge_alert(&self, alert_id: &AlertId) -> anyhow::Result<Alert> {
+        let mut alert = self.alert(alert_id).await?;
+        if alert.state != AlertState::Raised {
+            return Err(anyhow::anyhow!("Alert {} is not raised", alert_id));
+        }
+
+        alert.state = AlertState::Acknowledged;
+        alert.acknowledged = Some(chrono::Utc::now().timestamp_millis());
+        let alert = self.dao.update(TB_ALERTS, alert).await?;
+
+        self.bus.publish(ServerEvent::AlertAcknowledged { alert: alert.clone() })?;
+        Ok(alert)
+    }
+
+    async fn clear_alert(&self, mut alert: Alert) -> anyhow::Result<Alert> {
+        alert.state = AlertState::Cleared;
+        alert.cleared = Some(chrono::Utc::now().timestamp_millis());
+        let alert = self.dao.update(TB_ALERTS, alert).await?;
+        self.prune_cleared_alerts(&alert.vehicle_id).await?;
+
+        self.bus.publish(ServerEvent::AlertCleared { alert: alert.clone() })?;
+        Ok(alert)
+    }
+
+    // Keep only the newest cleared alerts of the vehicle in the history
+    async fn prune_cleared_alerts(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
+        let mut cleared: Vec<Alert> = self.vehicle_alerts(vehicle_id).await?.into_iter()
+            .filter(|alert| alert.state == AlertState::Cleared)
+            .collect();
+        cleared.sort_by_key(|alert| alert.cleared);
+
+        let excess = cleared.len().saturating_sub(MAX_CLEARED_ALERTS);
+        for alert in &cleared[..excess] {
+            self.dao.delete(TB_ALERTS, &alert.id).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn clear_vehicle_alerts(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
+        self.alert_violations.lock().unwrap().retain(|(_, id), _| id != vehicle_id);
+
+        for alert in self.vehicle_alerts(vehicle_id).await? {
+            if alert.state != AlertState::Cleared {
+                self.clear_alert(alert).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn raise_alert(&self, rule: &AlertRule, vehicle_id: &VehicleId, value: f32) -> anyhow::Result<Alert> {
+        let alert = self.dao.create(TB_ALERTS, Alert {
+            id: String::new(),
+            rule_id: rule.id.clone(),
+            vehicle_id: vehicle_id.clone(),
+            severity: rule.severity.clone(),
+            message: alert_message(rule, value),
+            value,
+            state: AlertState::Raised,
+            raised: chrono::Utc::now().timestamp_millis(),
+            acknowledged: None,
+            cleared: None
+        }).await?;
+
+        self.bus.publish(ServerEvent::AlertRaised { alert: alert.clone() })?;
+        Ok(alert)
+    }
+
+    async fn rules_for_vehicle(&self, vehicle_id: &VehicleId, values: &AlertValues) -> anyhow::Result<Vec<AlertRule>> {
+        let rules: Vec<AlertRule> = self.all_alert_rules().await?.into_iter()
+            .filter(|rule| rule.enabled && values.iter().any(|(parameter, _)| parameter == &rule.parameter))
+            .collect();
+        if rules.is_empty() {
+            return Ok(rules);
+        }
+
+        let vehicle_type = match rules.iter().any(|rule| matches!(rule.scope, AlertRuleScope::VehicleType { .. })) {
+            true => self.vehicle(vehicle_id).await.ok().map(|vehicle| vehicle.vehicle_type),
+            false => None
+        };
+        Ok(rules.into_iter().filter(|rule| match &rule.scope {
+            AlertRuleScope::All {} => true,
+            AlertRuleScope::VehicleType { vehicle_type: rule_type } => vehicle_type.as_ref() == Some(rule_type),
+            AlertRuleScope::Vehicle { vehicle_id: rule_vehicle_id } => rule_vehicle_id == vehicle_id
+        }).collect())
+    }
+
+    pub async fn evaluate_alert_rules(&self, vehicle_id: &VehicleId, values: AlertValues) -> anyhow::Result<()> {
+        let rules = self.rules_for_vehicle(vehicle_id, &values).await?;
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let active_alerts: Vec<Alert> = self.vehicle_alerts(vehicle_id).await?.into_iter()
+            .filter(|alert| alert.state != AlertState::Cleared)
+            .collect();
+
+        for rule in rules {
+            let value = match values.iter().find(|(parameter, _)| parameter == &rule.parameter) {
+                Some((_, value)) => *value,
+                None => continue
+            };
+            let active_alert = active_alerts.iter().find(|alert| alert.rule_id == rule.id);
+            let key = (rule.id.clone(), vehicle_id.clone());
+
+            if rule.is_violated(value) {
+                let since = *self.alert_violations.lock().unwrap().entry(key).or_insert(now);
+                if active_alert.is_none() && (now - since) as f32 >= rule.duration * 1000.0 {
+                    self.raise_alert(&rule, vehicle_id, value).await?;
+                }
+            } else {
+                self.alert_violations.lock().unwrap().remove(&key);
+                if let Some(alert) = active_alert {
+                    self.clear_alert(alert.clone()).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Alerts are a side effect of telemetry, so their errors must not fail the telemetry save
+    pub async fn check_alert_rules(&self, vehicle_id: &VehicleId, values: AlertValues) {
+        if let Err(err) = self.evaluate_alert_rules(vehicle_id, values).await {
+            log::warn!("Alert rules evaluation error for vehicle {}: {}", vehicle_id, err);
+        }
+    }
+}
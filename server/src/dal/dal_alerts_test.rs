@@ -0,0 +1,215 @@
+use test_case::test_case;
+
+use crate::dal::{dal, test_utils::in_memory_dal};
+use crate::dal::dal_alerts::MAX_CLEARED_ALERTS;
+
+use crate::models::alerts::*;
+use crate::models::events::ServerEvent;
+use crate::models::telemetry::{Battery, System};
+use crate::models::vehicles::VehicleId;
+
+fn low_battery_rule(scope: AlertRuleScope, duration: f32) -> AlertRule {
+    AlertRule {
+        id: String::new(),
+        name: "Low battery".into(),
+        scope,
+        parameter: AlertParameter::BatteryRemaining,
+        condition: AlertCondition::Less,
+        threshold: 25.0,
+        duration,
+        severity: AlertSeverity::Warning,
+        enabled: true
+    }
+}
+
+async fn save_battery_remaining(dal: &dal::Dal, vehicle_id: &VehicleId, remaining: i8) {
+    let mut system = System::default_for_id(vehicle_id);
+    system.battery_remaining = remaining;
+    dal.save_telemetry_system(vehicle_id.clone(), system).await.expect("Error saving system telemetry");
+}
+
+fn alert_events(rx: &mut tokio::sync::broadcast::Receiver<ServerEvent>) -> Vec<ServerEvent> {
+    let mut events = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        match event {
+            ServerEvent::AlertRaised { .. } | ServerEvent::AlertAcknowledged { .. } | ServerEvent::AlertCleared { .. } =>
+                events.push(event),
+            _ => {}
+        }
+    }
+    events
+}
+
+#[tokio::test]
+async fn test_alert_lifecycle() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+    let rule = dal.save_alert_rule(low_battery_rule(AlertRuleScope::All {}, 0.0)).await
+        .expect("Error saving alert rule");
+
+    save_battery_remaining(&dal, &vehicle_id, 80).await;
+    assert!(alert_events(&mut rx).is_empty());
+
+    // Raise
+    save_battery_remaining(&dal, &vehicle_id, 20).await;
+    let alert = match alert_events(&mut rx).as_slice() {
+        [ServerEvent::AlertRaised { alert }] => alert.clone(),
+        events => panic!("Unexpected events: {:?}", events)
+    };
+    assert_eq!(alert.rule_id, rule.id);
+    assert_eq!(alert.vehicle_id, vehicle_id);
+    assert_eq!(alert.state, AlertState::Raised);
+    assert_eq!(alert.value, 20.0);
+
+    // No duplicates while still violated
+    save_battery_remaining(&dal, &vehicle_id, 19).await;
+    assert!(alert_events(&mut rx).is_empty());
+
+    // Acknowledge
+    let acknowledged = dal.acknowledge_alert(&alert.id).await.expect("Error acknowledging alert");
+    assert_eq!(acknowledged.state, AlertState::Acknowledged);
+    assert!(acknowledged.acknowledged.is_some());
+    assert!(dal.acknowledge_alert(&alert.id).await.is_err());
+    assert_eq!(alert_events(&mut rx).len(), 1);
+
+    // Clear
+    save_battery_remaining(&dal, &vehicle_id, 30).await;
+    match alert_events(&mut rx).as_slice() {
+        [ServerEvent::AlertCleared { alert: cleared }] => {
+            assert_eq!(cleared.id, alert.id);
+            assert_eq!(cleared.state, AlertState::Cleared);
+        },
+        events => panic!("Unexpected events: {:?}", events)
+    };
+
+    assert!(dal.active_alerts().await.expect("Error reading alerts").is_empty());
+    assert_eq!(dal.vehicle_alerts(&vehicle_id).await.expect("Error reading alerts").len(), 1);
+}
+
+#[test_case(AlertRuleScope::All {}, true; "all vehicles")]
+#[test_case(AlertRuleScope::Vehicle { vehicle_id: "mav_1".into() }, true; "same vehicle")]
+#[test_case(AlertRuleScope::Vehicle { vehicle_id: "mav_2".into() }, false; "other vehicle")]
+#[tokio::test]
+async fn test_alert_rule_scope(scope: AlertRuleScope, raised: bool) {
+    let (dal, mut rx) = in_memory_dal().await;
+    dal.save_alert_rule(low_battery_rule(scope, 0.0)).await.expect("Error saving alert rule");
+
+    save_battery_remaining(&dal, &"mav_1".to_string(), 10).await;
+    assert_eq!(alert_events(&mut rx).len(), raised as usize);
+}
+
+#[tokio::test]
+async fn test_alert_rule_duration() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+    dal.save_alert_rule(low_battery_rule(AlertRuleScope::All {}, 5.0)).await.expect("Error saving alert rule");
+
+    // Condition must hold for the duration before raising
+    save_battery_remaining(&dal, &vehicle_id, 10).await;
+    save_battery_remaining(&dal, &vehicle_id, 10).await;
+    assert!(alert_events(&mut rx).is_empty());
+    assert_eq!(dal.alert_violations.lock().unwrap().len(), 1);
+
+    save_battery_remaining(&dal, &vehicle_id, 50).await;
+    assert!(dal.alert_violations.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_alert_rule_changes_reload_rules() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+    let rule = dal.save_alert_rule(low_battery_rule(AlertRuleScope::All {}, 0.0)).await.expect("Error saving alert rule");
+
+    save_battery_remaining(&dal, &vehicle_id, 30).await;
+    assert!(dal.alert_rules.lock().unwrap().is_some());
+    assert!(alert_events(&mut rx).is_empty());
+
+    dal.save_alert_rule(AlertRule { threshold: 50.0, ..rule }).await.expect("Error saving alert rule");
+    assert!(dal.alert_rules.lock().unwrap().is_none());
+
+    save_battery_remaining(&dal, &vehicle_id, 30).await;
+    assert_eq!(alert_events(&mut rx).len(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_alert_rule_clears_alerts() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+    let rule = dal.save_alert_rule(low_battery_rule(AlertRuleScope::All {}, 0.0)).await.expect("Error saving alert rule");
+
+    save_battery_remaining(&dal, &vehicle_id, 10).await;
+    assert_eq!(dal.active_alerts().await.expect("Error reading alerts").len(), 1);
+    alert_events(&mut rx);
+
+    dal.delete_alert_rule(&rule.id).await.expect("Error deleting alert rule");
+    assert!(matches!(alert_events(&mut rx).as_slice(), [ServerEvent::AlertCleared { .. }]));
+    assert!(dal.active_alerts().await.expect("Error reading alerts").is_empty());
+}
+
+#[tokio::test]
+async fn test_clear_vehicle_alerts() {
+    let (dal, mut rx) = in_memory_dal().await;
+    dal.save_alert_rule(low_battery_rule(AlertRuleScope::All {}, 0.0)).await.expect("Error saving alert rule");
+    dal.save_alert_rule(low_battery_rule(AlertRuleScope::All {}, 60.0)).await.expect("Error saving alert rule");
+
+    save_battery_remaining(&dal, &"mav_1".to_string(), 10).await;
+    save_battery_remaining(&dal, &"mav_2".to_string(), 10).await;
+    assert_eq!(dal.active_alerts().await.expect("Error reading alerts").len(), 2);
+    assert_eq!(dal.alert_violations.lock().unwrap().len(), 4);
+    alert_events(&mut rx);
+
+    dal.clear_vehicle_alerts(&"mav_1".to_string()).await.expect("Error clearing alerts");
+    assert_eq!(alert_events(&mut rx).len(), 1);
+    let active = dal.active_alerts().await.expect("Error reading alerts");
+    assert_eq!(active.len(), 1);
+    assert_eq!(active[0].vehicle_id, "mav_2");
+    assert_eq!(dal.alert_violations.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_cleared_alerts_are_pruned() {
+    let (dal, _rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+    dal.save_alert_rule(low_battery_rule(AlertRuleScope::All {}, 0.0)).await.expect("Error saving alert rule");
+
+    for _ in 0..MAX_CLEARED_ALERTS + 2 {
+        save_battery_remaining(&dal, &vehicle_id, 10).await;
+        save_battery_remaining(&dal, &vehicle_id, 50).await;
+    }
+    save_battery_remaining(&dal, &vehicle_id, 10).await;
+
+    let alerts = dal.vehicle_alerts(&vehicle_id).await.expect("Error reading alerts");
+    assert_eq!(alerts.len(), MAX_CLEARED_ALERTS + 1);
+    assert_eq!(alerts.iter().filter(|alert| alert.state == AlertState::Cleared).count(), MAX_CLEARED_ALERTS);
+}
+
+async fn save_battery_endurance(dal: &dal::Dal, vehicle_id: &VehicleId, battery_id: u8, endurance: Option<f32>) {
+    let mut battery = Battery::default_for_battery(vehicle_id, battery_id);
+    battery.estimated_endurance = endurance;
+    dal.save_telemetry_battery(vehicle_id.clone(), battery).await.expect("Error saving battery telemetry");
+}
+
+#[tokio::test]
+async fn test_battery_endurance_alert() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+    dal.save_alert_rule(AlertRule {
+        parameter: AlertParameter::BatteryEndurance,
+        threshold: 300.0,
+        ..low_battery_rule(AlertRuleScope::All {}, 0.0)
+    }).await.expect("Error saving alert rule");
+
+    save_battery_endurance(&dal, &vehicle_id, 0, Some(900.0)).await;
+    save_battery_endurance(&dal, &vehicle_id, 1, None).await;
+    assert!(alert_events(&mut rx).is_empty());
+
+    // The shortest endurance of all batteries is checked
+    save_battery_endurance(&dal, &vehicle_id, 1, Some(240.0)).await;
+    match alert_events(&mut rx).as_slice() {
+        [ServerEvent::AlertRaised { alert }] => assert_eq!(alert.value, 240.0),
+        events => panic!("Unexpected events: {:?}", events)
+    };
+
+    dal.delete_telemetry_batteries(&vehicle_id).await.expect("Error deleting batteries");
+    assert!(dal.telemetry_batteries(&vehicle_id).await.expect("Error reading batteries").is_empty());
+}
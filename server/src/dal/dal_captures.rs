@@ -0,0 +1,45 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::captures::{CaptureId, CapturedImage};
+use crate::models::flights::FlightSessionId;
+use crate::models::missions::MissionId;
+use crate::models::vehicles::VehicleId;
+
+const TB_CAPTURED_IMAGES: &str = "captured_images";
+
+impl Dal {
+    pub async fn save_captured_image(&self, image: CapturedImage) -> anyhow::Result<CapturedImage> {
+        let image = if image.id.is_empty() {
+            self.dao.create(TB_CAPTURED_IMAGES, image).await?
+        } else {
+            self.dao.update(TB_CAPTURED_IMAGES, image).await?
+        };
+
+        self.bus.publish(ServerEvent::ImageCaptured { image: image.clone() })?;
+        Ok(image)
+    }
+
+    pub async fn delete_captured_image(&self, capture_id: &CaptureId) -> anyhow::Result<()> {
+        self.dao.delete(TB_CAPTURED_IMAGES, capture_id).await?;
+
+        self.bus.publish(ServerEvent::CapturedImageRemoved { capture_id: capture_id.into() })?;
+        Ok(())
+    }
+
+    pub async fn captured_image(&self, capture_id: &CaptureId) -> anyhow::Result<CapturedImage> {
+        self.dao.select_one(TB_CAPTURED_IMAGES, capture_id).await
+    }
+
+    pub async fn mission_captured_images(&self, mission_id: &MissionId) -> anyhow::Result<Vec<CapturedImage>> {
+        self.dao.select_where(TB_CAPTURED_IMAGES, "mission_id", mission_id).await
+    }
+
+    pub async fn flight_captured_images(&self, session_id: &FlightSessionId) -> anyhow::Result<Vec<CapturedImage>> {
+        self.dao.select_where(TB_CAPTURED_IMAGES, "flight_session_id", session_id).await
+    }
+
+    pub async fn vehicle_captured_images(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<CapturedImage>> {
+        self.dao.select_where(TB_CAPTURED_IMAGES, "vehicle_id", vehicle_id).await
+    }
+}
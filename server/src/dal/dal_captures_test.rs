@@ -0,0 +1,118 @@
+use crate::dal::test_utils::in_memory_dal;
+
+use crate::models::captures::CapturedImage;
+use crate::models::flights::FlightSession;
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use crate::models::events::ServerEvent;
+
+fn test_image(vehicle_id: &str, mission_id: Option<&str>, index: i32) -> CapturedImage {
+    CapturedImage {
+        id: String::new(),
+        vehicle_id: vehicle_id.into(),
+        payload_id: "camera_1".into(),
+        mission_id: mission_id.map(|id| id.into()),
+        flight_session_id: None,
+        timestamp: 1700000000000 + index as i64,
+        index,
+        position: Geodetic {
+            latitude: 55.9,
+            longitude: 37.8,
+            altitude: 150.0,
+            frame: GeodeticFrame::Wgs84AboveSeaLevel
+        },
+        relative_altitude: 120.0,
+        roll: 0.0,
+        pitch: -90.0,
+        yaw: 45.0,
+        success: true,
+        file_url: String::new()
+    }
+}
+
+#[tokio::test]
+async fn test_captured_images() {
+    let (dal, mut rx) = in_memory_dal().await;
+
+    let mut saved_images = Vec::new();
+    for image in [
+        test_image("mav_1", Some("mission_1"), 0),
+        test_image("mav_1", Some("mission_1"), 1),
+        test_image("mav_1", None, 2),
+        test_image("mav_2", Some("mission_2"), 0)
+    ] {
+        let saved = dal.save_captured_image(image).await.expect("Error saving captured image");
+        assert_ne!(saved.id.len(), 0);
+
+        match rx.recv().await.expect("Error receiving event") {
+            ServerEvent::ImageCaptured{ image } => assert_eq!(saved, image),
+            _ => panic!("Unexpected event")
+        }
+        saved_images.push(saved);
+    }
+
+    let mission_images = dal.mission_captured_images(&"mission_1".to_string()).await
+        .expect("Error reading mission images");
+    assert_eq!(mission_images.len(), 2);
+    assert!(mission_images.iter().all(|image| image.mission_id == Some("mission_1".into())));
+
+    let vehicle_images = dal.vehicle_captured_images(&"mav_1".to_string()).await
+        .expect("Error reading vehicle images");
+    assert_eq!(vehicle_images.len(), 3);
+
+    let removed = &saved_images[0];
+    dal.delete_captured_image(&removed.id).await.expect("Error deleting captured image");
+
+    match rx.recv().await.expect("Error receiving event") {
+        ServerEvent::CapturedImageRemoved{ capture_id } => assert_eq!(removed.id, capture_id),
+        _ => panic!("Unexpected event")
+    }
+
+    let mission_images = dal.mission_captured_images(&"mission_1".to_string()).await
+        .expect("Error reading mission images");
+    assert_eq!(mission_images.len(), 1);
+}
+
+#[tokio::test]
+async fn test_flight_captured_images() {
+    let (dal, _rx) = in_memory_dal().await;
+
+    for (index, session_id) in [Some("flight_1"), Some("flight_1"), Some("flight_2"), None].iter().enumerate() {
+        let image = CapturedImage {
+            flight_session_id: session_id.map(|id| id.into()),
+            ..test_image("mav_1", Some("mission_1"), index as i32)
+        };
+        dal.save_captured_image(image).await.expect("Error saving captured image");
+    }
+
+    let flight_images = dal.flight_captured_images(&"flight_1".to_string()).await
+        .expect("Error reading flight images");
+    assert_eq!(flight_images.len(), 2);
+    assert!(flight_images.iter().all(|image| image.flight_session_id == Some("flight_1".into())));
+}
+
+#[tokio::test]
+async fn test_active_flight_session() {
+    let (dal, _rx) = in_memory_dal().await;
+
+    let mut session = FlightSession {
+        id: String::new(),
+        vehicle_id: "mav_1".into(),
+        mission_id: None,
+        takeoff_time: 1700000000000,
+        landing_time: None,
+        duration: 0.0,
+        distance: 0.0,
+        max_altitude: 0.0,
+        takeoff_position: Geodetic::default(),
+        landing_position: None
+    };
+    let session_id = dal.save_flight_session(session.clone()).await.expect("Error saving session").id;
+
+    let active = dal.active_flight_session(&"mav_1".into()).await.expect("Error reading active session");
+    assert_eq!(active.map(|session| session.id), Some(session_id.clone()));
+
+    session.id = session_id;
+    session.landing_time = Some(1700000600000);
+    dal.save_flight_session(session).await.expect("Error saving session");
+    assert_eq!(dal.active_flight_session(&"mav_1".into()).await.expect("Error reading active session"), None);
+}
@@ -1,9 +1,11 @@
 use super::dal::Dal;
 
 use crate::models::events::ServerEvent;
-use crate::models::commands::{CommandExecution, CommandId};
+use crate::models::commands::{CommandConfirmation, CommandExecution, CommandId, ConfirmationToken, ExecuteCommandRequest};
 
 const TB_COMMANDS_EXECUTIONS: &str = "command_executions";
+const TB_COMMANDS_CONFIRMATIONS: &str = "command_confirmations";
+const CONFIRMATION_TIMEOUT_MS: i64 = 30000;
 
 impl Dal {
     pub fn update_command_execution(&self, execution: CommandExecution) -> anyhow::Result<()> {
@@ -33,4 +35,29 @@ impl Dal {
     pub async fn all_command_executions(&self) -> anyhow::Result<Vec<CommandExecution>> {
         self.dao.select_all(TB_COMMANDS_EXECUTIONS).await
     }
+
+    pub async fn create_command_confirmation(&self, request: ExecuteCommandRequest) -> anyhow::Result<CommandConfirmation> {
+        self.purge_expired_confirmations().await?;
+        self.dao.create(TB_COMMANDS_CONFIRMATIONS, CommandConfirmation {
+            id: String::new(),
+            request,
+            expires: chrono::Utc::now().timestamp_millis() + CONFIRMATION_TIMEOUT_MS
+        }).await
+    }
+
+    // Confirmation token can be used only once, returns None if it is unknown or expired
+    pub async fn take_command_confirmation(&self, token: &ConfirmationToken) -> anyhow::Result<Option<CommandConfirmation>> {
+        let confirmation: Option<CommandConfirmation> = self.dao.take(TB_COMMANDS_CONFIRMATIONS, token).await?;
+        Ok(confirmation.filter(|confirmation| confirmation.expires >= chrono::Utc::now().timestamp_millis()))
+    }
+
+    // Tokens requested but never confirmed are dropped when next one is requested
+    async fn purge_expired_confirmations(&self) -> anyhow::Result<()> {
+        let now = chrono::Utc::now().timestamp_millis();
+        let confirmations: Vec<CommandConfirmation> = self.dao.select_all(TB_COMMANDS_CONFIRMATIONS).await?;
+        for confirmation in confirmations.iter().filter(|confirmation| confirmation.expires < now) {
+            self.dao.delete(TB_COMMANDS_CONFIRMATIONS, &confirmation.id).await?;
+        }
+        Ok(())
+    }
 }
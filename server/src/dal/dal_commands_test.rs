@@ -0,0 +1,81 @@
+use crate::dal::test_utils::in_memory_dal;
+
+use crate::models::commands::{Command, CommandConfirmation, CommandExecutor, ExecuteCommandRequest};
+
+fn termination_request() -> ExecuteCommandRequest {
+    ExecuteCommandRequest {
+        command: Command::FlightTermination {},
+        executor: CommandExecutor::Vehicle { vehicle_id: "mav_1".into() }
+    }
+}
+
+#[tokio::test]
+async fn test_confirmation_used_once() {
+    let (dal, _) = in_memory_dal().await;
+
+    let confirmation = dal.create_command_confirmation(termination_request()).await
+        .expect("Error creating confirmation");
+    assert_ne!(confirmation.id.len(), 0);
+    assert!(confirmation.expires > chrono::Utc::now().timestamp_millis());
+
+    let taken = dal.take_command_confirmation(&confirmation.id).await
+        .expect("Error taking confirmation");
+    assert_eq!(taken, Some(confirmation.clone()));
+
+    let taken_again = dal.take_command_confirmation(&confirmation.id).await
+        .expect("Error taking confirmation");
+    assert_eq!(taken_again, None);
+}
+
+#[tokio::test]
+async fn test_concurrent_confirmations_taken_once() {
+    let (dal, _) = in_memory_dal().await;
+
+    let confirmation = dal.create_command_confirmation(termination_request()).await
+        .expect("Error creating confirmation");
+
+    let (first, second) = tokio::join!(
+        dal.take_command_confirmation(&confirmation.id),
+        dal.take_command_confirmation(&confirmation.id)
+    );
+    let first = first.expect("Error taking confirmation");
+    let second = second.expect("Error taking confirmation");
+    assert_eq!(first.is_some() as u8 + second.is_some() as u8, 1);
+}
+
+#[tokio::test]
+async fn test_expired_confirmations_purged() {
+    let (dal, _) = in_memory_dal().await;
+
+    let expired: CommandConfirmation = dal.dao.create("command_confirmations", CommandConfirmation {
+        id: "expired".into(),
+        request: termination_request(),
+        expires: chrono::Utc::now().timestamp_millis() - 1000
+    }).await.expect("Error creating confirmation");
+
+    let taken = dal.take_command_confirmation(&expired.id).await
+        .expect("Error taking confirmation");
+    assert_eq!(taken, None);
+
+    let stale: CommandConfirmation = dal.dao.create("command_confirmations", CommandConfirmation {
+        expires: chrono::Utc::now().timestamp_millis() - 1000,
+        ..expired
+    }).await.expect("Error creating confirmation");
+
+    let confirmation = dal.create_command_confirmation(termination_request()).await
+        .expect("Error creating confirmation");
+
+    let remaining: Vec<CommandConfirmation> = dal.dao.select_all("command_confirmations").await
+        .expect("Error selecting confirmations");
+    assert_eq!(remaining, vec![confirmation]);
+    assert_ne!(stale.id, remaining[0].id);
+}
+
+#[tokio::test]
+async fn test_unknown_confirmation() {
+    let (dal, _) = in_memory_dal().await;
+
+    let taken = dal.take_command_confirmation(&"unknown".to_string()).await
+        .expect("Error taking confirmation");
+    assert_eq!(taken, None);
+}
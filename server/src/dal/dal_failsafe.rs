@@ -0,0 +1,49 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::failsafe::*;
+use crate::models::vehicles::VehicleId;
+
+const TB_FAILSAFE_STATUSES: &str = "failsafe_statuses";
+const TB_FAILSAFE_EVENTS: &str = "failsafe_events";
+
+impl Dal {
+    pub async fn failsafe_status(&self, vehicle_id: &VehicleId) -> anyhow::Result<FailsafeStatus> {
+        // No stored status means failsafe never triggered nor was overridden
+        Ok(self.dao.select_optional(TB_FAILSAFE_STATUSES, vehicle_id).await?
+            .unwrap_or_else(|| FailsafeStatus::default_for_id(vehicle_id)))
+    }
+
+    pub async fn update_failsafe_status(&self, status: FailsafeStatus) -> anyhow::Result<FailsafeStatus> {
+        let status = self.dao.update(TB_FAILSAFE_STATUSES, status).await?;
+        self.bus.publish(ServerEvent::FailsafeStatusUpdated { status: status.clone() })?;
+        Ok(status)
+    }
+
+    pub async fn set_failsafe_override(&self, vehicle_id: &VehicleId, overridden: bool) -> anyhow::Result<FailsafeStatus> {
+        let mut status = self.failsafe_status(vehicle_id).await?;
+        status.overridden = overridden;
+        self.update_failsafe_status(status).await
+    }
+
+    pub async fn add_failsafe_event(&self, mut event: FailsafeEvent) -> anyhow::Result<FailsafeEvent> {
+        event.timestamp = chrono::Utc::now().timestamp_millis();
+        let event: FailsafeEvent = self.dao.create(TB_FAILSAFE_EVENTS, event).await?;
+
+        self.bus.publish(ServerEvent::FailsafeTriggered { event: event.clone() })?;
+        Ok(event)
+    }
+
+    pub async fn failsafe_events(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<FailsafeEvent>> {
+        let mut events: Vec<FailsafeEvent> = self.dao.select_where(TB_FAILSAFE_EVENTS, "vehicle_id", vehicle_id).await?;
+        events.sort_by_key(|event| event.timestamp);
+        Ok(events)
+    }
+
+    pub async fn delete_failsafe_data(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
+        for event in self.failsafe_events(vehicle_id).await? {
+            self.dao.delete(TB_FAILSAFE_EVENTS, &event.id).await?;
+        }
+        self.dao.delete(TB_FAILSAFE_STATUSES, vehicle_id).await
+    }
+}
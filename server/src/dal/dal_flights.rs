@@ -0,0 +1,55 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::flights::{FlightSession, FlightSessionId};
+use crate::models::vehicles::VehicleId;
+
+const TB_FLIGHT_SESSIONS: &str = "flight_sessions";
+
+impl Dal {
+    pub async fn save_flight_session(&self, session: FlightSession) -> anyhow::Result<FlightSession> {
+        let session = if session.id.is_empty() {
+            self.dao.create(TB_FLIGHT_SESSIONS, session).await?
+        } else {
+            self.dao.update(TB_FLIGHT_SESSIONS, session).await?
+        };
+
+        self.bus.publish(ServerEvent::FlightSessionUpdated { session: session.clone() })?;
+        Ok(session)
+    }
+
+    pub async fn delete_flight_session(&self, session_id: &FlightSessionId) -> anyhow::Result<()> {
+        self.dao.delete(TB_FLIGHT_SESSIONS, session_id).await?;
+        self.bus.publish(ServerEvent::FlightSessionRemoved { session_id: session_id.into() })?;
+        Ok(())
+    }
+
+    pub async fn delete_vehicle_flight_sessions(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
+        for session in self.vehicle_flight_sessions(vehicle_id).await? {
+            self.delete_flight_session(&session.id).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn flight_session(&self, session_id: &FlightSessionId) -> anyhow::Result<FlightSession> {
+        self.dao.select_one(TB_FLIGHT_SESSIONS, session_id).await
+    }
+
+    pub async fn vehicle_flight_sessions(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<FlightSession>> {
+        let mut sessions: Vec<FlightSession> = self.dao.select_where(TB_FLIGHT_SESSIONS, "vehicle_id", vehicle_id).await?;
+        sessions.sort_by_key(|session| session.takeoff_time);
+        Ok(sessions)
+    }
+
+    // Session of the vehicle which is not landed yet
+    pub async fn active_flight_session(&self, vehicle_id: &VehicleId) -> anyhow::Result<Option<FlightSession>> {
+        let sessions = self.vehicle_flight_sessions(vehicle_id).await?;
+        Ok(sessions.into_iter().rev().find(|session| session.landing_time.is_none()))
+    }
+
+    pub async fn all_flight_sessions(&self) -> anyhow::Result<Vec<FlightSession>> {
+        let mut sessions: Vec<FlightSession> = self.dao.select_all(TB_FLIGHT_SESSIONS).await?;
+        sessions.sort_by_key(|session| session.takeoff_time);
+        Ok(sessions)
+    }
+}
@@ -0,0 +1,84 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::maintenance::*;
+use crate::models::vehicles::VehicleId;
+
+const TB_VEHICLE_COUNTERS: &str = "vehicle_counters";
+const TB_MAINTENANCE_INTERVALS: &str = "maintenance_intervals";
+const TB_MAINTENANCE_ENTRIES: &str = "maintenance_entries";
+
+impl Dal {
+    pub async fn vehicle_counters(&self, vehicle_id: &VehicleId) -> anyhow::Result<VehicleCounters> {
+        // No stored counters means vehicle never armed nor flew
+        Ok(self.dao.select_optional(TB_VEHICLE_COUNTERS, vehicle_id).await?
+            .unwrap_or_else(|| VehicleCounters::default_for_id(vehicle_id)))
+    }
+
+    pub async fn add_vehicle_counters(&self, vehicle_id: &VehicleId, flight_time: f64, cycles: u32, arm_time: f64
+    ) -> anyhow::Result<VehicleCounters> {
+        let mut counters = self.vehicle_counters(vehicle_id).await?;
+        counters.flight_time += flight_time;
+        counters.cycles += cycles;
+        counters.arm_time += arm_time;
+
+        let counters = self.dao.update(TB_VEHICLE_COUNTERS, counters).await?;
+        self.bus.publish(ServerEvent::VehicleCountersUpdated { counters: counters.clone() })?;
+        Ok(counters)
+    }
+
+    pub async fn save_maintenance_interval(&self, mut interval: MaintenanceInterval) -> anyhow::Result<MaintenanceInterval> {
+        let interval = if interval.id.is_empty() {
+            interval.created = chrono::Utc::now().timestamp_millis();
+            self.dao.create(TB_MAINTENANCE_INTERVALS, interval).await?
+        } else {
+            self.dao.update(TB_MAINTENANCE_INTERVALS, interval).await?
+        };
+
+        self.bus.publish(ServerEvent::MaintenanceIntervalUpserted { interval: interval.clone() })?;
+        Ok(interval)
+    }
+
+    pub async fn delete_maintenance_interval(&self, interval_id: &MaintenanceIntervalId) -> anyhow::Result<()> {
+        self.dao.delete(TB_MAINTENANCE_INTERVALS, interval_id).await?;
+        self.bus.publish(ServerEvent::MaintenanceIntervalRemoved { interval_id: interval_id.into() })?;
+        Ok(())
+    }
+
+    pub async fn vehicle_maintenance_intervals(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<MaintenanceInterval>> {
+        self.dao.select_where(TB_MAINTENANCE_INTERVALS, "vehicle_id", vehicle_id).await
+    }
+
+    pub async fn add_maintenance_entry(&self, mut entry: MaintenanceEntry) -> anyhow::Result<MaintenanceEntry> {
+        let counters = self.vehicle_counters(&entry.vehicle_id).await?;
+        entry.timestamp = chrono::Utc::now().timestamp_millis();
+        entry.flight_time = counters.flight_time;
+        entry.cycles = counters.cycles;
+
+        let entry: MaintenanceEntry = self.dao.create(TB_MAINTENANCE_ENTRIES, entry).await?;
+        self.bus.publish(ServerEvent::MaintenanceEntryAdded { entry: entry.clone() })?;
+        Ok(entry)
+    }
+
+    pub async fn delete_maintenance_entry(&self, entry_id: &MaintenanceEntryId) -> anyhow::Result<()> {
+        self.dao.delete(TB_MAINTENANCE_ENTRIES, entry_id).await?;
+        self.bus.publish(ServerEvent::MaintenanceEntryRemoved { entry_id: entry_id.into() })?;
+        Ok(())
+    }
+
+    pub async fn vehicle_maintenance_entries(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<MaintenanceEntry>> {
+        let mut entries: Vec<MaintenanceEntry> = self.dao.select_where(TB_MAINTENANCE_ENTRIES, "vehicle_id", vehicle_id).await?;
+        entries.sort_by_key(|entry| entry.timestamp);
+        Ok(entries)
+    }
+
+    pub async fn delete_vehicle_maintenance(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
+        for interval in self.vehicle_maintenance_intervals(vehicle_id).await? {
+            self.delete_maintenance_interval(&interval.id).await?;
+        }
+        for entry in self.vehicle_maintenance_entries(vehicle_id).await? {
+            self.delete_maintenance_entry(&entry.id).await?;
+        }
+        self.dao.delete(TB_VEHICLE_COUNTERS, vehicle_id).await
+    }
+}
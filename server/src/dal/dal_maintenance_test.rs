@@ -0,0 +1,64 @@
+use crate::dal::test_utils::in_memory_dal;
+
+use crate::models::maintenance::MaintenanceEntry;
+
+#[tokio::test]
+async fn test_counters_accumulate() {
+    let (dal, _) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+
+    let counters = dal.vehicle_counters(&vehicle_id).await.expect("Error reading counters");
+    assert_eq!(counters.cycles, 0);
+
+    dal.add_vehicle_counters(&vehicle_id, 0.0, 1, 30.0).await.expect("Error adding counters");
+    dal.add_vehicle_counters(&vehicle_id, 600.0, 0, 1.5).await.expect("Error adding counters");
+
+    let counters = dal.vehicle_counters(&vehicle_id).await.expect("Error reading counters");
+    assert_eq!(counters.flight_time, 600.0);
+    assert_eq!(counters.cycles, 1);
+    assert_eq!(counters.arm_time, 31.5);
+}
+
+#[tokio::test]
+async fn test_unreadable_counters_are_not_reset() {
+    let (dal, _) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+    dal.dao.create("vehicle_counters", serde_json::json!({ "id": "mav_1", "flight_time": "broken" })).await
+        .expect("Error saving counters");
+
+    assert!(dal.vehicle_counters(&vehicle_id).await.is_err());
+    assert!(dal.add_vehicle_counters(&vehicle_id, 60.0, 1, 60.0).await.is_err());
+
+    let stored: serde_json::Value = dal.dao.select_one("vehicle_counters", &vehicle_id).await
+        .expect("Error reading counters");
+    assert_eq!(stored["flight_time"], "broken");
+}
+
+#[tokio::test]
+async fn test_entry_snapshots_counters() {
+    let (dal, _) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+
+    dal.add_vehicle_counters(&vehicle_id, 7200.0, 3, 7500.0).await.expect("Error adding counters");
+
+    let entry = dal.add_maintenance_entry(MaintenanceEntry {
+        id: String::new(),
+        vehicle_id: vehicle_id.clone(),
+        interval_id: None,
+        timestamp: 0,
+        notes: "Replaced servo".into(),
+        flight_time: 0.0,
+        cycles: 0
+    }).await.expect("Error adding entry");
+    assert_ne!(entry.id.len(), 0);
+    assert_ne!(entry.timestamp, 0);
+    assert_eq!(entry.flight_time, 7200.0);
+    assert_eq!(entry.cycles, 3);
+
+    let entries = dal.vehicle_maintenance_entries(&vehicle_id).await.expect("Error reading entries");
+    assert_eq!(entries, vec![entry.clone()]);
+
+    dal.delete_maintenance_entry(&entry.id).await.expect("Error deleting entry");
+    let entries = dal.vehicle_maintenance_entries(&vehicle_id).await.expect("Error reading entries");
+    assert!(entries.is_empty());
+}
@@ -0,0 +1,50 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::messages::VehicleMessage;
+use crate::models::vehicles::VehicleId;
+
+const TB_VEHICLE_MESSAGES: &str = "vehicle_messages";
+pub const MAX_VEHICLE_MESSAGES: usize = 500; // TODO: to settings
+// Oldest messages are dropped in batches, so the log is not re-read on every message
+pub const VEHICLE_MESSAGES_PRUNE_WINDOW: usize = 50;
+
+impl Dal {
+    pub async fn add_vehicle_message(&self, mut message: VehicleMessage) -> anyhow::Result<VehicleMessage> {
+        message.timestamp = chrono::Utc::now().timestamp_millis();
+        let message: VehicleMessage = self.dao.create(TB_VEHICLE_MESSAGES, message).await?;
+
+        // Drop the oldest messages to keep the log capped
+        let count = self.dao.count_where(TB_VEHICLE_MESSAGES, "vehicle_id", &message.vehicle_id).await?;
+        if count > MAX_VEHICLE_MESSAGES + VEHICLE_MESSAGES_PRUNE_WINDOW {
+            let messages = self.all_vehicle_messages(&message.vehicle_id).await?;
+            for old_message in &messages[..messages.len() - MAX_VEHICLE_MESSAGES] {
+                self.dao.delete(TB_VEHICLE_MESSAGES, &old_message.id).await?;
+            }
+        }
+
+        self.bus.publish(ServerEvent::VehicleMessageAdded { message: message.clone() })?;
+        Ok(message)
+    }
+
+    pub async fn clear_vehicle_messages(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
+        for message in self.all_vehicle_messages(vehicle_id).await? {
+            self.dao.delete(TB_VEHICLE_MESSAGES, &message.id).await?;
+        }
+
+        self.bus.publish(ServerEvent::VehicleMessagesCleared { vehicle_id: vehicle_id.into() })?;
+        Ok(())
+    }
+
+    pub async fn vehicle_messages(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<VehicleMessage>> {
+        let mut messages = self.all_vehicle_messages(vehicle_id).await?;
+        let excess = messages.len().saturating_sub(MAX_VEHICLE_MESSAGES);
+        Ok(messages.split_off(excess))
+    }
+
+    async fn all_vehicle_messages(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<VehicleMessage>> {
+        let mut messages: Vec<VehicleMessage> = self.dao.select_where(TB_VEHICLE_MESSAGES, "vehicle_id", vehicle_id).await?;
+        messages.sort_by_key(|message| message.timestamp);
+        Ok(messages)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::dal::test_utils::in_memory_dal;
+
+use crate::models::messages::{MessageSeverity, VehicleMessage};
+use super::dal_messages::{MAX_VEHICLE_MESSAGES, VEHICLE_MESSAGES_PRUNE_WINDOW};
+
+fn message(vehicle_id: &str, text: String) -> VehicleMessage {
+    VehicleMessage {
+        id: String::new(),
+        vehicle_id: vehicle_id.into(),
+        timestamp: 0,
+        severity: MessageSeverity::Info,
+        text
+    }
+}
+
+#[tokio::test]
+async fn test_vehicle_messages() {
+    let (dal, _) = in_memory_dal().await;
+
+    dal.add_vehicle_message(message("mav_1", "first".into())).await.expect("Error adding message");
+    dal.add_vehicle_message(message("mav_2", "other".into())).await.expect("Error adding message");
+
+    let messages = dal.vehicle_messages(&"mav_1".into()).await.expect("Error reading messages");
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].text, "first");
+    assert!(messages[0].timestamp > 0);
+
+    dal.clear_vehicle_messages(&"mav_1".into()).await.expect("Error clearing messages");
+    assert!(dal.vehicle_messages(&"mav_1".into()).await.expect("Error reading messages").is_empty());
+    assert_eq!(dal.vehicle_messages(&"mav_2".into()).await.expect("Error reading messages").len(), 1);
+}
+
+#[tokio::test]
+async fn test_vehicle_messages_cap() {
+    let (dal, _) = in_memory_dal().await;
+
+    for index in 0..MAX_VEHICLE_MESSAGES + VEHICLE_MESSAGES_PRUNE_WINDOW {
+        dal.add_vehicle_message(message("mav_1", index.to_string())).await.expect("Error adding message");
+    }
+    // Below the window the log is only trimmed on reading
+    assert_eq!(dal.dao.count_where("vehicle_messages", "vehicle_id", "mav_1").await.expect("Error counting"),
+        MAX_VEHICLE_MESSAGES + VEHICLE_MESSAGES_PRUNE_WINDOW);
+    assert_eq!(dal.vehicle_messages(&"mav_1".into()).await.expect("Error reading messages").len(), MAX_VEHICLE_MESSAGES);
+
+    dal.add_vehicle_message(message("mav_1", "last".into())).await.expect("Error adding message");
+    assert_eq!(dal.dao.count_where("vehicle_messages", "vehicle_id", "mav_1").await.expect("Error counting"),
+        MAX_VEHICLE_MESSAGES);
+    let messages = dal.vehicle_messages(&"mav_1".into()).await.expect("Error reading messages");
+    assert_eq!(messages.len(), MAX_VEHICLE_MESSAGES);
+    assert_eq!(messages.last().map(|message| message.text.as_str()), Some("last"));
+}
@@ -0,0 +1,81 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::missions::*;
+use crate::models::mission_patterns::{MissionPattern, MissionPatternId, PatternParameters};
+use crate::services::missions::{patterns, route_edit};
+
+const TB_MISSION_PATTERNS: &str = "mission_patterns";
+const TB_MISSION_ROUTES: &str = "mission_routes";
+
+impl Dal {
+    // Generated items go after the existing route items, so takeoff and approach are kept
+    pub async fn add_mission_pattern(&self, mission_id: &MissionId, parameters: PatternParameters) -> anyhow::Result<MissionPattern> {
+        let generated = patterns::generate_pattern(&parameters)?;
+        {
+            let _lock = self.route_edits.lock().await;
+            let mut route: MissionRoute = self.dao.select_one(TB_MISSION_ROUTES, mission_id).await?;
+            self.record_baseline_revision_locked(&route).await?;
+            route.items.extend(generated.items.iter().cloned());
+            let route = self.dao.update_all(TB_MISSION_ROUTES, vec![route]).await?.remove(0);
+            self.bus.publish(ServerEvent::MissionRouteUpdated { route })?;
+            self.record_route_revision_locked(mission_id, RevisionAuthor::Operator).await?;
+        }
+
+        let pattern = self.dao.create(TB_MISSION_PATTERNS, MissionPattern {
+            id: String::new(), // will be generated
+            mission_id: mission_id.clone(),
+            parameters,
+            items: generated.items
+        }).await?;
+        self.bus.publish(ServerEvent::MissionPatternUpserted { pattern: pattern.clone() })?;
+        Ok(pattern)
+    }
+
+    // Replaces the previously generated items in place, they must not be edited in the route since then
+    pub async fn regenerate_mission_pattern(&self, pattern_id: &MissionPatternId, parameters: PatternParameters) -> anyhow::Result<MissionPattern> {
+        let mut pattern = self.mission_pattern(pattern_id).await?;
+        let generated = patterns::generate_pattern(&parameters)?;
+        {
+            let _lock = self.route_edits.lock().await;
+            let mut route: MissionRoute = self.dao.select_one(TB_MISSION_ROUTES, &pattern.mission_id).await?;
+            let index = match patterns::find_pattern_items(&route.items, &pattern.items) {
+                Some(index) => index,
+                None => return Err(anyhow::anyhow!("Items of pattern {} were changed in the route", pattern_id))
+            };
+            self.record_baseline_revision_locked(&route).await?;
+            route_edit::splice_items(&mut route.items, index..index + pattern.items.len(), generated.items.clone());
+            let route = self.dao.update_all(TB_MISSION_ROUTES, vec![route]).await?.remove(0);
+            self.bus.publish(ServerEvent::MissionRouteUpdated { route })?;
+            self.record_route_revision_locked(&pattern.mission_id, RevisionAuthor::Operator).await?;
+        }
+
+        pattern.parameters = parameters;
+        pattern.items = generated.items;
+        let pattern = self.dao.update(TB_MISSION_PATTERNS, pattern).await?;
+        self.bus.publish(ServerEvent::MissionPatternUpserted { pattern: pattern.clone() })?;
+        Ok(pattern)
+    }
+
+    pub async fn mission_pattern(&self, pattern_id: &MissionPatternId) -> anyhow::Result<MissionPattern> {
+        self.dao.select_one(TB_MISSION_PATTERNS, pattern_id).await
+    }
+
+    pub async fn mission_patterns(&self, mission_id: &MissionId) -> anyhow::Result<Vec<MissionPattern>> {
+        self.dao.select_where(TB_MISSION_PATTERNS, "mission_id", mission_id).await
+    }
+
+    // Unlinks the pattern, generated items stay in the route
+    pub async fn delete_mission_pattern(&self, pattern_id: &MissionPatternId) -> anyhow::Result<()> {
+        self.dao.delete(TB_MISSION_PATTERNS, pattern_id).await?;
+        self.bus.publish(ServerEvent::MissionPatternRemoved { pattern_id: pattern_id.into() })?;
+        Ok(())
+    }
+
+    pub async fn delete_mission_patterns(&self, mission_id: &MissionId) -> anyhow::Result<()> {
+        for pattern in self.mission_patterns(mission_id).await? {
+            self.delete_mission_pattern(&pattern.id).await?;
+        }
+        Ok(())
+    }
+}
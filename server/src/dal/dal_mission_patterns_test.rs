@@ -0,0 +1,109 @@
+use crate::dal::{dal, test_utils::in_memory_dal};
+
+use crate::models::missions::{MissionId, MissionRouteItem, RouteEdit};
+use crate::models::mission_patterns::{PatternParameters, StructureRequest, SurveyCamera};
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+
+fn wpt(hold: u16) -> MissionRouteItem {
+    MissionRouteItem::Waypoint { position: Geodetic::default(), hold, pass_radius: 0.0, accept_radius: 0.0, yaw: None }
+}
+
+fn structure(radius: f32) -> PatternParameters {
+    PatternParameters::Structure { request: StructureRequest {
+        center: Geodetic { latitude: 55.75, longitude: 37.6, altitude: 30.0, frame: GeodeticFrame::Wgs84RelativeHome },
+        radius,
+        bottom: 20.0,
+        top: 20.0,
+        camera: SurveyCamera { sensor_width: 13.2, sensor_height: 8.8, focal_length: 8.8, image_width: 5472, image_height: 3648 },
+        front_overlap: 80.0,
+        side_overlap: 70.0,
+        clockwise: true,
+        entry: None
+    }}
+}
+
+async fn mission_with_route(dal: &dal::Dal, items: Vec<MissionRouteItem>) -> MissionId {
+    let mission = dal.create_new_mission(&"vehicle".into()).await.expect("Error creating mission");
+    dal.edit_route(&mission.id, RouteEdit::Replace { items }).await.expect("Error editing route");
+    mission.id
+}
+
+#[tokio::test]
+async fn test_add_mission_pattern() {
+    let (dal, _) = in_memory_dal().await;
+    let mission_id = mission_with_route(&dal, vec![wpt(0)]).await;
+
+    let pattern = dal.add_mission_pattern(&mission_id, structure(50.0)).await.expect("Error adding pattern");
+    assert!(!pattern.id.is_empty());
+    assert_eq!(pattern.parameters, structure(50.0));
+
+    let route = dal.mission_route(&mission_id).await.expect("Error reading route");
+    assert_eq!(route.items[0], wpt(0));
+    assert_eq!(route.items[1..], pattern.items[..]);
+
+    let revisions = dal.mission_revisions(&mission_id).await.expect("Error reading revisions");
+    assert_eq!(revisions.last().expect("No revision recorded").items, route.items);
+    assert_eq!(dal.mission_patterns(&mission_id).await.expect("Error reading patterns"), vec![pattern]);
+}
+
+#[tokio::test]
+async fn test_regenerate_mission_pattern() {
+    let (dal, _) = in_memory_dal().await;
+    let mission_id = mission_with_route(&dal, vec![wpt(0)]).await;
+    let pattern = dal.add_mission_pattern(&mission_id, structure(50.0)).await.expect("Error adding pattern");
+    dal.upsert_route_item(&mission_id, wpt(9), (pattern.items.len() + 1) as u16).await.expect("Error adding item");
+
+    let regenerated = dal.regenerate_mission_pattern(&pattern.id, structure(80.0)).await
+        .expect("Error regenerating pattern");
+    assert_eq!(regenerated.id, pattern.id);
+    assert_eq!(regenerated.parameters, structure(80.0));
+    assert_ne!(regenerated.items, pattern.items);
+
+    // Items around the pattern are kept
+    let route = dal.mission_route(&mission_id).await.expect("Error reading route");
+    assert_eq!(route.items.len(), regenerated.items.len() + 2);
+    assert_eq!(route.items[0], wpt(0));
+    assert_eq!(route.items[1..route.items.len() - 1], regenerated.items[..]);
+    assert_eq!(route.items.last(), Some(&wpt(9)));
+}
+
+#[tokio::test]
+async fn test_regenerate_pattern_keeps_jumps() {
+    let (dal, _) = in_memory_dal().await;
+    let mission_id = mission_with_route(&dal, vec![wpt(0)]).await;
+    let pattern = dal.add_mission_pattern(&mission_id, structure(50.0)).await.expect("Error adding pattern");
+    let after = (pattern.items.len() + 1) as u16;
+    dal.upsert_route_item(&mission_id, wpt(9), after).await.expect("Error adding item");
+    dal.upsert_route_item(&mission_id, MissionRouteItem::DoJump { target: after, repeat: 1 }, after + 1).await
+        .expect("Error adding item");
+
+    let regenerated = dal.regenerate_mission_pattern(&pattern.id, structure(80.0)).await
+        .expect("Error regenerating pattern");
+
+    // Jump follows the item after the pattern
+    let route = dal.mission_route(&mission_id).await.expect("Error reading route");
+    let after = regenerated.items.len() + 1;
+    assert_eq!(route.items[after], wpt(9));
+    assert_eq!(route.items[after + 1], MissionRouteItem::DoJump { target: after as u16, repeat: 1 });
+}
+
+#[tokio::test]
+async fn test_regenerate_edited_pattern() {
+    let (dal, _) = in_memory_dal().await;
+    let mission_id = mission_with_route(&dal, Vec::new()).await;
+    let pattern = dal.add_mission_pattern(&mission_id, structure(50.0)).await.expect("Error adding pattern");
+    dal.upsert_route_item(&mission_id, wpt(9), 3).await.expect("Error editing item");
+
+    assert!(dal.regenerate_mission_pattern(&pattern.id, structure(80.0)).await.is_err());
+    assert_eq!(dal.mission_pattern(&pattern.id).await.expect("Error reading pattern"), pattern);
+}
+
+#[tokio::test]
+async fn test_delete_mission_with_patterns() {
+    let (dal, _) = in_memory_dal().await;
+    let mission_id = mission_with_route(&dal, Vec::new()).await;
+    dal.add_mission_pattern(&mission_id, structure(50.0)).await.expect("Error adding pattern");
+
+    dal.delete_mission(&mission_id).await.expect("Error deleting mission");
+    assert!(dal.mission_patterns(&mission_id).await.expect("Error reading patterns").is_empty());
+}
@@ -0,0 +1,99 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::missions::*;
+use crate::models::vehicles::VehicleId;
+
+const TB_MISSION_PLANS: &str = "mission_plans";
+
+impl Dal {
+    pub async fn save_mission_plan(&self, mut plan: MissionPlan) -> anyhow::Result<MissionPlan> {
+        plan.updated = chrono::prelude::Utc::now().timestamp_millis();
+        let plan = if plan.id.is_empty() {
+            self.dao.create(TB_MISSION_PLANS, plan).await?
+        } else {
+            self.dao.update(TB_MISSION_PLANS, plan).await?
+        };
+
+        self.bus.publish(ServerEvent::MissionPlanUpserted { plan: plan.clone() })?;
+        Ok(plan)
+    }
+
+    pub async fn delete_mission_plan(&self, plan_id: &MissionPlanId) -> anyhow::Result<()> {
+        self.dao.delete(TB_MISSION_PLANS, plan_id).await?;
+        self.bus.publish(ServerEvent::MissionPlanRemoved { plan_id: plan_id.into() })?;
+
+        // Missions keep their routes, only the link to the plan is dropped
+        for mission in self.all_missions().await? {
+            if mission.plan_id.as_ref() == Some(plan_id) {
+                self.set_mission_plan(&mission.id, None).await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn mission_plan(&self, plan_id: &MissionPlanId) -> anyhow::Result<MissionPlan> {
+        self.dao.select_one(TB_MISSION_PLANS, plan_id).await
+    }
+
+    pub async fn all_mission_plans(&self) -> anyhow::Result<Vec<MissionPlan>> {
+        let mut plans: Vec<MissionPlan> = self.dao.select_all(TB_MISSION_PLANS).await?;
+        plans.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(plans)
+    }
+
+    pub async fn save_mission_as_plan(&self, mission_id: &MissionId, name: String) -> anyhow::Result<MissionPlan> {
+        let route = self.mission_route(mission_id).await?;
+        self.save_mission_plan(MissionPlan { id: String::new(), name, items: route.items, updated: 0 }).await
+    }
+
+    pub async fn clone_mission_plan(&self, plan_id: &MissionPlanId, name: String) -> anyhow::Result<MissionPlan> {
+        let plan = self.mission_plan(plan_id).await?;
+        self.save_mission_plan(MissionPlan { id: String::new(), name, items: plan.items, updated: 0 }).await
+    }
+
+    // Copies plan to the active vehicle mission, plan stays in the library untouched
+    pub async fn assign_mission_plan(&self, plan_id: &MissionPlanId, vehicle_id: &VehicleId) -> anyhow::Result<Mission> {
+        let plan = self.mission_plan(plan_id).await?;
+        let mission = self.editable_vehicle_mission(vehicle_id).await?;
+        let mission = self.load_items_to_mission(mission, plan.items).await?;
+        self.set_mission_plan(&mission.id, Some(plan.id)).await
+    }
+
+    // Exchanges routes, so the replaced vehicle route is kept in the library instead of the plan
+    pub async fn swap_mission_plan(&self, plan_id: &MissionPlanId, vehicle_id: &VehicleId) -> anyhow::Result<Mission> {
+        let mut plan = self.mission_plan(plan_id).await?;
+        let mission = self.editable_vehicle_mission(vehicle_id).await?;
+
+        let items = std::mem::replace(&mut plan.items, mission.route.items.clone());
+        let mission = self.load_items_to_mission(mission, items).await?;
+        let plan = self.save_mission_plan(plan).await?;
+        self.set_mission_plan(&mission.id, Some(plan.id)).await
+    }
+
+    async fn editable_vehicle_mission(&self, vehicle_id: &VehicleId) -> anyhow::Result<Mission> {
+        let mission = match self.active_mission_assignment(vehicle_id).await? {
+            Some(assignment) => self.mission(&assignment.mission_id).await?,
+            None => self.create_new_mission(vehicle_id).await?
+        };
+        match mission.status.state {
+            MissionUpdateState::NotActual {} | MissionUpdateState::Actual { .. } => Ok(mission),
+            _ => Err(anyhow::anyhow!("Mission operation is in progress for vehicle {}", vehicle_id))
+        }
+    }
+
+    async fn load_items_to_mission(&self, mut mission: Mission, items: Vec<MissionRouteItem>) -> anyhow::Result<Mission> {
+        if mission.route.items == items {
+            return Ok(mission);
+        }
+
+        mission.route = self.edit_route(&mission.id, RouteEdit::Replace { items }).await?;
+
+        // New route has to be uploaded
+        if mission.status.state != (MissionUpdateState::NotActual {}) {
+            mission.status.state = MissionUpdateState::NotActual {};
+            mission.status = self.update_mission_status(mission.status).await?;
+        }
+        Ok(mission)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::dal::{dal, test_utils::in_memory_dal};
+
+use crate::models::missions::{MissionPlan, MissionRouteItem, MissionUpdateState, RouteEdit};
+use crate::models::spatial::Geodetic;
+use crate::models::events::ServerEvent;
+
+fn wpt(hold: u16) -> MissionRouteItem {
+    MissionRouteItem::Waypoint { position: Geodetic::default(), hold, pass_radius: 0.0, accept_radius: 0.0, yaw: None }
+}
+
+async fn save_plan(dal: &dal::Dal, name: &str, items: Vec<MissionRouteItem>) -> MissionPlan {
+    dal.save_mission_plan(MissionPlan { id: String::new(), name: name.into(), items, updated: 0 }).await
+        .expect("Error saving plan")
+}
+
+#[tokio::test]
+async fn test_crud_mission_plans() {
+    let (dal, mut rx) = in_memory_dal().await;
+
+    let plan = save_plan(&dal, "survey", vec![wpt(0), wpt(1)]).await;
+    assert!(!plan.id.is_empty());
+    assert!(plan.updated > 0);
+    match rx.recv().await.expect("Error receiving event") {
+        ServerEvent::MissionPlanUpserted { plan: plan_back } => assert_eq!(plan, plan_back),
+        _ => panic!("Unexpected event")
+    }
+
+    let copy = dal.clone_mission_plan(&plan.id, "another survey".into()).await
+        .expect("Error cloning plan");
+    assert_ne!(copy.id, plan.id);
+    assert_eq!(copy.items, plan.items);
+
+    let plans = dal.all_mission_plans().await.expect("Error reading plans");
+    assert_eq!(plans.iter().map(|plan| plan.name.as_str()).collect::<Vec<_>>(), vec!["another survey", "survey"]);
+
+    dal.delete_mission_plan(&plan.id).await.expect("Error deleting plan");
+    assert!(dal.mission_plan(&plan.id).await.is_err());
+    assert_eq!(dal.mission_plan(&copy.id).await.expect("Error reading plan"), copy);
+}
+
+#[tokio::test]
+async fn test_assign_mission_plan() {
+    let (dal, _rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+
+    let plan = save_plan(&dal, "survey", vec![wpt(0), wpt(1)]).await;
+
+    // Mission is created for vehicle without one
+    let mission = dal.assign_mission_plan(&plan.id, &vehicle_id).await
+        .expect("Error assigning plan");
+    assert_eq!(mission.vehicle_id, vehicle_id);
+    assert_eq!(mission.route.items, plan.items);
+    assert_eq!(mission.status.state, MissionUpdateState::NotActual {});
+    assert_eq!(mission.plan_id, Some(plan.id.clone()));
+    // Baseline and the assigned plan
+    assert_eq!(dal.mission_revisions(&mission.id).await.expect("Error reading revisions").len(), 2);
+
+    // Vehicle mission edits don't touch the plan
+    dal.edit_route(&mission.id, RouteEdit::Insert { index: 0, items: vec![wpt(5)] }).await
+        .expect("Error editing route");
+    assert_eq!(dal.mission_plan(&plan.id).await.expect("Error reading plan").items, plan.items);
+
+    // Same vehicle mission is reused
+    let other = save_plan(&dal, "patrol", vec![wpt(3)]).await;
+    let reassigned = dal.assign_mission_plan(&other.id, &vehicle_id).await
+        .expect("Error assigning plan");
+    assert_eq!(reassigned.id, mission.id);
+    assert_eq!(reassigned.route.items, vec![wpt(3)]);
+    assert_eq!(reassigned.plan_id, Some(other.id.clone()));
+
+    // Plan is saved back to the library from the vehicle mission
+    let saved = dal.save_mission_as_plan(&mission.id, "patrol copy".into()).await
+        .expect("Error saving mission as plan");
+    assert_eq!(saved.items, vec![wpt(3)]);
+
+    // Removed plan is unlinked from the mission, the route stays
+    dal.delete_mission_plan(&other.id).await.expect("Error deleting plan");
+    let mission = dal.mission(&mission.id).await.expect("Error reading mission");
+    assert_eq!(mission.plan_id, None);
+    assert_eq!(mission.route.items, vec![wpt(3)]);
+}
+
+#[tokio::test]
+async fn test_swap_mission_plan() {
+    let (dal, _rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+
+    let mission = dal.create_new_mission(&vehicle_id).await.expect("Error creating mission");
+    dal.edit_route(&mission.id, RouteEdit::Replace { items: vec![wpt(0)] }).await
+        .expect("Error editing route");
+    let plan = save_plan(&dal, "survey", vec![wpt(1), wpt(2)]).await;
+
+    let mission = dal.swap_mission_plan(&plan.id, &vehicle_id).await
+        .expect("Error swapping plan");
+    assert_eq!(mission.route.items, vec![wpt(1), wpt(2)]);
+    assert_eq!(mission.plan_id, Some(plan.id.clone()));
+    assert_eq!(dal.mission_plan(&plan.id).await.expect("Error reading plan").items, vec![wpt(0)]);
+}
+
+#[tokio::test]
+async fn test_assign_plan_during_mission_operation() {
+    let (dal, _rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+
+    let mut mission = dal.create_new_mission(&vehicle_id).await.expect("Error creating mission");
+    mission.status.state = MissionUpdateState::Upload { total: 3, progress: 1 };
+    dal.update_mission_status(mission.status).await.expect("Error updating status");
+
+    let plan = save_plan(&dal, "survey", vec![wpt(1)]).await;
+    assert!(dal.assign_mission_plan(&plan.id, &vehicle_id).await.is_err());
+    assert!(dal.mission_route(&mission.id).await.expect("Error reading route").items.is_empty());
+}
@@ -0,0 +1,166 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::missions::*;
+use crate::services::missions::route_diff;
+
+const TB_MISSION_REVISIONS: &str = "mission_revisions";
+const TB_MISSION_VEHICLE_ROUTES: &str = "mission_vehicle_routes";
+
+impl Dal {
+    pub async fn record_route_revision(&self, mission_id: &MissionId, author: RevisionAuthor) -> anyhow::Result<Option<MissionRevision>> {
+        let _lock = self.route_edits.lock().await;
+        self.record_route_revision_locked(mission_id, author).await
+    }
+
+    pub async fn mission_revisions(&self, mission_id: &MissionId) -> anyhow::Result<Vec<MissionRevision>> {
+        let mut revisions: Vec<MissionRevision> = self.dao.select_where(TB_MISSION_REVISIONS, "mission_id", mission_id).await?;
+        revisions.sort_by_key(|revision| revision.number);
+        Ok(revisions)
+    }
+
+    pub async fn undo_route(&self, mission_id: &MissionId) -> anyhow::Result<MissionRoute> {
+        let _lock = self.route_edits.lock().await;
+        let revisions = self.mission_revisions(mission_id).await?;
+
+        let mut active = revisions.into_iter().filter(|revision| !revision.undone).collect::<Vec<_>>();
+        // The oldest revision is the route before any recorded change, so it is never undone
+        if active.len() < 2 {
+            return Err(anyhow::anyhow!("Nothing to undo for mission {}", mission_id));
+        }
+        let mut last = active.pop().unwrap();
+        let items = active.pop().unwrap().items;
+
+        last.undone = true;
+        self.save_revision(last).await?;
+        self.set_route_items(mission_id, items).await
+    }
+
+    pub async fn redo_route(&self, mission_id: &MissionId) -> anyhow::Result<MissionRoute> {
+        let _lock = self.route_edits.lock().await;
+        let revisions = self.mission_revisions(mission_id).await?;
+
+        let mut next = match revisions.into_iter().find(|revision| revision.undone) {
+            Some(next) => next,
+            None => return Err(anyhow::anyhow!("Nothing to redo for mission {}", mission_id))
+        };
+        let items = next.items.clone();
+
+        next.undone = false;
+        self.save_revision(next).await?;
+        self.set_route_items(mission_id, items).await
+    }
+
+    // Restoring is a new revision itself, so it can be undone too
+    pub async fn restore_route_revision(&self, mission_id: &MissionId, revision_id: &MissionRevisionId) -> anyhow::Result<MissionRoute> {
+        let _lock = self.route_edits.lock().await;
+        let revision: MissionRevision = self.dao.select_one(TB_MISSION_REVISIONS, revision_id).await?;
+        if &revision.mission_id != mission_id {
+            return Err(anyhow::anyhow!("Revision {} does not belong to mission {}", revision_id, mission_id));
+        }
+
+        let route = self.set_route_items(mission_id, revision.items).await?;
+        self.record_route_revision_locked(mission_id, RevisionAuthor::Operator).await?;
+        Ok(route)
+    }
+
+    // Vehicle and local routes are the same after completed download or upload
+    pub async fn sync_vehicle_route(&self, mission_id: &MissionId, downloaded: bool) -> anyhow::Result<()> {
+        let route = self.mission_route(mission_id).await?;
+        self.save_vehicle_route(route).await?;
+        if downloaded {
+            self.record_route_revision(mission_id, RevisionAuthor::Vehicle).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn save_vehicle_route(&self, route: MissionRoute) -> anyhow::Result<MissionRoute> {
+        self.dao.update(TB_MISSION_VEHICLE_ROUTES, route).await
+    }
+
+    pub async fn vehicle_route(&self, mission_id: &MissionId) -> anyhow::Result<MissionRoute> {
+        self.dao.select_one(TB_MISSION_VEHICLE_ROUTES, mission_id).await
+    }
+
+    // What local route changes are not on the vehicle yet
+    pub async fn vehicle_route_diff(&self, mission_id: &MissionId) -> anyhow::Result<RouteDiff> {
+        let vehicle_route = self.vehicle_route(mission_id).await?;
+        let route = self.mission_route(mission_id).await?;
+        Ok(route_diff::diff_routes(&vehicle_route.items, &route.items))
+    }
+
+    pub async fn delete_mission_revisions(&self, mission_id: &MissionId) -> anyhow::Result<()> {
+        for revision in self.mission_revisions(mission_id).await? {
+            self.dao.delete(TB_MISSION_REVISIONS, &revision.id).await?;
+        }
+        self.dao.delete(TB_MISSION_VEHICLE_ROUTES, mission_id).await
+    }
+
+    // Route before the first recorded change, so that change can be undone too. Caller holds route_edits
+    pub async fn record_baseline_revision_locked(&self, route: &MissionRoute) -> anyhow::Result<()> {
+        if self.dao.count_where(TB_MISSION_REVISIONS, "mission_id", &route.id).await? > 0 {
+            return Ok(());
+        }
+
+        let revision = self.dao.create(TB_MISSION_REVISIONS, MissionRevision {
+            id: String::new(),
+            mission_id: route.id.clone(),
+            number: 1,
+            timestamp: chrono::prelude::Utc::now().timestamp_millis(),
+            author: RevisionAuthor::Operator,
+            items: route.items.clone(),
+            diff: route_diff::diff_routes(&[], &route.items),
+            undone: false
+        }).await?;
+        self.bus.publish(ServerEvent::MissionRevisionUpserted { revision })?;
+        Ok(())
+    }
+
+    // Caller holds route_edits
+    pub async fn record_route_revision_locked(&self, mission_id: &MissionId, author: RevisionAuthor) -> anyhow::Result<Option<MissionRevision>> {
+        let route = self.mission_route(mission_id).await?;
+        let revisions = self.mission_revisions(mission_id).await?;
+
+        let previous = revisions.iter().rev().find(|revision| !revision.undone);
+        let diff = route_diff::diff_routes(
+            previous.map(|revision| revision.items.as_slice()).unwrap_or_default(),
+            &route.items
+        );
+        if diff.changes.is_empty() {
+            return Ok(None);
+        }
+        let number = previous.map(|revision| revision.number + 1).unwrap_or(1);
+
+        // New change drops everything which could be redone
+        for revision in revisions.iter().filter(|revision| revision.undone) {
+            self.dao.delete(TB_MISSION_REVISIONS, &revision.id).await?;
+            self.bus.publish(ServerEvent::MissionRevisionRemoved {
+                mission_id: mission_id.clone(),
+                revision_id: revision.id.clone()
+            })?;
+        }
+
+        let revision = self.dao.create(TB_MISSION_REVISIONS, MissionRevision {
+            id: String::new(),
+            mission_id: mission_id.clone(),
+            number,
+            timestamp: chrono::prelude::Utc::now().timestamp_millis(),
+            author,
+            items: route.items,
+            diff,
+            undone: false
+        }).await?;
+        self.bus.publish(ServerEvent::MissionRevisionUpserted { revision: revision.clone() })?;
+        Ok(Some(revision))
+    }
+
+    async fn save_revision(&self, revision: MissionRevision) -> anyhow::Result<MissionRevision> {
+        let revision = self.dao.update(TB_MISSION_REVISIONS, revision).await?;
+        self.bus.publish(ServerEvent::MissionRevisionUpserted { revision: revision.clone() })?;
+        Ok(revision)
+    }
+
+    async fn set_route_items(&self, mission_id: &MissionId, items: Vec<MissionRouteItem>) -> anyhow::Result<MissionRoute> {
+        self.update_route(MissionRoute { id: mission_id.clone(), items }).await
+    }
+}
@@ -0,0 +1,147 @@
+use crate::dal::{dal, test_utils::in_memory_dal};
+
+use crate::models::missions::{MissionRoute, MissionRouteItem, RevisionAuthor, RouteEdit, RouteItemChange};
+use crate::models::spatial::Geodetic;
+use crate::models::events::ServerEvent;
+
+fn wpt(hold: u16) -> MissionRouteItem {
+    MissionRouteItem::Waypoint { position: Geodetic::default(), hold, pass_radius: 0.0, accept_radius: 0.0, yaw: None }
+}
+
+async fn edit(dal: &dal::Dal, mission_id: &String, edit: RouteEdit) {
+    dal.edit_route(mission_id, edit).await.expect("Error editing route");
+}
+
+#[tokio::test]
+async fn test_record_route_revisions() {
+    let (dal, _rx) = in_memory_dal().await;
+    let mission_id = dal.create_new_mission(&"mav_1".to_string()).await.expect("Error creating mission").id;
+
+    edit(&dal, &mission_id, RouteEdit::Replace { items: vec![wpt(0), wpt(1)] }).await;
+    edit(&dal, &mission_id, RouteEdit::Insert { index: 1, items: vec![wpt(7)] }).await;
+
+    // Nothing changed, nothing recorded
+    let revision = dal.record_route_revision(&mission_id, RevisionAuthor::Operator).await
+        .expect("Error recording revision");
+    assert!(revision.is_none());
+
+    let revisions = dal.mission_revisions(&mission_id).await.expect("Error reading revisions");
+    assert_eq!(revisions.len(), 3);
+    // Baseline is the route before the first change
+    assert_eq!(revisions[0].number, 1);
+    assert!(revisions[0].items.is_empty());
+    assert_eq!(revisions[1].number, 2);
+    assert_eq!(revisions[1].items, vec![wpt(0), wpt(1)]);
+    assert_eq!(revisions[1].diff.changes.len(), 2);
+    assert_eq!(revisions[2].number, 3);
+    assert_eq!(revisions[2].items, vec![wpt(0), wpt(7), wpt(1)]);
+    assert_eq!(revisions[2].diff.changes, vec![RouteItemChange::Added { index: 1, item: wpt(7) }]);
+
+    dal.delete_mission(&mission_id).await.expect("Error deleting mission");
+    let revisions = dal.mission_revisions(&mission_id).await.expect("Error reading revisions");
+    assert!(revisions.is_empty());
+}
+
+#[tokio::test]
+async fn test_undo_redo_route() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let mission_id = dal.create_new_mission(&"mav_1".to_string()).await.expect("Error creating mission").id;
+
+    edit(&dal, &mission_id, RouteEdit::Replace { items: vec![wpt(0)] }).await;
+    edit(&dal, &mission_id, RouteEdit::Insert { index: 1, items: vec![wpt(1)] }).await;
+    while rx.try_recv().is_ok() {}
+
+    let route = dal.undo_route(&mission_id).await.expect("Error undoing");
+    assert_eq!(route.items, vec![wpt(0)]);
+    let mut route_updated = false;
+    while let Ok(event) = rx.try_recv() {
+        if let ServerEvent::MissionRouteUpdated { route: route_back } = event {
+            assert_eq!(route, route_back);
+            route_updated = true;
+        }
+    }
+    assert!(route_updated);
+
+    let route = dal.undo_route(&mission_id).await.expect("Error undoing");
+    assert!(route.items.is_empty());
+    assert!(dal.undo_route(&mission_id).await.is_err());
+
+    let route = dal.redo_route(&mission_id).await.expect("Error redoing");
+    assert_eq!(route.items, vec![wpt(0)]);
+
+    // New change drops the redo tail
+    edit(&dal, &mission_id, RouteEdit::Insert { index: 0, items: vec![wpt(5)] }).await;
+    assert!(dal.redo_route(&mission_id).await.is_err());
+
+    let revisions = dal.mission_revisions(&mission_id).await.expect("Error reading revisions");
+    assert_eq!(revisions.len(), 3);
+    assert_eq!(revisions[2].number, 3);
+    assert_eq!(revisions[2].items, vec![wpt(5), wpt(0)]);
+    assert!(revisions.iter().all(|revision| !revision.undone));
+}
+
+#[tokio::test]
+async fn test_undo_first_route_change() {
+    let (dal, _rx) = in_memory_dal().await;
+    let mission_id = dal.create_new_mission(&"mav_1".to_string()).await.expect("Error creating mission").id;
+
+    // Route downloaded from the vehicle is recorded once the download is done
+    dal.upsert_downloaded_route_item(&mission_id, wpt(0), 0).await.expect("Error setting route item");
+    dal.upsert_downloaded_route_item(&mission_id, wpt(1), 1).await.expect("Error setting route item");
+    dal.record_route_revision(&mission_id, RevisionAuthor::Vehicle).await.expect("Error recording revision");
+
+    edit(&dal, &mission_id, RouteEdit::Replace { items: vec![wpt(2)] }).await;
+
+    let route = dal.undo_route(&mission_id).await.expect("Error undoing");
+    assert_eq!(route.items, vec![wpt(0), wpt(1)]);
+    let route = dal.undo_route(&mission_id).await.expect("Error undoing");
+    assert!(route.items.is_empty());
+
+    // Can't undo past the baseline
+    assert!(dal.undo_route(&mission_id).await.is_err());
+    let route = dal.mission_route(&mission_id).await.expect("Error reading mission route");
+    assert!(route.items.is_empty());
+}
+
+#[tokio::test]
+async fn test_restore_route_revision() {
+    let (dal, _rx) = in_memory_dal().await;
+    let mission_id = dal.create_new_mission(&"mav_1".to_string()).await.expect("Error creating mission").id;
+
+    edit(&dal, &mission_id, RouteEdit::Replace { items: vec![wpt(0), wpt(1)] }).await;
+    edit(&dal, &mission_id, RouteEdit::Replace { items: vec![wpt(2)] }).await;
+
+    let first = dal.mission_revisions(&mission_id).await.expect("Error reading revisions")[1].clone();
+    let route = dal.restore_route_revision(&mission_id, &first.id).await.expect("Error restoring revision");
+    assert_eq!(route.items, first.items);
+
+    let revisions = dal.mission_revisions(&mission_id).await.expect("Error reading revisions");
+    assert_eq!(revisions.len(), 4);
+    assert_eq!(revisions[3].items, first.items);
+
+    // Restoring can be undone as any other change
+    let route = dal.undo_route(&mission_id).await.expect("Error undoing");
+    assert_eq!(route.items, vec![wpt(2)]);
+
+    assert!(dal.restore_route_revision(&"other_mission".to_string(), &first.id).await.is_err());
+}
+
+#[tokio::test]
+async fn test_vehicle_route_diff() {
+    let (dal, _rx) = in_memory_dal().await;
+    let mission_id = dal.create_new_mission(&"mav_1".to_string()).await.expect("Error creating mission").id;
+
+    assert!(dal.vehicle_route_diff(&mission_id).await.is_err());
+
+    dal.save_vehicle_route(MissionRoute { id: mission_id.clone(), items: vec![wpt(0), wpt(1)] }).await
+        .expect("Error saving vehicle route");
+    dal.edit_route(&mission_id, RouteEdit::Replace { items: vec![wpt(0), wpt(1)] }).await
+        .expect("Error editing route");
+    let diff = dal.vehicle_route_diff(&mission_id).await.expect("Error getting diff");
+    assert!(diff.changes.is_empty());
+
+    dal.edit_route(&mission_id, RouteEdit::Replace { items: vec![wpt(0), wpt(3)] }).await
+        .expect("Error editing route");
+    let diff = dal.vehicle_route_diff(&mission_id).await.expect("Error getting diff");
+    assert_eq!(diff.changes, vec![RouteItemChange::Changed { index: 1, before: wpt(1), after: wpt(3) }]);
+}
@@ -0,0 +1,57 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::missions::MissionId;
+use crate::models::mission_validation::*;
+use crate::models::vehicles::VehicleType;
+
+const TB_MISSION_VALIDATION_SETTINGS: &str = "mission_validation_settings";
+const TB_MISSION_VALIDATION_REPORTS: &str = "mission_validation_reports";
+
+impl Dal {
+    pub async fn save_mission_validation_settings(&self, mut settings: MissionValidationSettings) -> anyhow::Result<MissionValidationSettings> {
+        // Only one settings entry per vehicle type
+        if let Some(existing) = self.stored_mission_validation_settings(&settings.vehicle_type).await? {
+            settings.id = existing.id;
+        }
+
+        let settings = if settings.id.is_empty() {
+            self.dao.create(TB_MISSION_VALIDATION_SETTINGS, settings).await?
+        } else {
+            self.dao.update(TB_MISSION_VALIDATION_SETTINGS, settings).await?
+        };
+
+        self.bus.publish(ServerEvent::MissionValidationSettingsUpdated { settings: settings.clone() })?;
+        Ok(settings)
+    }
+
+    pub async fn mission_validation_settings(&self, vehicle_type: &VehicleType) -> anyhow::Result<MissionValidationSettings> {
+        Ok(self.stored_mission_validation_settings(vehicle_type).await?
+            .unwrap_or(MissionValidationSettings::default_for_type(vehicle_type)))
+    }
+
+    pub async fn all_mission_validation_settings(&self) -> anyhow::Result<Vec<MissionValidationSettings>> {
+        self.dao.select_all(TB_MISSION_VALIDATION_SETTINGS).await
+    }
+
+    async fn stored_mission_validation_settings(&self, vehicle_type: &VehicleType) -> anyhow::Result<Option<MissionValidationSettings>> {
+        let settings: Vec<MissionValidationSettings> = self.dao.select_where(
+            TB_MISSION_VALIDATION_SETTINGS, "vehicle_type", vehicle_type).await?;
+        Ok(settings.into_iter().next())
+    }
+
+    pub async fn save_mission_validation_report(&self, report: MissionValidationReport) -> anyhow::Result<MissionValidationReport> {
+        let report = self.dao.update(TB_MISSION_VALIDATION_REPORTS, report).await?;
+
+        self.bus.publish(ServerEvent::MissionValidationReportUpdated { report: report.clone() })?;
+        Ok(report)
+    }
+
+    pub async fn mission_validation_report(&self, mission_id: &MissionId) -> anyhow::Result<MissionValidationReport> {
+        self.dao.select_one(TB_MISSION_VALIDATION_REPORTS, mission_id).await
+    }
+
+    pub async fn delete_mission_validation_report(&self, mission_id: &MissionId) -> anyhow::Result<()> {
+        self.dao.delete(TB_MISSION_VALIDATION_REPORTS, mission_id).await
+    }
+}
@@ -4,6 +4,7 @@ use crate::models::events::ServerEvent;
 
 use crate::models::vehicles::VehicleId;
 use crate::models::missions::*;
+use crate::services::missions::route_edit;
 
 const TB_MISSION_ASSIGNMENTS: &str = "mission_assignments";
 const TB_MISSION_ROUTES: &str = "mission_routes";
@@ -11,66 +12,101 @@ const TB_MISSION_STATUSES: &str = "mission_statuses";
 
 impl Dal {
     pub async fn create_new_mission(&self, vehicle_id: &VehicleId) -> anyhow::Result<Mission> {
-        if !vehicle_id.is_empty() {
-            let vehicle_mission_exists = self.mission_assignment_by_vehicle_id(&vehicle_id).await?;
-            if vehicle_mission_exists.is_some() {
-                return Err(anyhow::anyhow!("Mission for vehicle_id {} already exists", vehicle_id));
-            }
-        }
-
-        // Crete new vehicle-mission mapping
-        let assignment = self.dao.create(TB_MISSION_ASSIGNMENTS, MissionAssignment{
-            id: String::new(), // will be generated
-            vehicle_id: vehicle_id.clone(),
-        }).await?;
-
-        // Create new mission route
+        // Create new mission route, its id is the mission id
         let route = self.dao.create(TB_MISSION_ROUTES, MissionRoute{
-            id: assignment.id.clone(),
+            id: String::new(), // will be generated
             items: Vec::new(),
         }).await?;
 
         // Create new mission status
         let status = self.dao.create(TB_MISSION_STATUSES, MissionStatus{
-            id: assignment.id.clone(),
+            id: route.id.clone(),
             state: MissionUpdateState::NotActual {},
             progress: MissionProgress { current: None, reached: vec![] },
         }
         ).await?;
 
-        let saved_mission = Mission {
-            id: assignment.id,
-            vehicle_id: assignment.vehicle_id,
-            route,
-            status
-        };
+        // Bind mission to the vehicle, first vehicle mission becomes active
+        let active = !vehicle_id.is_empty() && self.active_mission_assignment(vehicle_id).await?.is_none();
+        let assignment = self.dao.create(TB_MISSION_ASSIGNMENTS, MissionAssignment{
+            id: String::new(), // will be generated
+            mission_id: route.id.clone(),
+            vehicle_id: vehicle_id.clone(),
+            plan_id: None,
+            active
+        }).await?;
 
+        let saved_mission = mission_from(assignment, route, status);
         self.bus.publish(ServerEvent::MissionUpserted { mission: saved_mission.clone() })?;
         Ok(saved_mission)
     }
 
     pub async fn delete_mission(&self, mission_id: &MissionId) -> anyhow::Result<()> {
+        let assignment = self.mission_assignment(mission_id).await?;
+
+        self.delete_mission_revisions(mission_id).await?;
+        self.delete_mission_patterns(mission_id).await?;
         self.dao.delete(TB_MISSION_STATUSES, mission_id).await?;
         self.dao.delete(TB_MISSION_ROUTES, mission_id).await?;
-        self.dao.delete(TB_MISSION_ASSIGNMENTS, mission_id).await?;
+        self.dao.delete(TB_MISSION_ASSIGNMENTS, &assignment.id).await?;
+        self.delete_mission_validation_report(mission_id).await?;
 
         self.bus.publish(ServerEvent::MissionRemoved { mission_id: mission_id.into() })?;
+
+        // Vehicle keeps an active mission while it has any
+        if assignment.active {
+            let remaining = self.vehicle_mission_assignments(&assignment.vehicle_id).await?;
+            if let Some(next) = remaining.first() {
+                self.activate_mission(&next.mission_id).await?;
+            }
+        }
         Ok(())
     }
 
-    pub async fn update_mission(&self, mission: Mission) -> anyhow::Result<Mission> {
-        let route = self.dao.update(TB_MISSION_ROUTES, mission.route).await?;
-        let status = self.dao.update(TB_MISSION_STATUSES, mission.status).await?;
+    // Only the active mission is uploaded to and downloaded from the vehicle
+    pub async fn activate_mission(&self, mission_id: &MissionId) -> anyhow::Result<Mission> {
+        let assignment = self.mission_assignment(mission_id).await?;
+        if let Some(active) = self.active_mission_assignment(&assignment.vehicle_id).await? {
+            if active.mission_id == assignment.mission_id {
+                return self.mission(mission_id).await;
+            }
+            let status = self.mission_status(&active.mission_id).await?;
+            if !matches!(status.state, MissionUpdateState::NotActual {} | MissionUpdateState::Actual { .. }) {
+                return Err(anyhow::anyhow!("Mission operation is in progress for vehicle {}", assignment.vehicle_id));
+            }
+        }
+
+        let mut changed = Vec::new();
+        for mut other in self.vehicle_mission_assignments(&assignment.vehicle_id).await? {
+            let active = other.mission_id == assignment.mission_id;
+            if other.active != active {
+                other.active = active;
+                changed.push(other);
+            }
+        }
+        for assignment in self.dao.update_all(TB_MISSION_ASSIGNMENTS, changed).await? {
+            let route = self.mission_route(&assignment.mission_id).await?;
+            let status = self.mission_status(&assignment.mission_id).await?;
+            self.bus.publish(ServerEvent::MissionUpserted { mission: mission_from(assignment, route, status) })?;
+        }
 
-        let updated_mission = Mission {
-            id: mission.id.clone(),
-            vehicle_id: mission.vehicle_id.clone(),
-            route,
-            status
-        };
+        // Vehicle holds the route of the previous mission
+        let status = self.mission_status(mission_id).await?;
+        if status.state != (MissionUpdateState::NotActual {}) {
+            self.update_mission_status(MissionStatus { state: MissionUpdateState::NotActual {}, ..status }).await?;
+        }
+        self.mission(mission_id).await
+    }
 
-        self.bus.publish(ServerEvent::MissionUpserted { mission: updated_mission.clone() })?;
-        Ok(updated_mission)
+    pub async fn set_mission_plan(&self, mission_id: &MissionId, plan_id: Option<MissionPlanId>) -> anyhow::Result<Mission> {
+        let mut assignment = self.mission_assignment(mission_id).await?;
+        if assignment.plan_id != plan_id {
+            assignment.plan_id = plan_id;
+            self.dao.update(TB_MISSION_ASSIGNMENTS, assignment).await?;
+        }
+        let mission = self.mission(mission_id).await?;
+        self.bus.publish(ServerEvent::MissionUpserted { mission: mission.clone() })?;
+        Ok(mission)
     }
 
     pub async fn update_route(&self, route: MissionRoute) -> anyhow::Result<MissionRoute> {
@@ -83,8 +119,46 @@ impl Dal {
         Ok(route)
     }
 
+    pub async fn edit_route(&self, mission_id: &MissionId, edit: RouteEdit) -> anyhow::Result<MissionRoute> {
+        let _lock = self.route_edits.lock().await;
+        let mut route: MissionRoute = self.dao.select_one(TB_MISSION_ROUTES, mission_id).await?;
+        let items = route_edit::apply_edit(&route.items, &edit)?;
+        self.record_baseline_revision_locked(&route).await?;
+        route.items = items;
+
+        let route = self.dao.update_all(TB_MISSION_ROUTES, vec![route]).await?.remove(0);
+        self.bus.publish(ServerEvent::MissionRouteUpdated { route: route.clone() })?;
+        self.record_route_revision_locked(mission_id, RevisionAuthor::Operator).await?;
+        Ok(route)
+    }
+
+    // Downloaded route is recorded as one vehicle revision when the download completes
+    pub async fn truncate_downloaded_route(&self, mission_id: &MissionId, count: u16) -> anyhow::Result<MissionRoute> {
+        let _lock = self.route_edits.lock().await;
+        let mut route: MissionRoute = self.dao.select_one(TB_MISSION_ROUTES, mission_id).await?;
+        if route.items.len() <= count as usize {
+            return Ok(route);
+        }
+        self.record_baseline_revision_locked(&route).await?;
+        route.items.truncate(count as usize);
+        self.update_route(route).await
+    }
+
+    pub async fn upsert_downloaded_route_item(&self, mission_id: &MissionId, item: MissionRouteItem, index: u16) -> anyhow::Result<Vec<(u16, MissionRouteItem)>> {
+        let _lock = self.route_edits.lock().await;
+        self.upsert_route_item_locked(mission_id, item, index).await
+    }
+
     pub async fn upsert_route_item(&self, mission_id: &MissionId, item: MissionRouteItem, index: u16) -> anyhow::Result<Vec<(u16, MissionRouteItem)>> {
+        let _lock = self.route_edits.lock().await;
+        let new_items = self.upsert_route_item_locked(mission_id, item, index).await?;
+        self.record_route_revision_locked(mission_id, RevisionAuthor::Operator).await?;
+        Ok(new_items)
+    }
+
+    async fn upsert_route_item_locked(&self, mission_id: &MissionId, item: MissionRouteItem, index: u16) -> anyhow::Result<Vec<(u16, MissionRouteItem)>> {
         let mut route: MissionRoute = self.dao.select_one(TB_MISSION_ROUTES, mission_id).await?;
+        self.record_baseline_revision_locked(&route).await?;
         let index = index as usize;
 
         let mut new_items = Vec::new();
@@ -113,11 +187,24 @@ impl Dal {
     }
 
     pub async fn remove_route_item(&self, mission_id: &MissionId, index: u16) -> anyhow::Result<u16> {
+        let _lock = self.route_edits.lock().await;
         let mut route: MissionRoute = self.dao.select_one(TB_MISSION_ROUTES, mission_id).await?;
 
-        route.items.remove(index as usize);
-        self.dao.update(TB_MISSION_ROUTES, route).await?;
+        if index as usize >= route.items.len() {
+            return Err(anyhow::anyhow!("Route item {} is out of route of {} items", index, route.items.len()));
+        }
+        self.record_baseline_revision_locked(&route).await?;
+        let jumps_shifted = route.items.iter()
+            .any(|item| matches!(item, MissionRouteItem::DoJump { target, .. } if *target > index));
+        route_edit::splice_items(&mut route.items, index as usize..index as usize + 1, Vec::new());
+
+        let route = self.dao.update(TB_MISSION_ROUTES, route).await?;
         self.bus.publish(ServerEvent::MissionRouteItemRemoved { mission_id: mission_id.clone(), index })?;
+        // Jumps past the removed item are shifted as well
+        if jumps_shifted {
+            self.bus.publish(ServerEvent::MissionRouteUpdated { route })?;
+        }
+        self.record_route_revision_locked(mission_id, RevisionAuthor::Operator).await?;
         Ok(index)
     }
 
@@ -132,47 +219,59 @@ impl Dal {
     }
 
     pub async fn mission(&self, mission_id: &MissionId) -> anyhow::Result<Mission> {
-        let assignment: MissionAssignment = self.dao.select_one(TB_MISSION_ASSIGNMENTS, mission_id).await?;
+        let assignment = self.mission_assignment(mission_id).await?;
         let route = self.dao.select_one(TB_MISSION_ROUTES, mission_id).await?;
         let status = self.dao.select_one(TB_MISSION_STATUSES, mission_id).await?;
-        Ok(Mission {
-            id: assignment.id,
-            vehicle_id: assignment.vehicle_id,
-            route,
-            status
-        })
+        Ok(mission_from(assignment, route, status))
     }
 
     pub async fn all_missions(&self) -> anyhow::Result<Vec<Mission>> {
         let mut missions = Vec::new();
-        for assignment in self.dao.select_all::<MissionAssignment>(TB_MISSION_ASSIGNMENTS).await? {
-            let route = self.dao.select_one(TB_MISSION_ROUTES, &assignment.id).await?;
-            let status = self.dao.select_one(TB_MISSION_STATUSES, &assignment.id).await?;
-            let mission = Mission {
-                id: assignment.id,
-                vehicle_id: assignment.vehicle_id,
-                route,
-                status
-            };
-            missions.push(mission);
+        let assignments: Vec<MissionAssignment> = self.dao.select_all(TB_MISSION_ASSIGNMENTS).await?;
+        for assignment in assignments {
+            let route = self.dao.select_one(TB_MISSION_ROUTES, &assignment.mission_id).await?;
+            let status = self.dao.select_one(TB_MISSION_STATUSES, &assignment.mission_id).await?;
+            missions.push(mission_from(assignment, route, status));
         }
         Ok(missions)
     }
 
-    pub async fn mission_assignment(&self, id: &MissionId) -> anyhow::Result<MissionAssignment> {
-        self.dao.select_one(TB_MISSION_ASSIGNMENTS, id).await
+    pub async fn mission_assignment(&self, mission_id: &MissionId) -> anyhow::Result<MissionAssignment> {
+        let assignments: Vec<MissionAssignment> = self.dao.select_where(TB_MISSION_ASSIGNMENTS, "mission_id", mission_id).await?;
+        assignments.into_iter().next()
+            .ok_or_else(|| anyhow::anyhow!("No assignment found for mission {}", mission_id))
+    }
+
+    pub async fn vehicle_mission_assignments(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<MissionAssignment>> {
+        self.dao.select_where(TB_MISSION_ASSIGNMENTS, "vehicle_id", vehicle_id).await
     }
 
-    pub async fn mission_assignment_by_vehicle_id(&self, vehicle_id: &VehicleId) -> anyhow::Result<Option<MissionAssignment>> {
-        let assignments = self.dao.select_where(
-            TB_MISSION_ASSIGNMENTS, "vehicle_id", vehicle_id).await?;
+    pub async fn active_mission_assignment(&self, vehicle_id: &VehicleId) -> anyhow::Result<Option<MissionAssignment>> {
+        let mut assignments: Vec<MissionAssignment> = self.vehicle_mission_assignments(vehicle_id).await?
+            .into_iter()
+            .filter(|assignment| assignment.active)
+            .collect();
         match assignments.len() {
             0 => Ok(None),
-            1 => Ok(Some(assignments.first().cloned().unwrap())),
-            _ => Err(anyhow::anyhow!("Multiple missions found for vehicle_id: {:?}", vehicle_id))
+            1 => Ok(assignments.pop()),
+            _ => Err(anyhow::anyhow!("Multiple active missions found for vehicle_id: {:?}", vehicle_id))
         }
     }
 
+    // Assignments stored before missions got own ids are the only vehicle mission, keyed by the mission id
+    pub async fn migrate_mission_assignments(&self) -> anyhow::Result<()> {
+        let assignments: Vec<MissionAssignment> = self.dao.select_all(TB_MISSION_ASSIGNMENTS).await?;
+        let legacy: Vec<MissionAssignment> = assignments.into_iter()
+            .filter(|assignment| assignment.mission_id.is_empty())
+            .map(|assignment| MissionAssignment { mission_id: assignment.id.clone(), active: true, ..assignment })
+            .collect();
+        if !legacy.is_empty() {
+            log::info!("Migrating {} legacy mission assignments", legacy.len());
+            self.dao.update_all(TB_MISSION_ASSIGNMENTS, legacy).await?;
+        }
+        Ok(())
+    }
+
     pub async fn mission_route(&self, mission_id: &MissionId) -> anyhow::Result<MissionRoute> {
         self.dao.select_one(TB_MISSION_ROUTES, mission_id).await
     }
@@ -181,3 +280,14 @@ impl Dal {
         self.dao.select_one(TB_MISSION_STATUSES, mission_id).await
     }
 }
+
+fn mission_from(assignment: MissionAssignment, route: MissionRoute, status: MissionStatus) -> Mission {
+    Mission {
+        id: assignment.mission_id,
+        vehicle_id: assignment.vehicle_id,
+        plan_id: assignment.plan_id,
+        active: assignment.active,
+        route,
+        status
+    }
+}
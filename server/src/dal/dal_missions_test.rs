@@ -1,23 +1,26 @@
+use crate::dal::{dal, test_utils::{dal_with_dao, in_memory_dal, in_memory_dao}};
 
-use surrealdb::{engine::local::Mem, Surreal};
-
-use crate::db::surreal_dao::Dao;
-use crate::{bus::bus, dal::dal};
-
-use crate::models::missions::{Mission, MissionId, MissionRouteItem};
+use crate::models::missions::{
+    Mission, MissionId, MissionProgress, MissionRoute, MissionRouteItem, MissionStatus, MissionUpdateState, RouteEdit
+};
 use crate::models::spatial::{Geodetic, GeodeticFrame};
 use crate::models::{events::ServerEvent, vehicles::VehicleId};
 
-async fn setup() -> (dal::Dal, tokio::sync::broadcast::Receiver<ServerEvent>) {
-    let db = Surreal::new::<Mem>(())
-        .await
-        .expect("Error establishing a database connection");
-    db.use_ns("test").use_db("test").await.expect("Error setting namespace and database");
-
-    let dao = Dao::new(db);
+// Route changes also publish their revisions, skip them
+async fn recv_route_event(rx: &mut tokio::sync::broadcast::Receiver<ServerEvent>) -> ServerEvent {
+    loop {
+        match rx.recv().await.expect("Error receiving event") {
+            ServerEvent::MissionRevisionUpserted { .. } | ServerEvent::MissionRevisionRemoved { .. } => continue,
+            event => return event
+        }
+    }
+}
 
-    let bus = bus::EventBus::<ServerEvent>::new();
-    (dal::Dal::new(dao, bus.clone()), bus.subscribe())
+fn assert_no_route_events(rx: &mut tokio::sync::broadcast::Receiver<ServerEvent>) {
+    while let Ok(event) = rx.try_recv() {
+        assert!(matches!(event,
+            ServerEvent::MissionRevisionUpserted { .. } | ServerEvent::MissionRevisionRemoved { .. }));
+    }
 }
 
 async fn create_new_mission(
@@ -73,7 +76,7 @@ async fn upsert_route_item(
         assert_eq!(response[i].0, expected_index);
         assert_eq!(response[i].1, expected_item);
 
-        match rx.recv().await.expect("Error receiving event") {
+        match recv_route_event(rx).await {
             ServerEvent::MissionRouteItemUpserted{ mission_id, index: idx, item: item_back } => {
                 assert_eq!(mission_id, mission_id);
                 assert_eq!(idx, expected_index);
@@ -88,7 +91,7 @@ async fn upsert_route_item(
 
 #[tokio::test]
 async fn test_crud_mission() {
-    let (dal, mut rx) = setup().await;
+    let (dal, mut rx) = in_memory_dal().await;
     let vehicle_id = "mav_1".to_string();
 
     let mission = create_new_mission(&dal, &mut rx, &vehicle_id).await;
@@ -102,9 +105,11 @@ async fn test_crud_mission() {
     dal.mission_status(&mission.id).await
         .expect("Status must exist for created mission");
 
-    let assignment = dal.mission_assignment_by_vehicle_id(&vehicle_id).await
-        .expect("Error reading mission for vehicle");
-    assert_eq!(mission.id, assignment.unwrap().id);
+    let assignment = dal.active_mission_assignment(&vehicle_id).await
+        .expect("Error reading mission for vehicle")
+        .expect("First vehicle mission must be active");
+    assert_eq!(mission.id, assignment.mission_id);
+    assert_ne!(mission.id, assignment.id);
 
     dal.delete_mission(&mission.id).await.expect("Error deleting mission");
 
@@ -121,7 +126,7 @@ async fn test_crud_mission() {
 
 #[tokio::test]
 async fn test_upsert_route_item() {
-    let (dal, mut rx) = setup().await;
+    let (dal, mut rx) = in_memory_dal().await;
     let vehicle_id = "mav_1".to_string();
 
     let mission_id = create_new_mission(&dal, &mut rx, &vehicle_id).await.id;
@@ -196,3 +201,159 @@ async fn test_upsert_route_item() {
     assert_eq!(route.items[3], fill_gap);
     assert_eq!(route.items[4], last);
 }
+
+#[tokio::test]
+async fn test_edit_route() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+
+    let mission_id = create_new_mission(&dal, &mut rx, &vehicle_id).await.id;
+
+    let waypoint = |latitude: f64| MissionRouteItem::Waypoint {
+        position: Geodetic {
+            latitude,
+            longitude: 56.6345,
+            altitude: 300.00,
+            frame: GeodeticFrame::Wgs84RelativeHome
+        },
+        hold: 0,
+        pass_radius: 0.0,
+        accept_radius: 10.0,
+        yaw: None
+    };
+
+    let edits = vec![
+        (RouteEdit::Replace { items: vec![waypoint(45.1), waypoint(45.2)] }, vec![waypoint(45.1), waypoint(45.2)]),
+        (RouteEdit::Insert { index: 1, items: vec![waypoint(45.3)] }, vec![waypoint(45.1), waypoint(45.3), waypoint(45.2)]),
+        (RouteEdit::Duplicate { from: 0, count: 2, to: 3 },
+            vec![waypoint(45.1), waypoint(45.3), waypoint(45.2), waypoint(45.1), waypoint(45.3)]),
+        (RouteEdit::Reorder { order: vec![4, 3, 2, 1, 0] },
+            vec![waypoint(45.3), waypoint(45.1), waypoint(45.2), waypoint(45.3), waypoint(45.1)]),
+    ];
+
+    for (edit, expected) in edits {
+        let route = dal.edit_route(&mission_id, edit).await
+            .expect("Error editing route");
+        assert_eq!(route.items, expected);
+
+        // One event for the whole operation
+        match recv_route_event(&mut rx).await {
+            ServerEvent::MissionRouteUpdated{ route: route_back } => assert_eq!(route, route_back),
+            _ => panic!("Unexpected event")
+        }
+        assert_no_route_events(&mut rx);
+    }
+
+    let route = dal.mission_route(&mission_id).await
+        .expect("Error reading mission route");
+
+    // Invalid edit leaves route untouched
+    assert!(dal.edit_route(&mission_id, RouteEdit::Move { from: 4, count: 2, to: 0 }).await.is_err());
+    assert!(rx.try_recv().is_err());
+    assert_eq!(dal.mission_route(&mission_id).await.expect("Error reading mission route"), route);
+}
+
+#[tokio::test]
+async fn test_concurrent_route_item_changes() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let mission_id = create_new_mission(&dal, &mut rx, &"mav_1".to_string()).await.id;
+
+    let item = |altitude: f32| MissionRouteItem::Takeoff {
+        position: Geodetic { altitude, ..Geodetic::default() },
+        pitch: 15.0,
+        yaw: None
+    };
+    let (first, second, third) = tokio::join!(
+        dal.upsert_route_item(&mission_id, item(10.0), 0),
+        dal.upsert_route_item(&mission_id, item(20.0), 1),
+        dal.upsert_route_item(&mission_id, item(30.0), 2)
+    );
+    first.and(second).and(third).expect("Error setting route item");
+
+    let route = dal.mission_route(&mission_id).await.expect("Error reading mission route");
+    assert_eq!(route.items, vec![item(10.0), item(20.0), item(30.0)]);
+
+    let (first, second) = tokio::join!(
+        dal.remove_route_item(&mission_id, 2),
+        dal.remove_route_item(&mission_id, 0)
+    );
+    first.and(second).expect("Error removing route item");
+
+    let route = dal.mission_route(&mission_id).await.expect("Error reading mission route");
+    assert_eq!(route.items, vec![item(20.0)]);
+}
+
+#[tokio::test]
+async fn test_remove_route_item_out_of_route() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let mission_id = create_new_mission(&dal, &mut rx, &"mav_1".to_string()).await.id;
+
+    assert!(dal.remove_route_item(&mission_id, 0).await.is_err());
+    assert!(rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_multiple_vehicle_missions() {
+    let (dal, mut rx) = in_memory_dal().await;
+    let vehicle_id = "mav_1".to_string();
+
+    let first = create_new_mission(&dal, &mut rx, &vehicle_id).await;
+    let second = create_new_mission(&dal, &mut rx, &vehicle_id).await;
+    assert!(first.active);
+    assert!(!second.active);
+
+    let assignments = dal.vehicle_mission_assignments(&vehicle_id).await
+        .expect("Error reading vehicle missions");
+    assert_eq!(assignments.len(), 2);
+
+    let activated = dal.activate_mission(&second.id).await.expect("Error activating mission");
+    assert!(activated.active);
+    assert!(!dal.mission(&first.id).await.expect("Error reading mission").active);
+    let active = dal.active_mission_assignment(&vehicle_id).await
+        .expect("Error reading active mission")
+        .expect("Vehicle must have active mission");
+    assert_eq!(active.mission_id, second.id);
+
+    // Active mission can't be switched while it's being synced
+    let mut status = dal.mission_status(&second.id).await.expect("Error reading status");
+    status.state = MissionUpdateState::Upload { total: 2, progress: 1 };
+    dal.update_mission_status(status).await.expect("Error updating status");
+    assert!(dal.activate_mission(&first.id).await.is_err());
+    let mut status = dal.mission_status(&second.id).await.expect("Error reading status");
+    status.state = MissionUpdateState::NotActual {};
+    dal.update_mission_status(status).await.expect("Error updating status");
+
+    // Removing active mission activates the remaining one
+    dal.delete_mission(&second.id).await.expect("Error deleting mission");
+    assert!(dal.mission(&first.id).await.expect("Error reading mission").active);
+
+    dal.delete_mission(&first.id).await.expect("Error deleting mission");
+    assert!(dal.active_mission_assignment(&vehicle_id).await.expect("Error reading active mission").is_none());
+}
+
+#[tokio::test]
+async fn test_mission_assigned_before_own_ids() {
+    let dao = in_memory_dao().await;
+    let (dal, _rx) = dal_with_dao(dao.clone());
+
+    dao.create("mission_routes", MissionRoute { id: "legacy".into(), items: Vec::new() }).await
+        .expect("Error creating route");
+    dao.create("mission_statuses", MissionStatus {
+        id: "legacy".into(),
+        state: MissionUpdateState::NotActual {},
+        progress: MissionProgress { current: None, reached: vec![] }
+    }).await.expect("Error creating status");
+    dao.create("mission_assignments", serde_json::json!({ "id": "legacy", "vehicle_id": "mav_1" })).await
+        .expect("Error creating assignment");
+    dal.migrate().await.expect("Error migrating");
+
+    let mission = dal.mission(&"legacy".to_string()).await.expect("Error reading mission");
+    assert_eq!(mission.vehicle_id, "mav_1");
+    assert!(mission.active);
+    assert_eq!(mission.plan_id, None);
+
+    // New vehicle mission doesn't replace the stored one
+    let other = dal.create_new_mission(&"mav_1".to_string()).await.expect("Error creating mission");
+    assert!(!other.active);
+    assert_eq!(dal.all_missions().await.expect("Error reading missions").len(), 2);
+}
@@ -0,0 +1,66 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::payloads::*;
+use crate::models::vehicles::{PayloadId, VehicleId};
+
+const TB_PAYLOADS: &str = "payloads";
+
+impl Dal {
+    pub async fn save_payload(&self, payload: Payload) -> anyhow::Result<Payload> {
+        let payload = if payload.id.is_empty() {
+            let same_protocol_exists = self.payload_by_protocol_id(&payload.vehicle_id, &payload.protocol_id, &payload.payload_type).await?;
+            if same_protocol_exists.is_some() {
+                return Err(anyhow::anyhow!("{:?} payload with protocol_id {:?} already exists for vehicle {}",
+                    payload.payload_type, payload.protocol_id, payload.vehicle_id));
+            }
+            self.dao.create(TB_PAYLOADS, payload).await?
+        } else {
+            self.dao.update(TB_PAYLOADS, payload).await?
+        };
+
+        self.bus.publish(ServerEvent::PayloadUpserted { payload: payload.clone() })?;
+        Ok(payload)
+    }
+
+    pub async fn delete_payload(&self, payload_id: &PayloadId) -> anyhow::Result<()> {
+        self.dao.delete(TB_PAYLOADS, payload_id).await?;
+        self.delete_payload_telemetry(payload_id).await?;
+
+        self.bus.publish(ServerEvent::PayloadRemoved { payload_id: payload_id.into() })?;
+        Ok(())
+    }
+
+    pub async fn delete_vehicle_payloads(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
+        for payload in self.vehicle_payloads(vehicle_id).await? {
+            self.delete_payload(&payload.id).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn payload(&self, payload_id: &PayloadId) -> anyhow::Result<Payload> {
+        self.dao.select_one(TB_PAYLOADS, payload_id).await
+    }
+
+    pub async fn find_payload(&self, payload_id: &PayloadId) -> anyhow::Result<Option<Payload>> {
+        self.dao.select_optional(TB_PAYLOADS, payload_id).await
+    }
+
+    pub async fn payload_by_protocol_id(
+        &self,
+        vehicle_id: &VehicleId,
+        protocol_id: &PayloadProtocolId,
+        payload_type: &PayloadType
+    ) -> anyhow::Result<Option<Payload>> {
+        let payloads = self.vehicle_payloads(vehicle_id).await?;
+        Ok(payloads.into_iter().find(|payload| &payload.protocol_id == protocol_id && &payload.payload_type == payload_type))
+    }
+
+    pub async fn vehicle_payloads(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<Payload>> {
+        self.dao.select_where(TB_PAYLOADS, "vehicle_id", vehicle_id).await
+    }
+
+    pub async fn all_payloads(&self) -> anyhow::Result<Vec<Payload>> {
+        self.dao.select_all(TB_PAYLOADS).await
+    }
+}
@@ -0,0 +1,75 @@
+use crate::dal::test_utils::in_memory_dal;
+
+use crate::models::payloads::{Payload, PayloadProtocolId, PayloadType};
+use crate::models::telemetry::Gimbal;
+
+fn test_payload(vehicle_id: &str, comp_id: u8, payload_type: PayloadType) -> Payload {
+    Payload {
+        id: String::new(),
+        vehicle_id: vehicle_id.into(),
+        name: format!("{:?} {}", payload_type, comp_id),
+        payload_type,
+        protocol_id: PayloadProtocolId::MavlinkComponent { comp_id }
+    }
+}
+
+#[tokio::test]
+async fn test_payload_by_protocol_id() {
+    let (dal, _) = in_memory_dal().await;
+
+    let camera = dal.save_payload(test_payload("mav_1", 100, PayloadType::Camera)).await
+        .expect("Error saving payload");
+    dal.save_payload(test_payload("mav_2", 100, PayloadType::Camera)).await
+        .expect("Error saving payload");
+
+    let protocol_id = PayloadProtocolId::MavlinkComponent { comp_id: 100 };
+    let found = dal.payload_by_protocol_id(&"mav_1".into(), &protocol_id, &PayloadType::Camera).await
+        .expect("Error getting payload");
+    assert_eq!(found, Some(camera));
+
+    let found = dal.payload_by_protocol_id(&"mav_1".into(), &protocol_id, &PayloadType::Gimbal).await
+        .expect("Error getting payload");
+    assert_eq!(found, None);
+}
+
+#[tokio::test]
+async fn test_payloads_of_same_component() {
+    let (dal, _) = in_memory_dal().await;
+
+    // Autopilot may proxy both camera and gimbal from its own component
+    let camera = dal.save_payload(test_payload("mav_1", 1, PayloadType::Camera)).await
+        .expect("Error saving camera");
+    let gimbal = dal.save_payload(test_payload("mav_1", 1, PayloadType::Gimbal)).await
+        .expect("Error saving gimbal");
+    assert_ne!(camera.id, gimbal.id);
+
+    assert!(dal.save_payload(test_payload("mav_1", 1, PayloadType::Gimbal)).await.is_err());
+    assert_eq!(dal.vehicle_payloads(&"mav_1".into()).await.expect("Error getting payloads").len(), 2);
+}
+
+#[tokio::test]
+async fn test_delete_vehicle_payloads() {
+    let (dal, _) = in_memory_dal().await;
+
+    dal.save_payload(test_payload("mav_1", 100, PayloadType::Camera)).await.expect("Error saving payload");
+    dal.save_payload(test_payload("mav_1", 154, PayloadType::Gimbal)).await.expect("Error saving payload");
+    let other = dal.save_payload(test_payload("mav_2", 100, PayloadType::Camera)).await.expect("Error saving payload");
+
+    dal.delete_vehicle_payloads(&"mav_1".into()).await.expect("Error deleting payloads");
+
+    assert!(dal.vehicle_payloads(&"mav_1".into()).await.expect("Error getting payloads").is_empty());
+    assert_eq!(dal.all_payloads().await.expect("Error getting payloads"), vec![other]);
+}
+
+#[tokio::test]
+async fn test_delete_payload_telemetry() {
+    let (dal, _) = in_memory_dal().await;
+
+    let gimbal = dal.save_payload(test_payload("mav_1", 154, PayloadType::Gimbal)).await.expect("Error saving payload");
+    dal.save_telemetry_gimbal("mav_1".into(), Gimbal::default_for_id(&gimbal.id)).await
+        .expect("Error saving gimbal telemetry");
+    assert!(dal.telemetry_gimbal(&gimbal.id).await.is_ok());
+
+    dal.delete_vehicle_payloads(&"mav_1".into()).await.expect("Error deleting payloads");
+    assert!(dal.telemetry_gimbal(&gimbal.id).await.is_err());
+}
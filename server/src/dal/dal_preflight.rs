@@ -0,0 +1,57 @@
+use super::dal::Dal;
+
+use crate::models::events::ServerEvent;
+use crate::models::preflight::*;
+use crate::models::vehicles::{VehicleId, VehicleType};
+
+const TB_PREFLIGHT_SETTINGS: &str = "preflight_settings";
+const TB_PREFLIGHT_REPORTS: &str = "preflight_reports";
+
+impl Dal {
+    pub async fn save_preflight_settings(&self, mut settings: PreflightSettings) -> anyhow::Result<PreflightSettings> {
+        // Only one settings entry per vehicle type
+        if let Some(existing) = self.stored_preflight_settings(&settings.vehicle_type).await? {
+            settings.id = existing.id;
+        }
+
+        let settings = if settings.id.is_empty() {
+            self.dao.create(TB_PREFLIGHT_SETTINGS, settings).await?
+        } else {
+            self.dao.update(TB_PREFLIGHT_SETTINGS, settings).await?
+        };
+
+        self.bus.publish(ServerEvent::PreflightSettingsUpdated { settings: settings.clone() })?;
+        Ok(settings)
+    }
+
+    pub async fn preflight_settings(&self, vehicle_type: &VehicleType) -> anyhow::Result<PreflightSettings> {
+        Ok(self.stored_preflight_settings(vehicle_type).await?
+            .unwrap_or(PreflightSettings::default_for_type(vehicle_type)))
+    }
+
+    pub async fn all_preflight_settings(&self) -> anyhow::Result<Vec<PreflightSettings>> {
+        self.dao.select_all(TB_PREFLIGHT_SETTINGS).await
+    }
+
+    async fn stored_preflight_settings(&self, vehicle_type: &VehicleType) -> anyhow::Result<Option<PreflightSettings>> {
+        let settings: Vec<PreflightSettings> = self.dao.select_where(
+            TB_PREFLIGHT_SETTINGS, "vehicle_type", vehicle_type).await?;
+        Ok(settings.into_iter().next())
+    }
+
+    pub async fn save_preflight_report(&self, mut report: PreflightReport) -> anyhow::Result<PreflightReport> {
+        report.timestamp = chrono::Utc::now().timestamp_millis();
+        let report = self.dao.update(TB_PREFLIGHT_REPORTS, report).await?;
+
+        self.bus.publish(ServerEvent::PreflightReportUpdated { report: report.clone() })?;
+        Ok(report)
+    }
+
+    pub async fn delete_preflight_report(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
+        self.dao.delete(TB_PREFLIGHT_REPORTS, vehicle_id).await
+    }
+
+    pub async fn preflight_report(&self, vehicle_id: &VehicleId) -> anyhow::Result<PreflightReport> {
+        self.dao.select_one(TB_PREFLIGHT_REPORTS, vehicle_id).await
+    }
+}
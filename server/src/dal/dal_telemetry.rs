@@ -1,11 +1,15 @@
-use super::dal::Dal;
+use super::{dal::Dal, dal_alerts::*};
 
-use crate::models::{events::ServerEvent, telemetry::*, vehicles::VehicleId};
+use crate::models::{events::ServerEvent, telemetry::*, vehicles::{PayloadId, VehicleId}};
 
 const TB_TELEMETRY_FLIGHT: &str = "telemetry_flight";
 const TB_TELEMETRY_NAVIGATION: &str = "telemetry_navigation";
 const TB_TELEMETRY_RAW_SNS: &str = "telemetry_raw_sns";
 const TB_TELEMETRY_SYSTEM: &str = "telemetry_system";
+const TB_TELEMETRY_GIMBAL: &str = "telemetry_gimbal";
+const TB_TELEMETRY_CAMERA: &str = "telemetry_camera";
+const TB_TELEMETRY_BATTERIES: &str = "telemetry_batteries";
+const TB_TELEMETRY_ENGINE: &str = "telemetry_engine";
 
 impl Dal {
     pub async fn save_telemetry_flight(&self, vehicle_id: VehicleId, mut flight: Flight) -> anyhow::Result<Flight> {
@@ -15,7 +19,8 @@ impl Dal {
         } else {
             self.dao.update(TB_TELEMETRY_FLIGHT, flight).await?
         };
-        self.bus.publish(ServerEvent::FlightUpdated { vehicle_id, flight: flight.clone() })?;
+        self.bus.publish(ServerEvent::FlightUpdated { vehicle_id: vehicle_id.clone(), flight: flight.clone() })?;
+        self.check_alert_rules(&vehicle_id, flight_alert_values(&flight)).await;
         Ok(flight)
     }
 
@@ -26,7 +31,8 @@ impl Dal {
         } else {
             self.dao.update(TB_TELEMETRY_NAVIGATION, navigation).await?
         };
-        self.bus.publish(ServerEvent::NavigationUpdated { vehicle_id, navigation: navigation.clone() })?;
+        self.bus.publish(ServerEvent::NavigationUpdated { vehicle_id: vehicle_id.clone(), navigation: navigation.clone() })?;
+        self.check_alert_rules(&vehicle_id, navigation_alert_values(&navigation)).await;
         Ok(navigation)
     }
 
@@ -37,7 +43,8 @@ impl Dal {
         } else {
             self.dao.update(TB_TELEMETRY_RAW_SNS, raw_sns).await?
         };
-        self.bus.publish(ServerEvent::RawSnsUpdated { vehicle_id, raw_sns: raw_sns.clone() })?;
+        self.bus.publish(ServerEvent::RawSnsUpdated { vehicle_id: vehicle_id.clone(), raw_sns: raw_sns.clone() })?;
+        self.check_alert_rules(&vehicle_id, raw_sns_alert_values(&raw_sns)).await;
         Ok(raw_sns)
     }
 
@@ -48,10 +55,64 @@ impl Dal {
         } else {
             self.dao.update(TB_TELEMETRY_SYSTEM, system).await?
         };
-        self.bus.publish(ServerEvent::SystemUpdated { vehicle_id, system: system.clone() })?;
+        self.bus.publish(ServerEvent::SystemUpdated { vehicle_id: vehicle_id.clone(), system: system.clone() })?;
+        self.check_alert_rules(&vehicle_id, system_alert_values(&system)).await;
         Ok(system)
     }
 
+    pub async fn save_telemetry_gimbal(&self, vehicle_id: VehicleId, mut gimbal: Gimbal) -> anyhow::Result<Gimbal> {
+        gimbal.timestamp = chrono::Utc::now().timestamp();
+        let gimbal = if gimbal.id.is_empty() {
+            self.dao.create(TB_TELEMETRY_GIMBAL, gimbal).await?
+        } else {
+            self.dao.update(TB_TELEMETRY_GIMBAL, gimbal).await?
+        };
+        self.bus.publish(ServerEvent::GimbalUpdated { vehicle_id, gimbal: gimbal.clone() })?;
+        Ok(gimbal)
+    }
+
+    pub async fn save_telemetry_camera(&self, vehicle_id: VehicleId, mut camera: Camera) -> anyhow::Result<Camera> {
+        camera.timestamp = chrono::Utc::now().timestamp();
+        let camera = if camera.id.is_empty() {
+            self.dao.create(TB_TELEMETRY_CAMERA, camera).await?
+        } else {
+            self.dao.update(TB_TELEMETRY_CAMERA, camera).await?
+        };
+        self.bus.publish(ServerEvent::CameraUpdated { vehicle_id, camera: camera.clone() })?;
+        Ok(camera)
+    }
+
+    pub async fn save_telemetry_battery(&self, vehicle_id: VehicleId, mut battery: Battery) -> anyhow::Result<Battery> {
+        battery.timestamp = chrono::Utc::now().timestamp();
+        let battery = self.dao.update(TB_TELEMETRY_BATTERIES, battery).await?;
+        self.bus.publish(ServerEvent::BatteryUpdated { vehicle_id: vehicle_id.clone(), battery: battery.clone() })?;
+        match self.telemetry_batteries(&vehicle_id).await {
+            Ok(batteries) => self.check_alert_rules(&vehicle_id, batteries_alert_values(&batteries)).await,
+            Err(err) => log::warn!("Alert rules evaluation error for vehicle {}: {}", vehicle_id, err)
+        }
+        Ok(battery)
+    }
+
+    pub async fn save_telemetry_engine(&self, vehicle_id: VehicleId, mut engine: Engine) -> anyhow::Result<Engine> {
+        engine.timestamp = chrono::Utc::now().timestamp();
+        let engine = self.dao.update(TB_TELEMETRY_ENGINE, engine).await?;
+        self.bus.publish(ServerEvent::EngineUpdated { vehicle_id, engine: engine.clone() })?;
+        Ok(engine)
+    }
+
+    pub async fn delete_telemetry_batteries(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
+        for battery in self.telemetry_batteries(vehicle_id).await? {
+            self.dao.delete(TB_TELEMETRY_BATTERIES, &battery.id).await?;
+        }
+        Ok(())
+    }
+
+    // Gimbal and camera telemetry is stored by payload
+    pub async fn delete_payload_telemetry(&self, payload_id: &PayloadId) -> anyhow::Result<()> {
+        self.dao.delete(TB_TELEMETRY_GIMBAL, payload_id).await?;
+        self.dao.delete(TB_TELEMETRY_CAMERA, payload_id).await
+    }
+
     pub async fn telemetry_flight(&self, vehicle_id: &VehicleId) -> anyhow::Result<Flight> {
         self.dao.select_one(TB_TELEMETRY_FLIGHT, vehicle_id).await
     }
@@ -67,4 +128,26 @@ impl Dal {
     pub async fn telemetry_system(&self, vehicle_id: &VehicleId) -> anyhow::Result<System> {
         self.dao.select_one(TB_TELEMETRY_SYSTEM, vehicle_id).await
     }
+
+    pub async fn telemetry_gimbal(&self, payload_id: &PayloadId) -> anyhow::Result<Gimbal> {
+        self.dao.select_one(TB_TELEMETRY_GIMBAL, payload_id).await
+    }
+
+    pub async fn telemetry_camera(&self, payload_id: &PayloadId) -> anyhow::Result<Camera> {
+        self.dao.select_one(TB_TELEMETRY_CAMERA, payload_id).await
+    }
+
+    pub async fn telemetry_battery(&self, battery_id: &TelemetryId) -> anyhow::Result<Battery> {
+        self.dao.select_one(TB_TELEMETRY_BATTERIES, battery_id).await
+    }
+
+    pub async fn telemetry_engine(&self, vehicle_id: &VehicleId) -> anyhow::Result<Engine> {
+        self.dao.select_one(TB_TELEMETRY_ENGINE, vehicle_id).await
+    }
+
+    pub async fn telemetry_batteries(&self, vehicle_id: &VehicleId) -> anyhow::Result<Vec<Battery>> {
+        let mut batteries: Vec<Battery> = self.dao.select_where(TB_TELEMETRY_BATTERIES, "vehicle_id", vehicle_id).await?;
+        batteries.sort_by_key(|battery| battery.battery_id);
+        Ok(batteries)
+    }
 }
@@ -0,0 +1,18 @@
+use super::dal::Dal;
+
+use crate::models::{events::ServerEvent, terrain::TerrainReport, vehicles::VehicleId};
+
+const TB_TERRAIN_REPORTS: &str = "terrain_reports";
+
+impl Dal {
+    pub async fn save_terrain_report(&self, mut report: TerrainReport) -> anyhow::Result<TerrainReport> {
+        report.timestamp = chrono::Utc::now().timestamp();
+        let report = self.dao.update(TB_TERRAIN_REPORTS, report).await?;
+        self.bus.publish(ServerEvent::TerrainReportUpdated { report: report.clone() })?;
+        Ok(report)
+    }
+
+    pub async fn terrain_report(&self, vehicle_id: &VehicleId) -> anyhow::Result<TerrainReport> {
+        self.dao.select_one(TB_TERRAIN_REPORTS, vehicle_id).await
+    }
+}
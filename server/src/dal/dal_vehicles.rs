@@ -27,10 +27,17 @@ impl Dal {
     }
 
     pub async fn delete_vehicle(&self, vehicle_id: &VehicleId) -> anyhow::Result<()> {
-        let mission_for_vehicle = self.mission_assignment_by_vehicle_id(&vehicle_id).await?;
-        if let Some(mission_for_vehicle) = mission_for_vehicle {
-            self.delete_mission(&mission_for_vehicle.id).await?
+        for assignment in self.vehicle_mission_assignments(vehicle_id).await? {
+            self.delete_mission(&assignment.mission_id).await?
         }
+        self.delete_vehicle_payloads(vehicle_id).await?;
+        self.delete_telemetry_batteries(vehicle_id).await?;
+        self.clear_vehicle_alerts(vehicle_id).await?;
+        self.clear_vehicle_messages(vehicle_id).await?;
+        self.delete_preflight_report(vehicle_id).await?;
+        self.delete_failsafe_data(vehicle_id).await?;
+        self.delete_vehicle_maintenance(vehicle_id).await?;
+        self.delete_vehicle_flight_sessions(vehicle_id).await?;
 
         self.dao.delete(TB_VEHICLE_STATUSES, vehicle_id).await?;
         self.dao.delete(TB_VEHICLE_DESCRIPTIONS, vehicle_id).await?;
@@ -49,6 +56,10 @@ impl Dal {
         self.dao.select_one(TB_VEHICLE_DESCRIPTIONS, vehicle_id).await
     }
 
+    pub async fn find_vehicle(&self, vehicle_id: &VehicleId) -> anyhow::Result<Option<VehicleDescription>> {
+        self.dao.select_optional(TB_VEHICLE_DESCRIPTIONS, vehicle_id).await
+    }
+
     pub async fn vehicle_by_protocol_id(&self, protocol_id: &ProtocolId) -> anyhow::Result<Option<VehicleDescription>> {
         let vehicles = self.dao.select_where(TB_VEHICLE_DESCRIPTIONS, "protocol_id", protocol_id).await?;
         match vehicles.len() {
@@ -1,9 +1,41 @@
-
 pub mod dal;
 pub mod dal_communication;
 pub mod dal_vehicles;
+pub mod dal_payloads;
+#[cfg(test)]
+mod dal_payloads_test;
 pub mod dal_telemetry;
 pub mod dal_commands;
+#[cfg(test)]
+mod dal_commands_test;
 pub mod dal_missions;
 #[cfg(test)]
 mod dal_missions_test;
+pub mod dal_mission_revisions;
+#[cfg(test)]
+mod dal_mission_revisions_test;
+pub mod dal_mission_plans;
+#[cfg(test)]
+mod dal_mission_plans_test;
+pub mod dal_mission_patterns;
+#[cfg(test)]
+mod dal_mission_patterns_test;
+pub mod dal_mission_validation;
+pub mod dal_captures;
+#[cfg(test)]
+mod dal_captures_test;
+pub mod dal_messages;
+#[cfg(test)]
+mod dal_messages_test;
+pub mod dal_preflight;
+pub mod dal_alerts;
+#[cfg(test)]
+mod dal_alerts_test;
+pub mod dal_failsafe;
+pub mod dal_flights;
+pub mod dal_terrain;
+pub mod dal_maintenance;
+#[cfg(test)]
+mod dal_maintenance_test;
+#[cfg(test)]
+pub mod test_utils;
@@ -0,0 +1,26 @@
+use surrealdb::{engine::local::Mem, Surreal};
+use tokio::sync::broadcast::Receiver;
+
+use crate::bus::bus::EventBus;
+use crate::db::surreal_dao::Dao;
+use crate::models::events::ServerEvent;
+use super::dal::Dal;
+
+// Each call gets its own empty database
+pub async fn in_memory_dao() -> Dao {
+    let db = Surreal::new::<Mem>(())
+        .await
+        .expect("Error establishing a database connection");
+    db.use_ns("test").use_db("test").await.expect("Error setting namespace and database");
+
+    Dao::new(db)
+}
+
+pub async fn in_memory_dal() -> (Dal, Receiver<ServerEvent>) {
+    dal_with_dao(in_memory_dao().await)
+}
+
+pub fn dal_with_dao(dao: Dao) -> (Dal, Receiver<ServerEvent>) {
+    let bus = EventBus::<ServerEvent>::new();
+    (Dal::new(dao, bus.clone()), bus.subscribe())
+}
@@ -1,6 +1,6 @@
 use surrealdb::{engine::local::Db, Surreal};
 
-use super::surreal_query::Builder;
+use super::surreal_query::{Builder, ReturnType};
 
 const ID: &str = "id";
 const STRING: &str = "String";
@@ -44,18 +44,59 @@ impl Dao {
         }
     }
 
+    // All or nothing, wrapped in a transaction
+    pub async fn update_all<T>(&self, table: &str, values: Vec<T>) -> anyhow::Result<Vec<T>>
+    where T: serde::ser::Serialize + for<'de> serde::Deserialize<'de> {
+        let mut query = Builder::new().begin_tx();
+        for value in values.iter() {
+            let mut data = serde_json::to_value(value)?;
+            match extract_surreal_id(&mut data) {
+                Some(id) => query = query.update().thing(table, &id).content(data),
+                None => return Err(anyhow::anyhow!("No id provided"))
+            }
+        }
+        let mut response = query.end_tx().exec(&self.db).await?;
+
+        let mut result = Vec::with_capacity(values.len());
+        for index in 0..values.len() {
+            let json: Option<serde_json::Value> = response.take(index)?;
+            match json {
+                Some(mut json) => {
+                    replace_surreal_id(&mut json);
+                    result.push(serde_json::from_value(json)?);
+                },
+                None => return Err(anyhow::anyhow!("No signle object found in response"))
+            }
+        }
+        Ok(result)
+    }
+
     pub async fn delete(&self, table: &str, id: &str) -> anyhow::Result<()> {
         let response = Builder::new().delete().thing(table, id.into()).exec(&self.db).await?;
         response.check()?;
         Ok(())
     }
 
+    // Deletes in a single statement and returns the deleted record, None if it is missing or already deleted
+    pub async fn take<T>(&self, table: &str, id: &str) -> anyhow::Result<Option<T>>
+    where T: for<'de> serde::Deserialize<'de> {
+        let response = Builder::new().delete().thing(table, id).returns(ReturnType::Before).exec(&self.db).await?;
+        parse_optional_value(response)
+    }
+
     pub async fn select_one<T>(&self, table: &str, id: &str) -> anyhow::Result<T>
     where T: for<'de> serde::Deserialize<'de> {
         let response = Builder::new().select().all().from().thing(table, id.into()).exec(&self.db).await?;
         parse_one_value(response)
     }
 
+    // Missing record is not an error, unlike select_one
+    pub async fn select_optional<T>(&self, table: &str, id: &str) -> anyhow::Result<Option<T>>
+    where T: for<'de> serde::Deserialize<'de> {
+        let response = Builder::new().select().all().from().thing(table, id).exec(&self.db).await?;
+        parse_optional_value(response)
+    }
+
     pub async fn select_where<T, D>(&self, table: &str, field: &str, value: T) -> anyhow::Result<Vec<D>>
     where T: serde::ser::Serialize, D: for<'de> serde::Deserialize<'de> {
         let value = serde_json::to_value(value)?;
@@ -64,6 +105,15 @@ impl Dao {
         parse_many_values(response)
     }
 
+    pub async fn count_where<T>(&self, table: &str, field: &str, value: T) -> anyhow::Result<usize>
+    where T: serde::ser::Serialize {
+        let value = serde_json::to_value(value)?;
+        let mut response = Builder::new().select().some("count()".into()).from().table(table)
+            .equals(field, value).some("GROUP ALL".into()).exec(&self.db).await?;
+        let counts: Vec<serde_json::Value> = response.take(0)?;
+        Ok(counts.first().and_then(|count| count["count"].as_u64()).unwrap_or(0) as usize)
+    }
+
     pub async fn select_all<T>(&self, table: &str) -> anyhow::Result<Vec<T>>
     where T: for<'de> serde::Deserialize<'de> {
         let response = Builder::new().select().all().from().table(table).exec(&self.db).await?;
@@ -95,14 +145,21 @@ fn extract_surreal_id(json: &mut serde_json::Value) -> Option<String> {
     None
 }
 
-fn parse_one_value<T>(mut response: surrealdb::Response) -> anyhow::Result<T>
+fn parse_one_value<T>(response: surrealdb::Response) -> anyhow::Result<T>
+where T: for<'de> serde::Deserialize<'de> {
+    parse_optional_value(response)?.ok_or_else(|| anyhow::anyhow!("No signle object found in response"))
+}
+
+fn parse_optional_value<T>(mut response: surrealdb::Response) -> anyhow::Result<Option<T>>
 where T: for<'de> serde::Deserialize<'de> {
     let json: Option<serde_json::Value> = response.take(0)?;
-    if let Some(mut json) = json {
-        replace_surreal_id(&mut json);
-        return Ok(serde_json::from_value(json)?);
+    match json {
+        Some(mut json) => {
+            replace_surreal_id(&mut json);
+            Ok(Some(serde_json::from_value(json)?))
+        },
+        None => Ok(None)
     }
-    Err(anyhow::anyhow!("No signle object found in response"))
 }
 
 fn parse_many_values<T>(mut response: surrealdb::Response) -> anyhow::Result<Vec<T>>
@@ -2,7 +2,7 @@
 use surrealdb::{engine::local::Mem, Surreal};
 use test_case::test_case;
 
-use crate::models::{colors::EntityColor, vehicles::*};
+use crate::models::{colors::EntityColor, failsafe::FailsafePolicy, vehicles::*};
 
 #[test_case(VehicleDescription {
     id: VehicleId::new(),
@@ -11,7 +11,9 @@ use crate::models::{colors::EntityColor, vehicles::*};
     vehicle_type: VehicleType::FixedWing,
     protocol_id: ProtocolId::MavlinkId{ mav_id: 1 },
     features: vec![VehicleFeatures::PetrolEngine, VehicleFeatures::Parachute],
-    available_modes: vec![VehicleMode::RTL, VehicleMode::Loiter]
+    available_modes: vec![VehicleMode::RTL, VehicleMode::Loiter],
+    failsafe: FailsafePolicy::default(),
+    fuel_tank_capacity: None
 }; "vehicle 1")]
 
 #[test_case(VehicleDescription {
@@ -21,7 +23,9 @@ use crate::models::{colors::EntityColor, vehicles::*};
     vehicle_type: VehicleType::Vtol,
     protocol_id: ProtocolId::MavlinkId{ mav_id: 2 },
     features: vec![VehicleFeatures::Lidar],
-    available_modes: Vec::new()
+    available_modes: Vec::new(),
+    failsafe: FailsafePolicy { enabled: true, ..FailsafePolicy::default() },
+    fuel_tank_capacity: None
 }; "vehicle 2")]
 
 #[tokio::test]
@@ -51,7 +55,9 @@ async fn test_vehicles_dao_operations(vehicle: VehicleDescription) {
         vehicle_type: VehicleType::Vtol,
         protocol_id: ProtocolId::MavlinkId{ mav_id: 2 },
         features: vec![VehicleFeatures::Lidar],
-        available_modes: vec![VehicleMode::Circle, VehicleMode::RTL]
+        available_modes: vec![VehicleMode::Circle, VehicleMode::RTL],
+        failsafe: FailsafePolicy::default(),
+        fuel_tank_capacity: None
     };
     let vehicle_back = dao.update("vehicles", vehicle.clone()).await
         .expect("Error updating vehicle");
@@ -70,6 +76,11 @@ async fn test_vehicles_dao_operations(vehicle: VehicleDescription) {
     assert_eq!(vehicles_by_protocol_id.len(), 1);
     assert_eq!(vehicles_by_protocol_id[0], vehicle);
 
+    // SELECT OPTIONAL
+    let vehicle_back = dao.select_optional::<VehicleDescription>("vehicles", &vehicle.id).await
+        .expect("Error reading vehicle");
+    assert_eq!(vehicle_back, Some(vehicle.clone()));
+
     // DELETE
     dao.delete("vehicles", &vehicle.id).await
         .expect("Error deleting vehicle");
@@ -77,4 +88,72 @@ async fn test_vehicles_dao_operations(vehicle: VehicleDescription) {
     // TRY TO READ THEN EMPTY
     let vehicle_back = dao.select_one::<VehicleDescription>("vehicles", &vehicle.id).await;
     assert!(vehicle_back.is_err());
+    let vehicle_back = dao.select_optional::<VehicleDescription>("vehicles", &vehicle.id).await
+        .expect("Error reading vehicle");
+    assert!(vehicle_back.is_none());
+}
+
+#[tokio::test]
+async fn test_update_all_in_transaction() {
+    let db = Surreal::new::<Mem>(()).await
+        .expect("Error establishing a database connection");
+    db.use_ns("test").use_db("test").await
+        .expect("Error setting namespace and database");
+
+    let dao = super::surreal_dao::Dao::new(db);
+
+    let mut vehicles = Vec::new();
+    for (index, name) in ["first", "second"].iter().enumerate() {
+        let vehicle = dao.create("vehicles", VehicleDescription {
+            id: VehicleId::new(),
+            name: name.to_string(),
+            color: EntityColor::Teal,
+            vehicle_type: VehicleType::Copter,
+            protocol_id: ProtocolId::MavlinkId{ mav_id: index as u8 + 1 },
+            features: Vec::new(),
+            available_modes: Vec::new(),
+            failsafe: FailsafePolicy::default(),
+            fuel_tank_capacity: None
+        }).await.expect("Error saving vehicle");
+        vehicles.push(vehicle);
+    }
+
+    for vehicle in vehicles.iter_mut() {
+        vehicle.color = EntityColor::Cyan;
+    }
+    let vehicles_back = dao.update_all("vehicles", vehicles.clone()).await
+        .expect("Error updating vehicles");
+    assert_eq!(vehicles, vehicles_back);
+
+    for vehicle in vehicles.iter() {
+        let vehicle_back = dao.select_one::<VehicleDescription>("vehicles", &vehicle.id).await
+            .expect("Error reading vehicle");
+        assert_eq!(vehicle, &vehicle_back);
+    }
+
+    // Whole batch is rejected without an id
+    vehicles[1].id = String::new();
+    assert!(dao.update_all("vehicles", vehicles).await.is_err());
+}
+
+#[tokio::test]
+async fn test_vehicle_status_stored_before_vtol_state() {
+    let db = Surreal::new::<Mem>(()).await
+        .expect("Error establishing a database connection");
+    db.use_ns("test").use_db("test").await
+        .expect("Error setting namespace and database");
+
+    let dao = super::surreal_dao::Dao::new(db);
+
+    dao.create("vehicle_statuses", serde_json::json!({
+        "id": "mav_1",
+        "last_heartbeat": 0,
+        "armed": false,
+        "mode": "None",
+        "state": "Unknown"
+    })).await.expect("Error saving vehicle status");
+
+    let status = dao.select_one::<VehicleStatus>("vehicle_statuses", "mav_1").await
+        .expect("Error reading vehicle status");
+    assert_eq!(status, VehicleStatus::default_for_id(&"mav_1".to_string()));
 }
@@ -44,6 +44,7 @@ pub async fn start() -> anyhow::Result<()> {
     let server_bus = bus::bus::EventBus::<ServerEvent>::new();
     let client_bus = bus::bus::EventBus::<ClientEvent>::new();
     let repository = dal::dal::Dal::new(dao, server_bus.clone());
+    repository.migrate().await?;
 
     let mut comm_service = services::communication::service::Service::new(
         repository.clone(),
@@ -51,6 +52,13 @@ pub async fn start() -> anyhow::Result<()> {
         client_bus.clone()
     );
 
+    let mut failsafe_service = services::failsafe::service::Service::new(
+        repository.clone(),
+        client_bus.clone()
+    );
+
+    let mut flights_service = services::flights::service::Service::new(repository.clone());
+
     tokio::select! {
         result = comm_service.start() => {
             match result {
@@ -58,6 +66,18 @@ pub async fn start() -> anyhow::Result<()> {
                 Err(err) => log::error!("Communication service start error: {}", err),
             }
         }
+        result = failsafe_service.start() => {
+            match result {
+                Ok(()) => {},
+                Err(err) => log::error!("Failsafe service start error: {}", err),
+            }
+        }
+        result = flights_service.start() => {
+            match result {
+                Ok(()) => {},
+                Err(err) => log::error!("Flights service start error: {}", err),
+            }
+        }
         _ = api::all_routes::serve(repository, server_bus, client_bus, &DEFAULT_REST_ADDRESS) => {}
         _ = tokio::signal::ctrl_c() => {}
     }
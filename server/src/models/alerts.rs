@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use super::vehicles::{VehicleId, VehicleType};
+
+pub type AlertRuleId = String;
+pub type AlertId = String;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum AlertParameter {
+    // Flight
+    IndicatedAirspeed,
+    GroundSpeed,
+    AltitudeAmsl,
+    Climb,
+    Throttle,
+    // Navigation
+    AltitudeError,
+    AirspeedError,
+    XtrackError,
+    // RawSns
+    SnsFix,
+    SatellitesVisible,
+    // System
+    BatteryVoltage,
+    BatteryCurrent,
+    BatteryRemaining,
+    RadioRssi,
+    RadioRemoteRssi,
+    // Batteries
+    BatteryEndurance
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum AlertCondition {
+    Less,
+    Greater
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum AlertSeverity {
+    Info,
+    Warning,
+    Critical
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum AlertRuleScope {
+    All {},
+    VehicleType { vehicle_type: VehicleType },
+    Vehicle { vehicle_id: VehicleId }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct AlertRule {
+    pub id: AlertRuleId,
+    pub name: String,
+    pub scope: AlertRuleScope,
+    pub parameter: AlertParameter,
+    pub condition: AlertCondition,
+    pub threshold: f32,
+    pub duration: f32, // seconds the condition must hold before raising
+    pub severity: AlertSeverity,
+    pub enabled: bool
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum AlertState {
+    Raised,
+    Acknowledged,
+    Cleared
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Alert {
+    pub id: AlertId,
+    pub rule_id: AlertRuleId,
+    pub vehicle_id: VehicleId,
+    pub severity: AlertSeverity,
+    pub message: String,
+    pub value: f32,
+    pub state: AlertState,
+    pub raised: i64,
+    pub acknowledged: Option<i64>,
+    pub cleared: Option<i64>
+}
+
+impl AlertRule {
+    pub fn is_violated(&self, value: f32) -> bool {
+        match self.condition {
+            AlertCondition::Less => value < self.threshold,
+            AlertCondition::Greater => value > self.threshold
+        }
+    }
+}
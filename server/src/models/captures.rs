@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use super::{flights::FlightSessionId, missions::MissionId, spatial::Geodetic, vehicles::{PayloadId, VehicleId}};
+
+pub type CaptureId = String;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct CapturedImage {
+    pub id: CaptureId,
+    pub vehicle_id: VehicleId,
+    pub payload_id: PayloadId,
+    pub mission_id: Option<MissionId>,
+    #[serde(default)]
+    pub flight_session_id: Option<FlightSessionId>,
+    pub timestamp: i64,
+
+    pub index: i32,
+    pub position: Geodetic,
+    pub relative_altitude: f32,
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+
+    pub success: bool,
+    pub file_url: String
+}
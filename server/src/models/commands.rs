@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
-use super::{spatial::Geodetic, vehicles::{PayloadId, VehicleId, VehicleMode}};
+use super::{preflight::PreflightReport, spatial::Geodetic, telemetry::CameraMode};
+use super::vehicles::{PayloadId, VehicleFeatures, VehicleId, VehicleMode, VehicleType};
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -13,6 +14,21 @@ pub enum Calibration {
     Temperature,
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone)]
+pub enum ParachuteAction {
+    Disable,
+    Enable,
+    Release
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone)]
+pub enum VtolTarget {
+    Multicopter,
+    FixedWing
+}
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[derive(Clone)]
@@ -23,7 +39,13 @@ pub enum Command {
     SetHome { position: Geodetic },
 
     ReturnToLaunch {},
-    NavTo { position: Geodetic },
+    NavTo {
+        position: Geodetic,
+        loiter_radius: Option<f32>,
+        ground_speed: Option<f32>,
+        #[serde(default)]
+        switch_to_guided: bool
+    },
 
     SetAltitude { altitide: f32},
     SetLoiterRadius { radius: f32},
@@ -39,7 +61,25 @@ pub enum Command {
 
     Takeoff { altitude: f32 },
     Land { position: Geodetic, abort_altitude: Option<f32> },
-    GoAround {}
+    GoAround {},
+    VtolTransition { to: VtolTarget },
+
+    Parachute { action: ParachuteAction },
+    EngineControl { start: bool },
+    FlightTermination {},
+
+    CameraTrigger {},
+    CameraRecord { record: bool },
+    StartImageCapture { interval: f32, total_images: u32 },
+    StopImageCapture {},
+    SetCameraMode { mode: CameraMode },
+    RequestCameraInformation {},
+    SetGimbalPitchYaw { pitch: f32, yaw: f32 },
+    SetGimbalRates { pitch_rate: f32, yaw_rate: f32 },
+    SetGimbalRoi { position: Geodetic },
+    ResetGimbalRoi {},
+    GimbalRetract {},
+    GimbalNeutral {}
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -65,6 +105,21 @@ pub enum CommandExecutor {
 }
 
 pub type CommandId = String;
+pub type ConfirmationToken = String;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone)]
+pub enum CommandRejection {
+    VehicleNotFound { vehicle_id: VehicleId },
+    PayloadNotFound { payload_id: PayloadId },
+    ModeUnavailable { mode: VehicleMode },
+    ModeUnsupported { mode: VehicleMode, vehicle_type: VehicleType },
+    CommandUnsupported { vehicle_type: VehicleType },
+    FeatureMissing { feature: VehicleFeatures },
+    ConfirmationRequired {},
+    ConfirmationInvalid { token: ConfirmationToken },
+    PreflightFailed { report: PreflightReport }
+}
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[derive(Clone)]
@@ -73,6 +128,15 @@ pub struct ExecuteCommandRequest {
     pub executor: CommandExecutor,
 }
 
+// Two-step confirmation for the commands which can't be undone
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone)]
+pub struct CommandConfirmation {
+    pub id: ConfirmationToken,
+    pub request: ExecuteCommandRequest,
+    pub expires: i64
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[derive(Clone)]
 pub struct CommandExecution {
@@ -81,3 +145,9 @@ pub struct CommandExecution {
     pub executor: CommandExecutor,
     pub state: CommandState
 }
+
+impl Command {
+    pub fn requires_confirmation(&self) -> bool {
+        matches!(self, Command::Parachute { .. } | Command::FlightTermination {})
+    }
+}
@@ -1,10 +1,22 @@
 use serde::{Deserialize, Serialize};
 
 use super::communication::{LinkDescription, LinkId, LinkStatus};
-use super::vehicles::{VehicleDescription, VehicleId, VehicleStatus};
-use super::telemetry::{Flight, Navigation, RawSns, System};
+use super::vehicles::{PayloadId, VehicleDescription, VehicleId, VehicleStatus};
+use super::payloads::Payload;
+use super::telemetry::{Battery, Camera, Engine, Flight, Gimbal, Navigation, RawSns, System};
 use super::commands::{CommandId, CommandExecution, ExecuteCommandRequest};
-use super::missions::{Mission, MissionId, MissionRoute, MissionRouteItem, MissionStatus};
+use super::missions::{Mission, MissionId, MissionPlan, MissionPlanId, MissionRevision, MissionRevisionId, MissionRoute, MissionRouteItem, MissionStatus};
+use super::captures::{CaptureId, CapturedImage};
+use super::messages::VehicleMessage;
+use super::preflight::{PreflightReport, PreflightSettings};
+use super::mission_validation::{MissionValidationReport, MissionValidationSettings};
+use super::mission_patterns::{MissionPattern, MissionPatternId};
+use super::alerts::{Alert, AlertRule, AlertRuleId};
+use super::failsafe::{FailsafeEvent, FailsafeStatus};
+use super::flights::{FlightSession, FlightSessionId};
+use super::spatial::Geodetic;
+use super::terrain::TerrainReport;
+use super::maintenance::{MaintenanceEntry, MaintenanceEntryId, MaintenanceInterval, MaintenanceIntervalId, VehicleCounters};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[derive(Clone)]
@@ -21,6 +33,9 @@ pub enum ClientEvent {
     DownloadMission { mission_id: MissionId },
     ClearMission { mission_id: MissionId },
     CancelMissionState { mission_id: MissionId },
+
+    // Terrain
+    CheckTerrain { vehicle_id: VehicleId, position: Geodetic },
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -35,12 +50,51 @@ pub enum ServerEvent {
     VehicleUpserted { vehicle: VehicleDescription },
     VehicleRemoved { vehicle_id: VehicleId },
     VehicleStatusUpdated { status: VehicleStatus },
+    VehicleMessageAdded { message: VehicleMessage },
+    VehicleMessagesCleared { vehicle_id: VehicleId },
+
+    // Preflight
+    PreflightSettingsUpdated { settings: PreflightSettings },
+    PreflightReportUpdated { report: PreflightReport },
+
+    // Alerts
+    AlertRuleUpserted { rule: AlertRule },
+    AlertRuleRemoved { rule_id: AlertRuleId },
+    AlertRaised { alert: Alert },
+    AlertAcknowledged { alert: Alert },
+    AlertCleared { alert: Alert },
+
+    // Failsafe
+    FailsafeStatusUpdated { status: FailsafeStatus },
+    FailsafeTriggered { event: FailsafeEvent },
+
+    // Flights
+    FlightSessionUpdated { session: FlightSession },
+    FlightSessionRemoved { session_id: FlightSessionId },
+
+    // Maintenance
+    VehicleCountersUpdated { counters: VehicleCounters },
+    MaintenanceIntervalUpserted { interval: MaintenanceInterval },
+    MaintenanceIntervalRemoved { interval_id: MaintenanceIntervalId },
+    MaintenanceEntryAdded { entry: MaintenanceEntry },
+    MaintenanceEntryRemoved { entry_id: MaintenanceEntryId },
+
+    // Payloads
+    PayloadUpserted { payload: Payload },
+    PayloadRemoved { payload_id: PayloadId },
 
     // Telemetry
     FlightUpdated { vehicle_id: VehicleId, flight: Flight },
     NavigationUpdated { vehicle_id: VehicleId, navigation: Navigation },
     RawSnsUpdated { vehicle_id: VehicleId, raw_sns: RawSns },
     SystemUpdated { vehicle_id: VehicleId, system: System },
+    BatteryUpdated { vehicle_id: VehicleId, battery: Battery },
+    EngineUpdated { vehicle_id: VehicleId, engine: Engine },
+    GimbalUpdated { vehicle_id: VehicleId, gimbal: Gimbal },
+    CameraUpdated { vehicle_id: VehicleId, camera: Camera },
+
+    // Terrain
+    TerrainReportUpdated { report: TerrainReport },
 
     // Commands
     CommandExecutionUpserted { execution: CommandExecution },
@@ -53,4 +107,16 @@ pub enum ServerEvent {
     MissionRouteUpdated { route: MissionRoute },
     MissionRouteItemUpserted { mission_id: MissionId, index: u16, item: MissionRouteItem },
     MissionRouteItemRemoved { mission_id: MissionId, index: u16 },
+    MissionRevisionUpserted { revision: MissionRevision },
+    MissionRevisionRemoved { mission_id: MissionId, revision_id: MissionRevisionId },
+    MissionPlanUpserted { plan: MissionPlan },
+    MissionPlanRemoved { plan_id: MissionPlanId },
+    MissionPatternUpserted { pattern: MissionPattern },
+    MissionPatternRemoved { pattern_id: MissionPatternId },
+    MissionValidationSettingsUpdated { settings: MissionValidationSettings },
+    MissionValidationReportUpdated { report: MissionValidationReport },
+
+    // Captures
+    ImageCaptured { image: CapturedImage },
+    CapturedImageRemoved { capture_id: CaptureId },
 }
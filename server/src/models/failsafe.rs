@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use super::commands::{Command, CommandId, CommandRejection};
+use super::vehicles::VehicleId;
+
+pub type FailsafeEventId = String;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub enum FailsafeTrigger {
+    LinkLoss,
+    LowBattery
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FailsafePolicy {
+    pub enabled: bool,
+    pub delay: f32, // seconds a trigger condition must hold before acting
+    pub link_loss_timeout: f32, // seconds without heartbeat
+    pub link_loss_action: Option<Command>,
+    pub battery_threshold: i8, // percent
+    pub battery_hysteresis: i8, // percent above threshold to recover
+    pub battery_action: Option<Command>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FailsafeStatus {
+    pub id: VehicleId,
+    pub overridden: bool,
+    pub triggered: Vec<FailsafeTrigger>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FailsafeEvent {
+    pub id: FailsafeEventId,
+    pub vehicle_id: VehicleId,
+    pub timestamp: i64,
+    pub trigger: FailsafeTrigger,
+    pub command: Option<Command>,
+    pub command_id: Option<CommandId>,
+    pub overridden: bool,
+    #[serde(default)]
+    pub rejection: Option<CommandRejection> // action the vehicle is not capable of is not executed
+}
+
+impl Default for FailsafePolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            delay: 2.0,
+            link_loss_timeout: 5.0,
+            link_loss_action: Some(Command::ReturnToLaunch {}),
+            battery_threshold: 20,
+            battery_hysteresis: 5,
+            battery_action: Some(Command::ReturnToLaunch {})
+        }
+    }
+}
+
+impl FailsafePolicy {
+    pub fn action(&self, trigger: &FailsafeTrigger) -> Option<Command> {
+        match trigger {
+            FailsafeTrigger::LinkLoss => self.link_loss_action.clone(),
+            FailsafeTrigger::LowBattery => self.battery_action.clone()
+        }
+    }
+}
+
+impl FailsafeStatus {
+    pub fn default_for_id(vehicle_id: &VehicleId) -> Self {
+        Self {
+            id: vehicle_id.clone(),
+            overridden: false,
+            triggered: Vec::new()
+        }
+    }
+}
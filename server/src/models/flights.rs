@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use super::missions::MissionId;
+use super::spatial::Geodetic;
+use super::vehicles::VehicleId;
+
+pub type FlightSessionId = String;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FlightSession {
+    pub id: FlightSessionId,
+    pub vehicle_id: VehicleId,
+    pub mission_id: Option<MissionId>,
+
+    pub takeoff_time: i64,
+    pub landing_time: Option<i64>,
+    pub duration: f32, // seconds
+    pub distance: f32, // meters
+    pub max_altitude: f32, // meters above sea level
+
+    pub takeoff_position: Geodetic,
+    pub landing_position: Option<Geodetic>
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use super::vehicles::VehicleId;
+
+pub type MaintenanceIntervalId = String;
+pub type MaintenanceEntryId = String;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct VehicleCounters {
+    pub id: VehicleId,
+    pub flight_time: f64, // seconds
+    pub cycles: u32,
+    pub arm_time: f64 // seconds
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MaintenanceInterval {
+    pub id: MaintenanceIntervalId,
+    pub vehicle_id: VehicleId,
+    pub name: String,
+    pub flight_hours: Option<f32>,
+    pub cycles: Option<u32>,
+    pub days: Option<u32>,
+    pub created: i64
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MaintenanceEntry {
+    pub id: MaintenanceEntryId,
+    pub vehicle_id: VehicleId,
+    pub interval_id: Option<MaintenanceIntervalId>,
+    pub timestamp: i64,
+    pub notes: String,
+    // Counters at the moment maintenance was performed
+    pub flight_time: f64,
+    pub cycles: u32
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum MaintenanceState {
+    Due,
+    Overdue
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MaintenanceNotice {
+    pub interval_id: MaintenanceIntervalId,
+    pub vehicle_id: VehicleId,
+    pub name: String,
+    pub state: MaintenanceState,
+    pub remaining_hours: Option<f32>,
+    pub remaining_cycles: Option<i64>,
+    pub remaining_days: Option<f32>
+}
+
+impl VehicleCounters {
+    pub fn default_for_id(vehicle_id: &VehicleId) -> Self {
+        Self {
+            id: vehicle_id.clone(),
+            flight_time: 0.0,
+            cycles: 0,
+            arm_time: 0.0
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use super::vehicles::VehicleId;
+
+pub type VehicleMessageId = String;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum MessageSeverity {
+    Emergency,
+    Alert,
+    Critical,
+    Error,
+    Warning,
+    Notice,
+    Info,
+    Debug
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct VehicleMessage {
+    pub id: VehicleMessageId,
+    pub vehicle_id: VehicleId,
+    pub timestamp: i64,
+    pub severity: MessageSeverity,
+    pub text: String
+}
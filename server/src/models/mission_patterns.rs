@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+use super::missions::{MissionId, MissionRouteItem};
+use super::spatial::{Geodetic, GeodeticFrame};
+
+pub type MissionPatternId = String;
+
+// Sensor sizes and focal length in millimeters, image width is laid across the track
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct SurveyCamera {
+    pub sensor_width: f32,
+    pub sensor_height: f32,
+    pub focal_length: f32,
+    pub image_width: u32,
+    pub image_height: u32
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum SurveyResolution {
+    Gsd { gsd: f32 },           // Centimeters per pixel
+    Altitude { altitude: f32 }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct SurveyRequest {
+    pub polygon: Vec<Geodetic>,
+    pub camera: SurveyCamera,
+    pub resolution: SurveyResolution,
+    pub frame: GeodeticFrame,       // Altitude frame of generated waypoints
+    pub front_overlap: f32,         // Percents
+    pub side_overlap: f32,
+    pub heading: f32,               // Direction of the passes, degrees
+    pub turnaround: f32,            // Run-in and run-out past the area, meters
+    pub entry: Option<Geodetic>,    // Passes are ordered to start close to entry and end close to exit
+    pub exit: Option<Geodetic>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct CorridorRequest {
+    pub path: Vec<Geodetic>,        // Centerline of the corridor
+    pub width: f32,
+    pub camera: SurveyCamera,
+    pub resolution: SurveyResolution,
+    pub frame: GeodeticFrame,
+    pub front_overlap: f32,
+    pub side_overlap: f32,
+    pub turnaround: f32,
+    pub entry: Option<Geodetic>,
+    pub exit: Option<Geodetic>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct StructureRequest {
+    pub center: Geodetic,           // Region of interest, layer altitudes are in its frame
+    pub radius: f32,
+    pub bottom: f32,
+    pub top: f32,
+    pub camera: SurveyCamera,
+    pub front_overlap: f32,         // Along the orbit
+    pub side_overlap: f32,          // Between layers
+    pub clockwise: bool,
+    pub entry: Option<Geodetic>     // Orbits start at the bearing to entry, north otherwise
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum PatternParameters {
+    Survey { request: SurveyRequest },
+    Corridor { request: CorridorRequest },
+    Structure { request: StructureRequest }
+}
+
+// Lines are passes of surveys and corridors or orbit layers of structure scans
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct GeneratedPattern {
+    pub items: Vec<MissionRouteItem>,
+    pub altitude: f32,              // Lowest layer for structure scans
+    pub gsd: f32,
+    pub line_spacing: f32,
+    pub trigger_distance: f32,
+    pub lines: u16,
+    pub length: f64,                // Flight path length including turnarounds, meters
+    pub images: u32
+}
+
+// Items are kept as they were placed into the route, to be found there again on regeneration
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MissionPattern {
+    pub id: MissionPatternId,
+    pub mission_id: MissionId,
+    pub parameters: PatternParameters,
+    pub items: Vec<MissionRouteItem>
+}
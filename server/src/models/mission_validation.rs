@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use super::missions::MissionId;
+use super::vehicles::VehicleType;
+
+pub type MissionValidationSettingsId = String;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MissionValidationSettings {
+    pub id: MissionValidationSettingsId,
+    pub vehicle_type: VehicleType,
+    pub blocking: bool,
+
+    pub min_altitude: f32,      // Above ground, meters
+    pub max_leg_length: f32,    // Meters
+    pub cruise_speed: f32,      // m/s
+    pub cruise_power: f32,      // Watts, zero to skip energy estimation
+    pub battery_energy: f32,    // Watt-hours of the full battery
+    pub battery_reserve: i8,    // Percent to keep after landing
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum MissionIssueKind {
+    EmptyRoute,
+    NoTakeoff,
+    NoLanding,
+    Gap,
+    MixedFrames,
+    LowAltitude,
+    LongLeg,
+    InsufficientEnergy
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum MissionIssueSeverity {
+    Warning,
+    Error
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MissionIssue {
+    pub kind: MissionIssueKind,
+    pub severity: MissionIssueSeverity,
+    pub index: Option<u16>,
+    pub description: String
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MissionEstimate {
+    pub distance: f64,  // Meters
+    pub duration: f32,  // Seconds
+    pub energy: f32     // Watt-hours
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MissionValidationReport {
+    pub id: MissionId,
+    pub timestamp: i64,
+    pub issues: Vec<MissionIssue>,
+    pub estimate: MissionEstimate,
+    pub passed: bool // No errors, warnings are allowed
+}
+
+impl MissionValidationSettings {
+    pub fn default_for_type(vehicle_type: &VehicleType) -> Self {
+        let (min_altitude, cruise_speed) = match vehicle_type {
+            VehicleType::FixedWing | VehicleType::Vtol => (50.0, 20.0),
+            _ => (10.0, 10.0)
+        };
+
+        Self {
+            id: String::new(),
+            vehicle_type: vehicle_type.clone(),
+            blocking: false,
+            min_altitude,
+            max_leg_length: 10000.0,
+            cruise_speed,
+            cruise_power: 0.0,
+            battery_energy: 0.0,
+            battery_reserve: 20
+        }
+    }
+}
@@ -4,6 +4,9 @@ use serde::{Deserialize, Serialize};
 use super::{spatial::Geodetic, vehicles::VehicleId};
 
 pub type MissionId = String;
+pub type MissionRevisionId = String;
+pub type MissionPlanId = String;
+pub type MissionAssignmentId = String;
 
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 #[serde(tag = "type")]
@@ -14,11 +17,73 @@ pub enum MissionRouteItem {
     Takeoff { position: Geodetic, pitch: f32, yaw: Option<u16> },
     LandStart {},
     Landing { position: Geodetic, abort_altitude: Option<f32>, yaw: Option<u16> },
+    VtolTakeoff { position: Geodetic, yaw: Option<u16> },
+    VtolLand { position: Geodetic, approach_altitude: Option<f32>, yaw: Option<u16> },
+    ReturnToLaunch {},
 
     LoiterTrn { position: Geodetic, heading_required: bool, radius: f32, turns: u16, clockwise: bool },
     LoiterAlt { position: Geodetic, heading_required: bool, radius: f32, clockwise: bool },
+    Delay { seconds: f32 },
 
-    TriggerCam { distance: f32, shutter: i16, trigger: bool }
+    TriggerCam { distance: f32, shutter: i16, trigger: bool },
+
+    DoSetRoi { position: Geodetic },
+    RoiNone {},
+    DoGimbalControl { pitch: f32, roll: f32, yaw: f32 },
+
+    DoChangeSpeed { speed_type: SpeedType, speed: f32, throttle: Option<f32> },
+    DoJump { target: u16, repeat: i16 },    // Target is the route item index, -1 repeats forever
+    ConditionYaw { angle: f32, speed: f32, clockwise: bool, relative: bool },
+    DoSetServo { servo: u16, pwm: u16 }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum SpeedType {
+    Airspeed,
+    Groundspeed,
+    ClimbSpeed,
+    DescentSpeed
+}
+
+// Batch route operations, each one is applied atomically
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum RouteEdit {
+    Replace { items: Vec<MissionRouteItem> },
+    Insert { index: u16, items: Vec<MissionRouteItem> },
+    Move { from: u16, count: u16, to: u16 },
+    Reorder { order: Vec<u16> },
+    Duplicate { from: u16, count: u16, to: u16 },
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum RouteItemChange {
+    Added { index: u16, item: MissionRouteItem },
+    Removed { index: u16, item: MissionRouteItem },
+    Changed { index: u16, before: MissionRouteItem, after: MissionRouteItem },
+}
+
+// Indices of Removed and Changed refer to the old route, indices of Added to the new one
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct RouteDiff {
+    pub changes: Vec<RouteItemChange>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum RevisionAuthor {
+    Operator,
+    Vehicle
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MissionRevision {
+    pub id: MissionRevisionId,
+    pub mission_id: MissionId,
+    pub number: u32,
+    pub timestamp: i64,
+    pub author: RevisionAuthor,
+    pub items: Vec<MissionRouteItem>,
+    pub diff: RouteDiff,
+    pub undone: bool
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -44,10 +109,18 @@ pub struct MissionRoute {
     pub items: Vec<MissionRouteItem>,
 }
 
+// Binds a mission to its vehicle, only the active one is synced with the vehicle.
+// Assignments stored before missions got their own ids have no mission_id.
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct MissionAssignment {
-    pub id: MissionId,
+    pub id: MissionAssignmentId,
+    #[serde(default)]
+    pub mission_id: MissionId,
     pub vehicle_id: VehicleId,
+    #[serde(default)]
+    pub plan_id: Option<MissionPlanId>,
+    #[serde(default)]
+    pub active: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -61,6 +134,17 @@ pub struct MissionStatus {
 pub struct Mission {
     pub id: MissionId,
     pub vehicle_id: VehicleId,
+    pub plan_id: Option<MissionPlanId>,
+    pub active: bool,
     pub route: MissionRoute,
     pub status: MissionStatus
 }
+
+// Named reusable route from the mission library, not bound to any vehicle
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MissionPlan {
+    pub id: MissionPlanId,
+    pub name: String,
+    pub items: Vec<MissionRouteItem>,
+    pub updated: i64
+}
@@ -1,8 +1,19 @@
 pub mod spatial;
+pub mod terrain;
 pub mod colors;
 pub mod communication;
 pub mod vehicles;
+pub mod payloads;
 pub mod telemetry;
 pub mod commands;
 pub mod missions;
+pub mod mission_validation;
+pub mod mission_patterns;
+pub mod captures;
+pub mod messages;
+pub mod preflight;
+pub mod alerts;
+pub mod failsafe;
+pub mod flights;
+pub mod maintenance;
 pub mod events;
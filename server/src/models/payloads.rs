@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use super::vehicles::{PayloadId, VehicleId};
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub enum PayloadType {
+    Unknown,
+    Camera,
+    Gimbal
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum PayloadProtocolId {
+    MavlinkComponent { comp_id: u8 },
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Payload {
+    pub id: PayloadId,
+    pub vehicle_id: VehicleId,
+    pub name: String,
+    pub payload_type: PayloadType,
+    pub protocol_id: PayloadProtocolId
+}
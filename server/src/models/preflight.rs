@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use super::vehicles::{VehicleId, VehicleType};
+
+pub type PreflightSettingsId = String;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum PreflightCheck {
+    Sensors,
+    ArmReady,
+    Battery,
+    SnsFix,
+    HomePosition,
+    MissionActual,
+    LinkOnline
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct PreflightSettings {
+    pub id: PreflightSettingsId,
+    pub vehicle_type: VehicleType,
+    pub checks: Vec<PreflightCheck>,
+    pub blocking: bool,
+
+    pub min_battery_voltage: f32,
+    pub min_battery_remaining: i8,
+    pub min_sns_fix: u8,
+    pub min_satellites: u8,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct PreflightCheckResult {
+    pub check: PreflightCheck,
+    pub passed: bool,
+    pub reason: Option<String>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct PreflightReport {
+    pub id: VehicleId,
+    pub timestamp: i64,
+    pub results: Vec<PreflightCheckResult>,
+    pub passed: bool
+}
+
+impl PreflightSettings {
+    pub fn default_for_type(vehicle_type: &VehicleType) -> Self {
+        let mut checks = vec![
+            PreflightCheck::LinkOnline,
+            PreflightCheck::Sensors,
+            PreflightCheck::ArmReady,
+            PreflightCheck::Battery,
+            PreflightCheck::SnsFix,
+            PreflightCheck::HomePosition
+        ];
+        // Planes can't hold a position, so they shouldn't take off without a mission
+        if matches!(vehicle_type, VehicleType::FixedWing | VehicleType::Vtol) {
+            checks.push(PreflightCheck::MissionActual);
+        }
+
+        Self {
+            id: String::new(),
+            vehicle_type: vehicle_type.clone(),
+            checks,
+            blocking: false,
+            min_battery_voltage: 0.0,
+            min_battery_remaining: 20,
+            min_sns_fix: 3, // 3D fix
+            min_satellites: 6
+        }
+    }
+}
@@ -27,3 +27,75 @@ impl Default for Geodetic {
         }
     }
 }
+
+// Local tangent plane offsets, meters
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Ned {
+    pub north: f64,
+    pub east: f64,
+    pub down: f64
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Enu {
+    pub east: f64,
+    pub north: f64,
+    pub up: f64
+}
+
+impl From<Enu> for Ned {
+    fn from(enu: Enu) -> Ned {
+        Ned { north: enu.north, east: enu.east, down: -enu.up }
+    }
+}
+
+impl From<Ned> for Enu {
+    fn from(ned: Ned) -> Enu {
+        Enu { east: ned.east, north: ned.north, up: -ned.down }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct MeasureRequest {
+    pub from: Geodetic,
+    pub to: Geodetic
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Measure {
+    pub distance: f64,  // Great-circle, meters
+    pub bearing: f64,   // Initial, degrees
+    pub offset: Ned
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TrackRequest {
+    pub position: Geodetic,
+    pub start: Geodetic,
+    pub end: Geodetic
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TrackOffset {
+    pub cross_track: f64,   // Positive to the right of the path, meters
+    pub along_track: f64    // From the path start, meters
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct DestinationRequest {
+    pub from: Geodetic,
+    pub bearing: f64,
+    pub distance: f64
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct OffsetRequest {
+    pub from: Geodetic,
+    pub offset: Ned
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FrameConversionRequest {
+    pub position: Geodetic,
+    pub frame: GeodeticFrame
+}
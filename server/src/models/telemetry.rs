@@ -1,7 +1,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::spatial::Geodetic;
+use super::{spatial::Geodetic, vehicles::VehicleId};
 
 pub type TelemetryId = String;
 
@@ -23,6 +23,37 @@ pub enum SensorType {
     Avoidance
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum GimbalFailure {
+    AtRollLimit,
+    AtPitchLimit,
+    AtYawLimit,
+    Encoder,
+    Power,
+    Motor,
+    Software,
+    Comms,
+    Calibrating,
+    NoManager
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum CameraMode {
+    Unknown,
+    Image,
+    Video,
+    Survey
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum BatteryFunction {
+    Unknown,
+    All,
+    Propulsion,
+    Avionics,
+    Payload
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Flight {
     pub id: TelemetryId,
@@ -51,6 +82,8 @@ pub struct Navigation {
     pub position: Geodetic,
     pub target_position: Geodetic,
     pub home_position: Geodetic,
+    pub relative_altitude: f32,         // Above home as reported by the vehicle
+    pub terrain_altitude: Option<f32>,  // Above local terrain, none without terrain data
 
     pub desired_pitch: f32,
     pub desired_roll: f32,
@@ -102,6 +135,92 @@ pub struct System {
     pub radio_remote_rssi: u8,
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Gimbal {
+    pub id: TelemetryId,
+    pub timestamp: i64,
+
+    pub pitch: f32,
+    pub roll: f32,
+    pub yaw: f32,
+
+    pub pitch_rate: f32,
+    pub roll_rate: f32,
+    pub yaw_rate: f32,
+
+    pub yaw_in_earth_frame: bool,
+    pub retracted: bool,
+    pub neutral: bool,
+    pub failures: Vec<GimbalFailure>,
+
+    pub primary_control_sysid: u8,
+    pub primary_control_compid: u8,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Camera {
+    pub id: TelemetryId,
+    pub timestamp: i64,
+
+    pub vendor_name: String,
+    pub model_name: String,
+    pub firmware_version: u32,
+    pub focal_length: f32,
+    pub sensor_size_h: f32,
+    pub sensor_size_v: f32,
+    pub resolution_h: u16,
+    pub resolution_v: u16,
+    pub can_capture_image: bool,
+    pub can_capture_video: bool,
+
+    pub mode: CameraMode,
+    pub image_capturing: bool,
+    pub image_interval: f32,
+    pub image_count: i32,
+    pub video_recording: bool,
+    pub recording_time_ms: u32,
+    pub available_capacity: f32,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Battery {
+    pub id: TelemetryId,
+    pub timestamp: i64,
+    pub vehicle_id: VehicleId,
+    pub battery_id: u8,
+    pub function: BatteryFunction,
+
+    pub voltage: f32,
+    pub cell_voltages: Vec<f32>,
+    pub current: Option<f32>,
+    pub consumed_mah: Option<i32>,
+    pub consumed_energy: Option<f32>,
+    pub temperature: Option<f32>,
+    pub remaining: Option<i8>,
+    pub time_remaining: Option<i32>,
+
+    pub consumption_rate: Option<f32>,
+    pub estimated_endurance: Option<f32>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Engine {
+    pub id: TelemetryId,
+    pub timestamp: i64,
+
+    pub healthy: bool,
+    pub ignition: bool,
+    pub running: bool,
+    pub rpm: f32,
+    pub engine_load: f32,
+    pub throttle_position: f32,
+    pub cylinder_head_temperature: f32,
+    pub exhaust_gas_temperature: f32,
+    pub fuel_consumed: f32,
+    pub fuel_flow: f32,
+    pub fuel_level: Option<f32>, // percent, unknown without the tank capacity
+}
+
 impl Flight {
     pub fn default_for_id(id: &TelemetryId) -> Self {
         Self {
@@ -129,6 +248,8 @@ impl Navigation {
             position: Geodetic::default(),
             target_position: Geodetic::default(),
             home_position: Geodetic::default(),
+            relative_altitude: 0.0,
+            terrain_altitude: None,
             desired_pitch: 0.0,
             desired_roll: 0.0,
             desired_bearing: 0.0,
@@ -171,4 +292,93 @@ impl System {
             radio_remote_rssi: 0
         }
     }
-}
\ No newline at end of file
+}
+
+impl Gimbal {
+    pub fn default_for_id(id: &TelemetryId) -> Self {
+        Self {
+            id: id.clone(),
+            timestamp: 0,
+            pitch: 0.0,
+            roll: 0.0,
+            yaw: 0.0,
+            pitch_rate: 0.0,
+            roll_rate: 0.0,
+            yaw_rate: 0.0,
+            yaw_in_earth_frame: false,
+            retracted: false,
+            neutral: false,
+            failures: Vec::new(),
+            primary_control_sysid: 0,
+            primary_control_compid: 0
+        }
+    }
+}
+
+impl Camera {
+    pub fn default_for_id(id: &TelemetryId) -> Self {
+        Self {
+            id: id.clone(),
+            timestamp: 0,
+            vendor_name: String::new(),
+            model_name: String::new(),
+            firmware_version: 0,
+            focal_length: 0.0,
+            sensor_size_h: 0.0,
+            sensor_size_v: 0.0,
+            resolution_h: 0,
+            resolution_v: 0,
+            can_capture_image: false,
+            can_capture_video: false,
+            mode: CameraMode::Unknown,
+            image_capturing: false,
+            image_interval: 0.0,
+            image_count: 0,
+            video_recording: false,
+            recording_time_ms: 0,
+            available_capacity: 0.0
+        }
+    }
+}
+
+impl Engine {
+    pub fn default_for_id(id: &TelemetryId) -> Self {
+        Self {
+            id: id.clone(),
+            timestamp: 0,
+            healthy: false,
+            ignition: false,
+            running: false,
+            rpm: 0.0,
+            engine_load: 0.0,
+            throttle_position: 0.0,
+            cylinder_head_temperature: 0.0,
+            exhaust_gas_temperature: 0.0,
+            fuel_consumed: 0.0,
+            fuel_flow: 0.0,
+            fuel_level: None
+        }
+    }
+}
+
+impl Battery {
+    pub fn default_for_battery(vehicle_id: &VehicleId, battery_id: u8) -> Self {
+        Self {
+            id: format!("{}_{}", vehicle_id, battery_id),
+            timestamp: 0,
+            vehicle_id: vehicle_id.clone(),
+            battery_id,
+            function: BatteryFunction::Unknown,
+            voltage: 0.0,
+            cell_voltages: Vec::new(),
+            current: None,
+            consumed_mah: None,
+            consumed_energy: None,
+            temperature: None,
+            remaining: None,
+            time_remaining: None,
+            consumption_rate: None,
+            estimated_endurance: None
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::spatial::{Geodetic, GeodeticFrame};
+use super::vehicles::VehicleId;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TerrainElevation {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub elevation: Option<f32>  // Above sea level, meters, none without terrain data
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TerrainProfilePoint {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub distance: f64,          // Along the path from its start, meters
+    pub elevation: Option<f32>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TerrainProfile {
+    pub points: Vec<TerrainProfilePoint>,
+    pub min_elevation: Option<f32>,
+    pub max_elevation: Option<f32>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TerrainProfileRequest {
+    pub path: Vec<Geodetic>,
+    pub step: f32               // Meters between samples, path vertices are always sampled
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct AltitudeConversionRequest {
+    pub position: Geodetic,
+    pub frame: GeodeticFrame,
+    pub home: Option<Geodetic>
+}
+
+// Terrain database state reported by the vehicle, e.g. on TERRAIN_CHECK
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TerrainReport {
+    pub id: VehicleId,
+    pub timestamp: i64,
+
+    pub latitude: f64,
+    pub longitude: f64,
+    pub terrain_height: f32,            // Vehicle terrain above sea level at the point, meters
+    pub current_height: f32,            // Vehicle height above its terrain, meters
+    pub spacing: u16,                   // Grid spacing, zero if no terrain at the point
+    pub pending: u16,                   // Blocks the vehicle is waiting for
+    pub loaded: u16,                    // Blocks in the vehicle memory
+    pub local_elevation: Option<f32>    // Local DEM at the same point to compare with
+}
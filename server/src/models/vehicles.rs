@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::colors::EntityColor;
+use super::failsafe::FailsafePolicy;
 
 pub type VehicleId = String;
 pub type PayloadId = String;
@@ -37,6 +38,26 @@ pub enum VehicleState {
     FlightTermination
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Default)]
+pub enum VtolState {
+    #[default]
+    Undefined,
+    TransitionToFixedWing,
+    TransitionToMulticopter,
+    Multicopter,
+    FixedWing
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Default)]
+pub enum LandedState {
+    #[default]
+    Undefined,
+    OnGround,
+    InAir,
+    Takeoff,
+    Landing
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub enum VehicleMode {
     None,
@@ -97,7 +118,11 @@ pub struct VehicleDescription {
     pub vehicle_type: VehicleType,
     pub protocol_id: ProtocolId,
     pub features: Vec<VehicleFeatures>,
-    pub available_modes: Vec<VehicleMode>
+    pub available_modes: Vec<VehicleMode>,
+    #[serde(default)]
+    pub failsafe: FailsafePolicy,
+    #[serde(default)]
+    pub fuel_tank_capacity: Option<f32> // liters, to estimate fuel level of petrol engine
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
@@ -107,6 +132,10 @@ pub struct VehicleStatus {
     pub armed: bool,
     pub mode: VehicleMode,
     pub state: VehicleState,
+    #[serde(default)]
+    pub vtol_state: VtolState,
+    #[serde(default)]
+    pub landed_state: LandedState,
 }
 
 impl VehicleStatus {
@@ -116,7 +145,9 @@ impl VehicleStatus {
             last_heartbeat: 0,
             armed: false,
             state: VehicleState::Unknown,
-            mode: VehicleMode::None
+            mode: VehicleMode::None,
+            vtol_state: VtolState::default(),
+            landed_state: LandedState::default()
         }
     }
 }
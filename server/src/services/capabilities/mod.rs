@@ -0,0 +1,3 @@
+pub mod validation;
+#[cfg(test)]
+mod validation_test;
@@ -0,0 +1,82 @@
+use crate::dal::dal;
+use crate::models::commands::{Command, CommandExecutor, CommandRejection, ExecuteCommandRequest};
+use crate::models::failsafe::FailsafePolicy;
+use crate::models::vehicles::{VehicleDescription, VehicleFeatures, VehicleMode, VehicleType};
+
+fn is_vtol_mode(mode: &VehicleMode) -> bool {
+    matches!(mode,
+        VehicleMode::QStabilize |
+        VehicleMode::QHover |
+        VehicleMode::QLoiter |
+        VehicleMode::QLand |
+        VehicleMode::QRTL |
+        VehicleMode::QAutotune |
+        VehicleMode::QAcro)
+}
+
+fn required_feature(command: &Command) -> Option<VehicleFeatures> {
+    match command {
+        Command::Parachute { .. } => Some(VehicleFeatures::Parachute),
+        Command::EngineControl { .. } => Some(VehicleFeatures::PetrolEngine),
+        _ => None
+    }
+}
+
+pub fn validate(vehicle: &VehicleDescription, command: &Command) -> Result<(), CommandRejection> {
+    if let Command::SetMode { mode } = command {
+        // Empty modes means they are not reported by the vehicle yet
+        if !vehicle.available_modes.is_empty() && !vehicle.available_modes.contains(mode) {
+            return Err(CommandRejection::ModeUnavailable { mode: mode.clone() });
+        }
+        // Auto type is not detected yet, so let the vehicle decide
+        if is_vtol_mode(mode) && !matches!(vehicle.vehicle_type, VehicleType::Vtol | VehicleType::Auto) {
+            return Err(CommandRejection::ModeUnsupported {
+                mode: mode.clone(),
+                vehicle_type: vehicle.vehicle_type.clone()
+            });
+        }
+    }
+
+    if let Command::VtolTransition { .. } = command {
+        if !matches!(vehicle.vehicle_type, VehicleType::Vtol | VehicleType::Auto) {
+            return Err(CommandRejection::CommandUnsupported { vehicle_type: vehicle.vehicle_type.clone() });
+        }
+    }
+
+    if let Some(feature) = required_feature(command) {
+        if !vehicle.features.contains(&feature) {
+            return Err(CommandRejection::FeatureMissing { feature });
+        }
+    }
+    Ok(())
+}
+
+// Failsafe runs unattended, so its actions can't pass the two-step confirmation
+pub fn validate_failsafe(policy: &FailsafePolicy) -> Result<(), CommandRejection> {
+    for action in [&policy.link_loss_action, &policy.battery_action].into_iter().flatten() {
+        if action.requires_confirmation() {
+            return Err(CommandRejection::ConfirmationRequired {});
+        }
+    }
+    Ok(())
+}
+
+// Returns a rejection, if the executor is not capable of the requested command
+pub async fn check_command(dal: &dal::Dal, request: &ExecuteCommandRequest) -> anyhow::Result<Option<CommandRejection>> {
+    let vehicle_id = match &request.executor {
+        CommandExecutor::Vehicle { vehicle_id } => vehicle_id,
+        CommandExecutor::Payload { vehicle_id, payload_id } => {
+            match dal.find_payload(payload_id).await? {
+                Some(payload) if &payload.vehicle_id == vehicle_id => {},
+                _ => return Ok(Some(CommandRejection::PayloadNotFound { payload_id: payload_id.clone() }))
+            }
+            vehicle_id
+        }
+    };
+
+    let vehicle = match dal.find_vehicle(vehicle_id).await? {
+        Some(vehicle) => vehicle,
+        None => return Ok(Some(CommandRejection::VehicleNotFound { vehicle_id: vehicle_id.clone() }))
+    };
+    Ok(validate(&vehicle, &request.command).err())
+}
@@ -0,0 +1,141 @@
+use test_case::test_case;
+
+use crate::dal::test_utils::in_memory_dal;
+use crate::models::colors::EntityColor;
+use crate::models::commands::{Command, CommandExecutor, CommandRejection, ExecuteCommandRequest, ParachuteAction, VtolTarget};
+use crate::models::failsafe::FailsafePolicy;
+use crate::models::payloads::{Payload, PayloadProtocolId, PayloadType};
+use crate::models::vehicles::*;
+use super::validation::{check_command, validate, validate_failsafe};
+
+fn vehicle(vehicle_type: VehicleType, available_modes: Vec<VehicleMode>) -> VehicleDescription {
+    VehicleDescription {
+        id: "mav_1".into(),
+        name: "Test".into(),
+        color: EntityColor::Cyan,
+        vehicle_type,
+        protocol_id: ProtocolId::MavlinkId { mav_id: 1 },
+        features: Vec::new(),
+        available_modes,
+        failsafe: FailsafePolicy::default(),
+        fuel_tank_capacity: None
+    }
+}
+
+#[test_case(VehicleMode::Loiter, Ok(()); "available")]
+#[test_case(VehicleMode::Guided, Err(CommandRejection::ModeUnavailable { mode: VehicleMode::Guided }); "unavailable")]
+fn test_mode_available(mode: VehicleMode, expected: Result<(), CommandRejection>) {
+    let vehicle = vehicle(VehicleType::FixedWing, vec![VehicleMode::Loiter, VehicleMode::RTL]);
+    assert_eq!(validate(&vehicle, &Command::SetMode { mode }), expected);
+}
+
+#[test]
+fn test_unknown_modes_allowed() {
+    let vehicle = vehicle(VehicleType::Copter, Vec::new());
+    assert_eq!(validate(&vehicle, &Command::SetMode { mode: VehicleMode::Guided }), Ok(()));
+}
+
+#[test_case(VehicleType::Vtol, true; "vtol")]
+#[test_case(VehicleType::Auto, true; "not detected")]
+#[test_case(VehicleType::FixedWing, false; "fixed wing")]
+#[test_case(VehicleType::Copter, false; "copter")]
+fn test_vtol_modes(vehicle_type: VehicleType, allowed: bool) {
+    let vehicle = vehicle(vehicle_type.clone(), Vec::new());
+    let result = validate(&vehicle, &Command::SetMode { mode: VehicleMode::QHover });
+    if allowed {
+        assert_eq!(result, Ok(()));
+    } else {
+        assert_eq!(result, Err(CommandRejection::ModeUnsupported { mode: VehicleMode::QHover, vehicle_type }));
+    }
+}
+
+#[test]
+fn test_other_commands_allowed() {
+    let vehicle = vehicle(VehicleType::FixedWing, vec![VehicleMode::Loiter]);
+    assert_eq!(validate(&vehicle, &Command::ReturnToLaunch {}), Ok(()));
+    assert_eq!(validate(&vehicle, &Command::ArmDisarm { arm: true }), Ok(()));
+}
+
+#[test_case(VehicleType::Vtol, true; "vtol")]
+#[test_case(VehicleType::Auto, true; "not detected")]
+#[test_case(VehicleType::Copter, false; "copter")]
+fn test_vtol_transition(vehicle_type: VehicleType, allowed: bool) {
+    let vehicle = vehicle(vehicle_type.clone(), Vec::new());
+    let result = validate(&vehicle, &Command::VtolTransition { to: VtolTarget::FixedWing });
+    if allowed {
+        assert_eq!(result, Ok(()));
+    } else {
+        assert_eq!(result, Err(CommandRejection::CommandUnsupported { vehicle_type }));
+    }
+}
+
+#[test_case(Command::Parachute { action: ParachuteAction::Release }, VehicleFeatures::Parachute; "parachute")]
+#[test_case(Command::EngineControl { start: true }, VehicleFeatures::PetrolEngine; "engine")]
+fn test_command_requires_feature(command: Command, feature: VehicleFeatures) {
+    let mut vehicle = vehicle(VehicleType::FixedWing, Vec::new());
+    assert_eq!(validate(&vehicle, &command), Err(CommandRejection::FeatureMissing { feature: feature.clone() }));
+
+    vehicle.features.push(feature);
+    assert_eq!(validate(&vehicle, &command), Ok(()));
+}
+
+#[test_case(Command::Parachute { action: ParachuteAction::Enable }, true; "parachute")]
+#[test_case(Command::FlightTermination {}, true; "flight termination")]
+#[test_case(Command::ReturnToLaunch {}, false; "rtl")]
+fn test_requires_confirmation(command: Command, expected: bool) {
+    assert_eq!(command.requires_confirmation(), expected);
+}
+
+#[test_case(None, None, Ok(()); "no actions")]
+#[test_case(Some(Command::ReturnToLaunch {}), Some(Command::SetMode { mode: VehicleMode::Loiter }), Ok(()); "no confirmation")]
+#[test_case(Some(Command::FlightTermination {}), None, Err(CommandRejection::ConfirmationRequired {}); "link loss")]
+#[test_case(Some(Command::ReturnToLaunch {}), Some(Command::Parachute { action: ParachuteAction::Release }),
+    Err(CommandRejection::ConfirmationRequired {}); "low battery")]
+fn test_validate_failsafe(link_loss_action: Option<Command>, battery_action: Option<Command>, expected: Result<(), CommandRejection>) {
+    let policy = FailsafePolicy { link_loss_action, battery_action, ..FailsafePolicy::default() };
+    assert_eq!(validate_failsafe(&policy), expected);
+}
+
+fn request(executor: CommandExecutor) -> ExecuteCommandRequest {
+    ExecuteCommandRequest { command: Command::ReturnToLaunch {}, executor }
+}
+
+#[tokio::test]
+async fn test_check_command_executor() {
+    let (dal, _) = in_memory_dal().await;
+    let vehicle = dal.save_vehicle(VehicleDescription { id: String::new(), ..vehicle(VehicleType::FixedWing, Vec::new()) }).await
+        .expect("Error saving vehicle");
+    let payload = dal.save_payload(Payload {
+        id: String::new(),
+        vehicle_id: vehicle.id.clone(),
+        name: "Camera".into(),
+        payload_type: PayloadType::Camera,
+        protocol_id: PayloadProtocolId::MavlinkComponent { comp_id: 100 }
+    }).await.expect("Error saving payload");
+
+    let result = check_command(&dal, &request(CommandExecutor::Vehicle { vehicle_id: vehicle.id.clone() })).await
+        .expect("Error checking command");
+    assert_eq!(result, None);
+
+    let result = check_command(&dal, &request(CommandExecutor::Vehicle { vehicle_id: "missing".into() })).await
+        .expect("Error checking command");
+    assert_eq!(result, Some(CommandRejection::VehicleNotFound { vehicle_id: "missing".into() }));
+
+    let result = check_command(&dal, &request(CommandExecutor::Payload {
+        vehicle_id: vehicle.id.clone(),
+        payload_id: payload.id.clone()
+    })).await.expect("Error checking command");
+    assert_eq!(result, None);
+
+    let result = check_command(&dal, &request(CommandExecutor::Payload {
+        vehicle_id: "other".into(),
+        payload_id: payload.id.clone()
+    })).await.expect("Error checking command");
+    assert_eq!(result, Some(CommandRejection::PayloadNotFound { payload_id: payload.id.clone() }));
+
+    let result = check_command(&dal, &request(CommandExecutor::Payload {
+        vehicle_id: vehicle.id.clone(),
+        payload_id: "missing".into()
+    })).await.expect("Error checking command");
+    assert_eq!(result, Some(CommandRejection::PayloadNotFound { payload_id: "missing".into() }));
+}
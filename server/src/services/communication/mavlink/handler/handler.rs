@@ -1,14 +1,16 @@
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 use tokio::{time, sync::broadcast::Receiver};
-use mavlink::{MavHeader, common::MavMessage};
+use mavlink::{MavHeader, common::{MavAutopilot, MavMessage}};
 
 use crate::models::events::{ClientEvent, ServerEvent};
 use crate::models::commands::CommandId;
-use crate::models::vehicles::{VehicleId, VehicleMode};
+use crate::models::payloads::PayloadType;
+use crate::models::vehicles::{PayloadId, VehicleId, VehicleMode};
 use crate::models::missions::{MissionId, MissionStatus};
 use crate::{bus::bus, dal::dal};
+use super::handler_navigation::GoToTarget;
 
 pub struct Handler {
     pub dal: dal::Dal,
@@ -17,8 +19,13 @@ pub struct Handler {
 
     pub mav_vehicles: HashMap<u8, VehicleId>,
     pub mav_modes: HashMap<u8, HashMap<u32, VehicleMode>>,
+    pub mav_autopilots: HashMap<u8, MavAutopilot>,
+    // Camera and gimbal may be reported by the same component, so the type is a part of the key
+    pub mav_payloads: HashMap<(u8, u8, PayloadType), PayloadId>,
     pub mav_mission_operation_statuses: HashMap<u8, MissionStatus>,
     pub waiting_ack_command_executions: HashMap<(u16, u8), CommandId>,
+    pub go_to_targets: HashMap<u8, GoToTarget>,
+    pub terrain_messages: VecDeque<MavMessage>,
 
     pub command_executions_last_sent: HashMap<CommandId, time::Instant>,
     pub mission_statuses_last_sent: HashMap<MissionId, time::Instant>,
@@ -32,8 +39,12 @@ impl Handler {
             client_events_rx,
             mav_vehicles: HashMap::new(),
             mav_modes: HashMap::new(),
+            mav_autopilots: HashMap::new(),
+            mav_payloads: HashMap::new(),
             mav_mission_operation_statuses: HashMap::new(),
             waiting_ack_command_executions: HashMap::new(),
+            go_to_targets: HashMap::new(),
+            terrain_messages: VecDeque::new(),
             command_executions_last_sent: HashMap::new(),
             mission_statuses_last_sent: HashMap::new()
         }
@@ -55,14 +66,9 @@ impl Handler {
             Some(vehicle_id) => vehicle_id,
             None => return None
         };
-        let assignment = match self.dal.mission_assignment_by_vehicle_id(&vehicle_id).await {
-            Ok(assignment) => assignment,
+        match self.dal.active_mission_assignment(&vehicle_id).await {
+            Ok(assignment) => assignment.map(|assignment| assignment.mission_id),
             Err(_) => None,
-        };
-        if let Some(assignment) = assignment {
-            Some(assignment.id)
-        } else {
-            None
         }
     }
 
@@ -73,7 +79,13 @@ impl Handler {
             return None;
         }
 
-        match self.mav_id_from_vehicle_id(&assignment.unwrap().vehicle_id) {
+        let assignment = assignment.unwrap();
+        if !assignment.active {
+            log::error!("Mission {:?} is not active for vehicle {:?}", &mission_id, &assignment.vehicle_id);
+            return None;
+        }
+
+        match self.mav_id_from_vehicle_id(&assignment.vehicle_id) {
             Some(mav_id) => Some(mav_id),
             None => {
                 log::error!("No MAVLink vehicle for mission: {:?}", &mission_id);
@@ -85,7 +97,7 @@ impl Handler {
     pub async fn handle_message(&mut self, header: &MavHeader, msg: &MavMessage) {
         match msg {
             MavMessage::HEARTBEAT(heartbeat_data) =>
-                self.handle_heartbeat( header.system_id, heartbeat_data).await,
+                self.handle_heartbeat(header.system_id, header.component_id, heartbeat_data).await,
             MavMessage::ATTITUDE(attitude) =>
                 self.handle_attitude(header.system_id, attitude).await,
             MavMessage::VFR_HUD(vfr_hud) =>
@@ -98,12 +110,34 @@ impl Handler {
                 self.handle_gps_raw(header.system_id, gps_raw).await,
             MavMessage::SYS_STATUS(sys_data) =>
                 self.handle_sys_data(header.system_id, sys_data).await,
+            MavMessage::BATTERY_STATUS(battery_status) =>
+                self.handle_battery_status(header.system_id, battery_status).await,
+            MavMessage::EXTENDED_SYS_STATE(data) =>
+                self.handle_extended_sys_state(header.system_id, data).await,
+            MavMessage::EFI_STATUS(efi_status) =>
+                self.handle_efi_status(header.system_id, efi_status).await,
+            MavMessage::RAW_RPM(raw_rpm) =>
+                self.handle_raw_rpm(header.system_id, raw_rpm).await,
             MavMessage::NAV_CONTROLLER_OUTPUT(nav_data) =>
                 self.handle_nav_data(header.system_id, nav_data).await,
             MavMessage::POSITION_TARGET_GLOBAL_INT(target) =>
                 self.handle_target_position(header.system_id, target).await,
             MavMessage::RADIO_STATUS(radio_status) =>
                 self.handle_radio_status(header.system_id, radio_status).await,
+            MavMessage::GIMBAL_DEVICE_ATTITUDE_STATUS(data) =>
+                self.handle_gimbal_device_attitude(header.system_id, header.component_id, data).await,
+            MavMessage::GIMBAL_MANAGER_STATUS(data) =>
+                self.handle_gimbal_manager_status(header.system_id, header.component_id, data).await,
+            MavMessage::STATUSTEXT(data) =>
+                self.handle_status_text(header.system_id, data).await,
+            MavMessage::CAMERA_INFORMATION(data) =>
+                self.handle_camera_information(header.system_id, header.component_id, data).await,
+            MavMessage::CAMERA_SETTINGS(data) =>
+                self.handle_camera_settings(header.system_id, header.component_id, data).await,
+            MavMessage::CAMERA_CAPTURE_STATUS(data) =>
+                self.handle_camera_capture_status(header.system_id, header.component_id, data).await,
+            MavMessage::CAMERA_IMAGE_CAPTURED(data) =>
+                self.handle_camera_image_captured(header.system_id, header.component_id, data).await,
             MavMessage::COMMAND_ACK(ack) =>
                 self.handle_command_ack(header.system_id, ack).await,
             MavMessage::MISSION_COUNT(data) =>
@@ -118,6 +152,10 @@ impl Handler {
                 self.handle_mission_item_current(header.system_id, data).await,
             MavMessage::MISSION_ITEM_REACHED(data) =>
                 self.handle_mission_item_reached(header.system_id, data).await,
+            MavMessage::TERRAIN_REQUEST(data) =>
+                self.handle_terrain_request(header.system_id, data).await,
+            MavMessage::TERRAIN_REPORT(data) =>
+                self.handle_terrain_report(header.system_id, data).await,
             _ => {}
         }
     }
@@ -142,6 +180,9 @@ impl Handler {
             ClientEvent::CancelMissionState { mission_id } => {
                 self.cancel_mission_state(mission_id).await;
             }
+            ClientEvent::CheckTerrain { vehicle_id, position } => {
+                self.check_terrain(vehicle_id, position).await;
+            }
             _ => {}
         }
     }
@@ -155,6 +196,10 @@ impl Handler {
                 }
             }
         }
-        [self.collect_command_messages().await, self.collect_mission_messages().await].concat()
+        [
+            self.collect_command_messages().await,
+            self.collect_mission_messages().await,
+            self.collect_terrain_messages()
+        ].concat()
     }
 }
@@ -0,0 +1,54 @@
+use mavlink::common::*;
+
+use crate::models::telemetry::*;
+use super::{handler, super::protocol::telemetry as protocol};
+
+impl BatteryFunction {
+    pub fn from_mavlink(function: MavBatteryFunction) -> BatteryFunction {
+        match function {
+            MavBatteryFunction::MAV_BATTERY_FUNCTION_UNKNOWN => BatteryFunction::Unknown,
+            MavBatteryFunction::MAV_BATTERY_FUNCTION_ALL => BatteryFunction::All,
+            MavBatteryFunction::MAV_BATTERY_FUNCTION_PROPULSION => BatteryFunction::Propulsion,
+            MavBatteryFunction::MAV_BATTERY_FUNCTION_AVIONICS => BatteryFunction::Avionics,
+            MavBatteryFunction::MAV_BATTERY_FUNCTION_PAYLOAD => BatteryFunction::Payload,
+        }
+    }
+}
+
+impl handler::Handler {
+    pub async fn handle_battery_status(&mut self, mav_id: u8, data: &BATTERY_STATUS_DATA) {
+        let vehicle_id = match self.vehicle_id_from_mav_id(&mav_id) {
+            Some(vehicle_id) => vehicle_id,
+            None => return
+        };
+        let mut battery = Battery::default_for_battery(&vehicle_id, data.id);
+        if let Ok(stored) = self.dal.telemetry_battery(&battery.id).await {
+            battery = stored;
+        }
+
+        battery.function = BatteryFunction::from_mavlink(data.battery_function);
+        battery.cell_voltages = protocol::decode_cell_voltages(&data.voltages);
+        battery.voltage = battery.cell_voltages.iter().sum();
+        battery.current = if data.current_battery >= 0 { Some(protocol::decode_current(data.current_battery)) } else { None };
+        battery.consumed_mah = if data.current_consumed >= 0 { Some(data.current_consumed) } else { None };
+        battery.consumed_energy = protocol::decode_energy(data.energy_consumed);
+        battery.temperature = protocol::decode_temperature(data.temperature);
+        battery.remaining = if data.battery_remaining >= 0 { Some(data.battery_remaining) } else { None };
+
+        // NOTE: mavlink 2 time_remaining & fault_bitmask extensions are not emitted by the mavlink crate build,
+        // so faults are not reported until the crate exposes them
+        battery.time_remaining = None;
+
+        if let Some(current) = battery.current {
+            battery.consumption_rate = Some(protocol::smooth_consumption_rate(battery.consumption_rate, current));
+        }
+        battery.estimated_endurance = match (battery.consumed_mah, battery.remaining, battery.consumption_rate) {
+            (Some(consumed_mah), Some(remaining), Some(rate)) => protocol::estimate_endurance(consumed_mah, remaining, rate),
+            _ => None
+        };
+
+        if let Err(err) = self.dal.save_telemetry_battery(vehicle_id, battery).await {
+            log::error!("Save battery telemetry error: {}", err);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use mavlink::common::*;
+
+use crate::models::captures::CapturedImage;
+use crate::models::commands::{Command, CommandExecutor, ExecuteCommandRequest};
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use crate::models::telemetry::{Camera, CameraMode};
+use crate::models::{payloads::PayloadType, vehicles::{PayloadId, VehicleId}};
+use super::{handler, super::protocol::telemetry as protocol};
+
+impl CameraMode {
+    pub fn from_mavlink(mode: mavlink::common::CameraMode) -> CameraMode {
+        match mode {
+            mavlink::common::CameraMode::CAMERA_MODE_IMAGE => CameraMode::Image,
+            mavlink::common::CameraMode::CAMERA_MODE_VIDEO => CameraMode::Video,
+            mavlink::common::CameraMode::CAMERA_MODE_IMAGE_SURVEY => CameraMode::Survey,
+        }
+    }
+}
+
+impl handler::Handler {
+    async fn obtain_camera(&mut self, mav_id: u8, comp_id: u8) -> Option<(Camera, PayloadId, VehicleId)> {
+        let payload_id = self.payload_id_from_mav_ids(mav_id, comp_id, PayloadType::Camera).await?;
+        let vehicle_id = self.vehicle_id_from_mav_id(&mav_id)?;
+        let camera = self.dal.telemetry_camera(&payload_id).await.unwrap_or(
+            Camera::default_for_id(&payload_id));
+        Some((camera, payload_id, vehicle_id))
+    }
+
+    async fn save_camera(&mut self, vehicle_id: VehicleId, camera: Camera) {
+        if let Err(err) = self.dal.save_telemetry_camera(vehicle_id, camera).await {
+            log::error!("Save camera telemetry error: {}", err);
+        }
+    }
+
+    pub async fn request_camera_information(&mut self, vehicle_id: VehicleId, payload_id: PayloadId) {
+        let request = ExecuteCommandRequest {
+            command: Command::RequestCameraInformation {},
+            executor: CommandExecutor::Payload { vehicle_id, payload_id },
+        };
+        self.add_command_execution(request, uuid::Uuid::new_v4().to_string()).await;
+    }
+
+    pub async fn handle_camera_information(&mut self, mav_id: u8, comp_id: u8, data: &CAMERA_INFORMATION_DATA) {
+        let (mut camera, _, vehicle_id) = match self.obtain_camera(mav_id, comp_id).await {
+            Some(camera) => camera,
+            None => return
+        };
+
+        camera.vendor_name = protocol::decode_string(&data.vendor_name);
+        camera.model_name = protocol::decode_string(&data.model_name);
+        camera.firmware_version = data.firmware_version;
+        camera.focal_length = data.focal_length;
+        camera.sensor_size_h = data.sensor_size_h;
+        camera.sensor_size_v = data.sensor_size_v;
+        camera.resolution_h = data.resolution_h;
+        camera.resolution_v = data.resolution_v;
+        camera.can_capture_image = data.flags.intersects(CameraCapFlags::CAMERA_CAP_FLAGS_CAPTURE_IMAGE);
+        camera.can_capture_video = data.flags.intersects(CameraCapFlags::CAMERA_CAP_FLAGS_CAPTURE_VIDEO);
+
+        self.save_camera(vehicle_id, camera).await;
+    }
+
+    pub async fn handle_camera_settings(&mut self, mav_id: u8, comp_id: u8, data: &CAMERA_SETTINGS_DATA) {
+        let (mut camera, _, vehicle_id) = match self.obtain_camera(mav_id, comp_id).await {
+            Some(camera) => camera,
+            None => return
+        };
+
+        camera.mode = CameraMode::from_mavlink(data.mode_id);
+
+        self.save_camera(vehicle_id, camera).await;
+    }
+
+    pub async fn handle_camera_capture_status(&mut self, mav_id: u8, comp_id: u8, data: &CAMERA_CAPTURE_STATUS_DATA) {
+        let (mut camera, _, vehicle_id) = match self.obtain_camera(mav_id, comp_id).await {
+            Some(camera) => camera,
+            None => return
+        };
+
+        // Image status: 0 idle, 1 capture in progress, 2 interval set but idle, 3 interval set and capture in progress
+        camera.image_capturing = data.image_status == 1 || data.image_status == 3;
+        camera.image_interval = data.image_interval;
+        camera.video_recording = data.video_status == 1;
+        camera.recording_time_ms = data.recording_time_ms;
+        camera.available_capacity = data.available_capacity;
+
+        self.save_camera(vehicle_id, camera).await;
+    }
+
+    pub async fn handle_camera_image_captured(&mut self, mav_id: u8, comp_id: u8, data: &CAMERA_IMAGE_CAPTURED_DATA) {
+        let (mut camera, payload_id, vehicle_id) = match self.obtain_camera(mav_id, comp_id).await {
+            Some(camera) => camera,
+            None => return
+        };
+
+        let (roll, pitch, yaw) = protocol::decode_quaternion(data.q);
+        let timestamp = if data.time_utc > 0 {
+            (data.time_utc / 1000) as i64
+        } else {
+            chrono::Utc::now().timestamp_millis()
+        };
+
+        let image = CapturedImage {
+            id: String::new(),
+            vehicle_id: vehicle_id.clone(),
+            payload_id,
+            mission_id: self.mission_id_from_mav_id(&mav_id).await,
+            flight_session_id: match self.dal.active_flight_session(&vehicle_id).await {
+                Ok(session) => session.map(|session| session.id),
+                Err(err) => {
+                    log::warn!("Flight session for captured image error: {}", err);
+                    None
+                }
+            },
+            timestamp,
+            index: data.image_index,
+            position: Geodetic {
+                latitude: protocol::decode_lat_lon(data.lat),
+                longitude: protocol::decode_lat_lon(data.lon),
+                altitude: protocol::decode_altitude(data.alt),
+                frame: GeodeticFrame::Wgs84AboveSeaLevel
+            },
+            relative_altitude: protocol::decode_altitude(data.relative_alt),
+            roll,
+            pitch,
+            yaw,
+            success: data.capture_result == 1,
+            file_url: protocol::decode_string(&data.file_url)
+        };
+
+        if let Err(err) = self.dal.save_captured_image(image).await {
+            log::error!("Save captured image error: {}", err);
+        }
+
+        camera.image_count = camera.image_count.max(data.image_index + 1);
+        self.save_camera(vehicle_id, camera).await;
+    }
+}
@@ -39,6 +39,10 @@ impl handler::Handler {
         }
 
         self.command_executions_last_sent.remove(&execution.id);
+        // Go to target is tracked to arrival only when its execution succeeded
+        if execution.state != (CommandState::Accepted {}) {
+            self.go_to_targets.retain(|_, target| target.command_id != execution.id);
+        }
         if let Err(err) = self.dal.remove_command_execution(&execution.id).await {
             log::error!("Error removing command execution: {}", err);
         }
@@ -77,21 +81,30 @@ impl handler::Handler {
             }
         }
 
-        // Get MAV ID for Vehicle
-        let mav_id; {
-            if let CommandExecutor::Vehicle { ref vehicle_id } = execution.executor {
-                let mav_id_opt = self.mav_id_from_vehicle_id(&vehicle_id);
-                if mav_id_opt.is_none() {
-                    log::warn!("Vehicle not found: {}", vehicle_id);
-                    self.finish_comand_execution(execution, CommandState::Failed {}).await;
-                    return None;
+        // Get MAV ID for Vehicle and component ID for Payload
+        let (mav_id, comp_id) = match execution.executor {
+            CommandExecutor::Vehicle { ref vehicle_id } => {
+                match self.mav_id_from_vehicle_id(vehicle_id) {
+                    Some(mav_id) => (mav_id, None),
+                    None => {
+                        log::warn!("Vehicle not found: {}", vehicle_id);
+                        self.finish_comand_execution(execution, CommandState::Failed {}).await;
+                        return None;
+                    }
+                }
+            },
+            CommandExecutor::Payload { ref vehicle_id, ref payload_id } => {
+                match self.mav_ids_from_payload_id(payload_id) {
+                    Some((mav_id, comp_id)) if self.vehicle_id_from_mav_id(&mav_id).as_ref() == Some(vehicle_id) =>
+                        (mav_id, Some(comp_id)),
+                    _ => {
+                        log::warn!("Payload {} not found for vehicle {}", payload_id, vehicle_id);
+                        self.finish_comand_execution(execution, CommandState::Failed {}).await;
+                        return None;
+                    }
                 }
-                mav_id = mav_id_opt.unwrap();
-            } else {
-                // Skip if executor is not a vehicle
-                return None;
             }
-        }
+        };
 
         // Check if exeeded max attempts
         let state;
@@ -114,8 +127,10 @@ impl handler::Handler {
         if let CommandState::Sent { attempt } = state {
             let encoded: Option<protocol::EncodedCommand>;
 
-            // Special case for SetMode
-            if let Command::SetMode { mode } = &execution.command {
+            if let Some(comp_id) = comp_id {
+                encoded = protocol::encode_payload_command(execution.command.clone(), mav_id, comp_id, attempt - 1);
+            } else if let Command::SetMode { mode } = &execution.command {
+                // Special case for SetMode
                 let modes = self.mav_modes.get(&mav_id);
                 if modes.is_none() {
                     log::warn!("Modes are not initialised for vehicle: {}", mav_id);
@@ -129,6 +144,9 @@ impl handler::Handler {
                     return None;
                 }
                 encoded = Some(protocol::encode_set_mode(mode_code.unwrap(), mav_id, attempt - 1));
+            } else if let Command::NavTo { .. } = &execution.command {
+                // Special case for NavTo, depends on autopilot and mode
+                encoded = self.encode_nav_to(&execution, mav_id, attempt - 1).await;
             } else {
                 encoded = protocol::encode_command(execution.command.clone(), mav_id, attempt - 1);
             }
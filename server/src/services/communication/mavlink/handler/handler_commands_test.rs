@@ -0,0 +1,69 @@
+use mavlink::common::*;
+
+use crate::models::commands::{Command, CommandExecutor, ExecuteCommandRequest};
+use crate::models::payloads::PayloadType;
+use super::handler::Handler;
+use super::test_utils::in_memory_handler;
+
+async fn setup() -> Handler {
+    let mut handler = in_memory_handler().await;
+
+    handler.mav_vehicles.insert(1, "mav_1".into());
+    handler.mav_vehicles.insert(2, "mav_2".into());
+    // Camera and gimbal proxied by the autopilot component and a standalone gimbal
+    handler.mav_payloads.insert((1, 1, PayloadType::Camera), "camera_1".into());
+    handler.mav_payloads.insert((1, 1, PayloadType::Gimbal), "gimbal_1".into());
+    handler.mav_payloads.insert((2, 154, PayloadType::Gimbal), "gimbal_2".into());
+    handler
+}
+
+async fn execute(handler: &mut Handler, vehicle_id: &str, payload_id: &str, command: Command) -> Vec<MavMessage> {
+    let request = ExecuteCommandRequest {
+        command,
+        executor: CommandExecutor::Payload { vehicle_id: vehicle_id.into(), payload_id: payload_id.into() }
+    };
+    handler.add_command_execution(request, "command_1".into()).await;
+    handler.collect_command_messages().await
+}
+
+fn targets(messages: &[MavMessage]) -> Vec<(MavCmd, u8, u8)> {
+    messages.iter().map(|message| match message {
+        MavMessage::COMMAND_LONG(data) => (data.command, data.target_system, data.target_component),
+        message => panic!("Unexpected message {:?}", message)
+    }).collect()
+}
+
+#[tokio::test]
+async fn test_payload_command_routing() {
+    let mut handler = setup().await;
+
+    let messages = execute(&mut handler, "mav_1", "camera_1", Command::CameraTrigger {}).await;
+    assert_eq!(targets(&messages), vec![(MavCmd::MAV_CMD_DO_DIGICAM_CONTROL, 1, 1)]);
+    assert!(handler.waiting_ack_command_executions.contains_key(&(MavCmd::MAV_CMD_DO_DIGICAM_CONTROL as u16, 1)));
+}
+
+#[tokio::test]
+async fn test_standalone_payload_routing() {
+    let mut handler = setup().await;
+
+    let messages = execute(&mut handler, "mav_2", "gimbal_2", Command::GimbalNeutral {}).await;
+    assert_eq!(targets(&messages), vec![(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW, 2, 154)]);
+}
+
+#[tokio::test]
+async fn test_payload_of_other_vehicle() {
+    let mut handler = setup().await;
+
+    let messages = execute(&mut handler, "mav_2", "camera_1", Command::CameraTrigger {}).await;
+    assert!(messages.is_empty());
+    assert!(handler.dal.command_execution(&"command_1".into()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_unknown_payload() {
+    let mut handler = setup().await;
+
+    let messages = execute(&mut handler, "mav_1", "camera_2", Command::CameraTrigger {}).await;
+    assert!(messages.is_empty());
+    assert!(handler.dal.all_command_executions().await.expect("Error getting executions").is_empty());
+}
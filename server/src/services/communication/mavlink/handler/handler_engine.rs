@@ -0,0 +1,85 @@
+use mavlink::common::*;
+
+use crate::models::{telemetry::*, vehicles::VehicleId};
+use super::handler;
+
+const RPM_SENSOR_INDEX: u8 = 0; // NOTE: first RPM sensor is treated as the engine one
+const CM3_IN_LITER: f32 = 1000.0;
+
+// EFI reports consumed fuel only, so the level is left from the full tank
+pub fn fuel_level(tank_capacity: Option<f32>, fuel_consumed: f32) -> Option<f32> {
+    match tank_capacity {
+        Some(capacity) if capacity > 0.0 => {
+            let left = capacity - fuel_consumed / CM3_IN_LITER;
+            Some((left / capacity * 100.0).clamp(0.0, 100.0))
+        },
+        _ => None
+    }
+}
+
+// NOTE: EFI has no ignition flag, powered ECU reports health and a turning engine reports rpm
+pub fn ignition_on(data: &EFI_STATUS_DATA) -> bool {
+    data.health != 0 || data.rpm > 0.0
+}
+
+impl handler::Handler {
+    pub async fn handle_efi_status(&mut self, mav_id: u8, data: &EFI_STATUS_DATA) {
+        let vehicle_id = match self.vehicle_id_from_mav_id(&mav_id) {
+            Some(vehicle_id) => vehicle_id,
+            None => return
+        };
+        let mut engine = self.dal.telemetry_engine(&vehicle_id).await
+            .unwrap_or(Engine::default_for_id(&vehicle_id));
+
+        engine.healthy = data.health != 0;
+        engine.ignition = ignition_on(data);
+        engine.running = data.rpm > 0.0;
+        engine.rpm = data.rpm;
+        engine.engine_load = data.engine_load;
+        engine.throttle_position = data.throttle_position;
+        engine.cylinder_head_temperature = data.cylinder_head_temperature;
+        engine.exhaust_gas_temperature = data.exhaust_gas_temperature;
+        engine.fuel_consumed = data.fuel_consumed;
+        engine.fuel_flow = data.fuel_flow;
+        let tank_capacity = match self.dal.vehicle(&vehicle_id).await {
+            Ok(vehicle) => vehicle.fuel_tank_capacity,
+            Err(err) => {
+                log::warn!("Vehicle {} for engine telemetry error: {}", &vehicle_id, err);
+                None
+            }
+        };
+        engine.fuel_level = fuel_level(tank_capacity, data.fuel_consumed);
+        // TODO: mavlink 2 ignition_voltage & fuel_pressure extensions, they are not emitted by the mavlink crate build
+
+        if let Err(err) = self.dal.save_telemetry_engine(vehicle_id.clone(), engine).await {
+            log::error!("Save engine telemetry error: {}", err);
+        }
+        self.update_flight_rpm(&vehicle_id, data.rpm).await;
+    }
+
+    pub async fn handle_raw_rpm(&mut self, mav_id: u8, data: &RAW_RPM_DATA) {
+        if data.index != RPM_SENSOR_INDEX {
+            return;
+        }
+        let vehicle_id = match self.vehicle_id_from_mav_id(&mav_id) {
+            Some(vehicle_id) => vehicle_id,
+            None => return
+        };
+        self.update_flight_rpm(&vehicle_id, data.frequency).await;
+    }
+
+    async fn update_flight_rpm(&mut self, vehicle_id: &VehicleId, rpm: f32) {
+        let mut flight = self.dal.telemetry_flight(vehicle_id).await
+            .unwrap_or(Flight::default_for_id(vehicle_id));
+
+        let rpm = rpm.round() as i32;
+        if flight.rpm == rpm {
+            return;
+        }
+        flight.rpm = rpm;
+
+        if let Err(err) = self.dal.save_telemetry_flight(vehicle_id.clone(), flight).await {
+            log::error!("Save flight telemetry error: {}", err);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use mavlink::common::*;
+use test_case::test_case;
+
+use crate::models::colors::EntityColor;
+use crate::models::failsafe::FailsafePolicy;
+use crate::models::vehicles::*;
+use super::handler::Handler;
+use super::test_utils::in_memory_handler;
+use super::handler_engine::{fuel_level, ignition_on};
+
+async fn setup(fuel_tank_capacity: Option<f32>) -> (Handler, VehicleId) {
+    let mut handler = in_memory_handler().await;
+
+    let vehicle = handler.dal.save_vehicle(VehicleDescription {
+        id: String::new(),
+        name: "Petrol".into(),
+        color: EntityColor::Cyan,
+        vehicle_type: VehicleType::FixedWing,
+        protocol_id: ProtocolId::MavlinkId { mav_id: 1 },
+        features: vec![VehicleFeatures::PetrolEngine],
+        available_modes: Vec::new(),
+        failsafe: FailsafePolicy::default(),
+        fuel_tank_capacity
+    }).await.expect("Error saving vehicle");
+    handler.mav_vehicles.insert(1, vehicle.id.clone());
+    (handler, vehicle.id)
+}
+
+#[test_case(Some(10.0), 0.0, Some(100.0); "full")]
+#[test_case(Some(10.0), 2500.0, Some(75.0); "consumed")]
+#[test_case(Some(10.0), 12000.0, Some(0.0); "overconsumed")]
+#[test_case(None, 2500.0, None; "unknown capacity")]
+#[test_case(Some(0.0), 2500.0, None; "zero capacity")]
+fn test_fuel_level(tank_capacity: Option<f32>, fuel_consumed: f32, expected: Option<f32>) {
+    assert_eq!(fuel_level(tank_capacity, fuel_consumed), expected);
+}
+
+#[test_case(0, 0.0, false; "off")]
+#[test_case(1, 0.0, true; "powered")]
+#[test_case(0, 3200.0, true; "running unhealthy")]
+fn test_ignition(health: u8, rpm: f32, expected: bool) {
+    assert_eq!(ignition_on(&EFI_STATUS_DATA { health, rpm, ..EFI_STATUS_DATA::default() }), expected);
+}
+
+#[tokio::test]
+async fn test_handle_efi_status() {
+    let (mut handler, vehicle_id) = setup(Some(20.0)).await;
+
+    handler.handle_efi_status(1, &EFI_STATUS_DATA {
+        health: 1,
+        rpm: 4200.4,
+        fuel_consumed: 5000.0,
+        fuel_flow: 120.0,
+        cylinder_head_temperature: 140.0,
+        ..EFI_STATUS_DATA::default()
+    }).await;
+
+    let engine = handler.dal.telemetry_engine(&vehicle_id).await.expect("Error reading engine telemetry");
+    assert!(engine.healthy && engine.ignition && engine.running);
+    assert_eq!(engine.rpm, 4200.4);
+    assert_eq!(engine.cylinder_head_temperature, 140.0);
+    assert_eq!(engine.fuel_level, Some(75.0));
+
+    let flight = handler.dal.telemetry_flight(&vehicle_id).await.expect("Error reading flight telemetry");
+    assert_eq!(flight.rpm, 4200);
+}
+
+#[tokio::test]
+async fn test_handle_efi_status_unknown_tank() {
+    let (mut handler, vehicle_id) = setup(None).await;
+
+    handler.handle_efi_status(1, &EFI_STATUS_DATA { fuel_consumed: 5000.0, ..EFI_STATUS_DATA::default() }).await;
+
+    let engine = handler.dal.telemetry_engine(&vehicle_id).await.expect("Error reading engine telemetry");
+    assert!(!engine.ignition && !engine.running);
+    assert_eq!(engine.fuel_consumed, 5000.0);
+    assert_eq!(engine.fuel_level, None);
+}
+
+#[tokio::test]
+async fn test_handle_raw_rpm() {
+    let (mut handler, vehicle_id) = setup(None).await;
+
+    // Not the engine sensor
+    handler.handle_raw_rpm(1, &RAW_RPM_DATA { index: 1, frequency: 5000.0 }).await;
+    assert!(handler.dal.telemetry_flight(&vehicle_id).await.is_err());
+
+    handler.handle_raw_rpm(1, &RAW_RPM_DATA { index: 0, frequency: 3000.0 }).await;
+    let flight = handler.dal.telemetry_flight(&vehicle_id).await.expect("Error reading flight telemetry");
+    assert_eq!(flight.rpm, 3000);
+}
@@ -0,0 +1,81 @@
+use mavlink::common::*;
+
+use crate::models::{payloads::PayloadType, telemetry::*, vehicles::VehicleId};
+use super::{handler, super::protocol::telemetry as protocol};
+
+impl GimbalFailure {
+    pub fn from_mavlink(flags: GimbalDeviceErrorFlags) -> Vec<GimbalFailure> {
+        let mut failures = Vec::new();
+        let mut check_flag = |failure: GimbalFailure, flag: GimbalDeviceErrorFlags| {
+            if flags.intersects(flag) {
+                failures.push(failure);
+            }
+        };
+
+        check_flag(GimbalFailure::AtRollLimit, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_AT_ROLL_LIMIT);
+        check_flag(GimbalFailure::AtPitchLimit, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_AT_PITCH_LIMIT);
+        check_flag(GimbalFailure::AtYawLimit, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_AT_YAW_LIMIT);
+        check_flag(GimbalFailure::Encoder, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_ENCODER_ERROR);
+        check_flag(GimbalFailure::Power, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_POWER_ERROR);
+        check_flag(GimbalFailure::Motor, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_MOTOR_ERROR);
+        check_flag(GimbalFailure::Software, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_SOFTWARE_ERROR);
+        check_flag(GimbalFailure::Comms, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_COMMS_ERROR);
+        check_flag(GimbalFailure::Calibrating, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_CALIBRATION_RUNNING);
+        check_flag(GimbalFailure::NoManager, GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_NO_MANAGER);
+        failures
+    }
+}
+
+impl handler::Handler {
+    async fn obtain_gimbal(&mut self, mav_id: u8, comp_id: u8) -> Option<(Gimbal, VehicleId)> {
+        // NOTE: gimbal may be proxied by the autopilot, so it's registered as a gimbal payload of the sending component,
+        // apart from a camera reported by the same component
+        let payload_id = self.payload_id_from_mav_ids(mav_id, comp_id, PayloadType::Gimbal).await?;
+        let vehicle_id = self.vehicle_id_from_mav_id(&mav_id)?;
+        let gimbal = self.dal.telemetry_gimbal(&payload_id).await.unwrap_or(
+            Gimbal::default_for_id(&payload_id));
+        Some((gimbal, vehicle_id))
+    }
+
+    pub async fn handle_gimbal_device_attitude(&mut self, mav_id: u8, comp_id: u8, data: &GIMBAL_DEVICE_ATTITUDE_STATUS_DATA) {
+        let (mut gimbal, vehicle_id) = match self.obtain_gimbal(mav_id, comp_id).await {
+            Some(gimbal) => gimbal,
+            None => return
+        };
+
+        let (roll, pitch, yaw) = protocol::decode_quaternion(data.q);
+        gimbal.roll = roll;
+        gimbal.pitch = pitch;
+        gimbal.yaw = yaw;
+
+        gimbal.roll_rate = protocol::decode_angles(data.angular_velocity_x);
+        gimbal.pitch_rate = protocol::decode_angles(data.angular_velocity_y);
+        gimbal.yaw_rate = protocol::decode_angles(data.angular_velocity_z);
+
+        gimbal.yaw_in_earth_frame = data.flags.intersects(GimbalDeviceFlags::GIMBAL_DEVICE_FLAGS_YAW_IN_EARTH_FRAME);
+        gimbal.retracted = data.flags.intersects(GimbalDeviceFlags::GIMBAL_DEVICE_FLAGS_RETRACT);
+        gimbal.neutral = data.flags.intersects(GimbalDeviceFlags::GIMBAL_DEVICE_FLAGS_NEUTRAL);
+        gimbal.failures = GimbalFailure::from_mavlink(data.failure_flags);
+
+        if let Err(err) = self.dal.save_telemetry_gimbal(vehicle_id, gimbal).await {
+            log::error!("Save gimbal telemetry error: {}", err);
+        }
+    }
+
+    pub async fn handle_gimbal_manager_status(&mut self, mav_id: u8, comp_id: u8, data: &GIMBAL_MANAGER_STATUS_DATA) {
+        let (mut gimbal, vehicle_id) = match self.obtain_gimbal(mav_id, comp_id).await {
+            Some(gimbal) => gimbal,
+            None => return
+        };
+
+        // NOTE: GimbalManagerFlags is not a bitmask in this mavlink version, so only a single flag is decoded
+        gimbal.retracted = data.flags == GimbalManagerFlags::GIMBAL_MANAGER_FLAGS_RETRACT;
+        gimbal.neutral = data.flags == GimbalManagerFlags::GIMBAL_MANAGER_FLAGS_NEUTRAL;
+        gimbal.primary_control_sysid = data.primary_control_sysid;
+        gimbal.primary_control_compid = data.primary_control_compid;
+
+        if let Err(err) = self.dal.save_telemetry_gimbal(vehicle_id, gimbal).await {
+            log::error!("Save gimbal telemetry error: {}", err);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use mavlink::common::GimbalDeviceErrorFlags;
+
+use crate::models::telemetry::GimbalFailure;
+
+#[test]
+fn test_gimbal_failures() {
+    let flags = GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_AT_PITCH_LIMIT |
+        GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_MOTOR_ERROR |
+        GimbalDeviceErrorFlags::GIMBAL_DEVICE_ERROR_FLAGS_NO_MANAGER;
+    assert_eq!(GimbalFailure::from_mavlink(flags),
+        vec![GimbalFailure::AtPitchLimit, GimbalFailure::Motor, GimbalFailure::NoManager]);
+}
+
+#[test]
+fn test_no_gimbal_failures() {
+    assert!(GimbalFailure::from_mavlink(GimbalDeviceErrorFlags::empty()).is_empty());
+}
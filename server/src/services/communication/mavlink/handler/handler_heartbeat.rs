@@ -1,6 +1,7 @@
 use mavlink::common::*;
 
-use crate::models::{colors::EntityColor, vehicles::*};
+use crate::models::{colors::EntityColor, failsafe::FailsafePolicy, payloads::PayloadType, vehicles::*};
+use crate::models::messages::{MessageSeverity, VehicleMessage};
 use super::{handler, super::protocol::modes as protocol};
 
 const AUTO_ADD_VEHICLES: bool = true; // TODO: to settings
@@ -40,8 +41,36 @@ impl VehicleState {
     }
 }
 
+impl VtolState {
+    pub fn from_mavlink(vtol_state: MavVtolState) -> VtolState {
+        match vtol_state {
+            MavVtolState::MAV_VTOL_STATE_UNDEFINED => VtolState::Undefined,
+            MavVtolState::MAV_VTOL_STATE_TRANSITION_TO_FW => VtolState::TransitionToFixedWing,
+            MavVtolState::MAV_VTOL_STATE_TRANSITION_TO_MC => VtolState::TransitionToMulticopter,
+            MavVtolState::MAV_VTOL_STATE_MC => VtolState::Multicopter,
+            MavVtolState::MAV_VTOL_STATE_FW => VtolState::FixedWing,
+        }
+    }
+}
+
+impl LandedState {
+    pub fn from_mavlink(landed_state: MavLandedState) -> LandedState {
+        match landed_state {
+            MavLandedState::MAV_LANDED_STATE_UNDEFINED => LandedState::Undefined,
+            MavLandedState::MAV_LANDED_STATE_ON_GROUND => LandedState::OnGround,
+            MavLandedState::MAV_LANDED_STATE_IN_AIR => LandedState::InAir,
+            MavLandedState::MAV_LANDED_STATE_TAKEOFF => LandedState::Takeoff,
+            MavLandedState::MAV_LANDED_STATE_LANDING => LandedState::Landing,
+        }
+    }
+}
+
 impl handler::Handler {
-    pub async fn handle_heartbeat(&mut self, mav_id: u8, heartbeat_data: &HEARTBEAT_DATA) {
+    pub async fn handle_heartbeat(&mut self, mav_id: u8, comp_id: u8, heartbeat_data: &HEARTBEAT_DATA) {
+        if let Some(payload_type) = PayloadType::from_mavlink(heartbeat_data.mavtype) {
+            return self.handle_payload_heartbeat(mav_id, comp_id, payload_type).await;
+        }
+
         let mut vehicle = match self.obtain_vehicle(mav_id).await {
             Ok(vehicle) => {
                 match vehicle {
@@ -62,6 +91,8 @@ impl handler::Handler {
             save_vehicle = true;
         }
 
+        self.mav_autopilots.insert(mav_id, heartbeat_data.autopilot);
+
         if !self.mav_modes.contains_key(&mav_id) || vehicle.available_modes.is_empty() {
             match heartbeat_data.autopilot {
                 MavAutopilot::MAV_AUTOPILOT_ARDUPILOTMEGA => {
@@ -83,12 +114,18 @@ impl handler::Handler {
 
         // TODO: MAV_MODE_FLAG_MANUAL_INPUT_ENABLED, MAV_MODE_FLAG_STABILIZE_ENABLED, MAV_MODE_FLAG_GUIDED_ENABLED, MAV_MODE_FLAG_AUTO_ENABLED
 
+        // Keep states reported by other messages
+        let previous = self.dal.vehcile_status(&vehicle.id).await
+            .unwrap_or(VehicleStatus::default_for_id(&vehicle.id));
+
         let status = VehicleStatus {
             id: vehicle.id.clone(),
             last_heartbeat: chrono::prelude::Utc::now().timestamp_millis(),
             state: VehicleState::from_mavlink(heartbeat_data.system_status),
             armed: heartbeat_data.base_mode.intersects(MavModeFlag::MAV_MODE_FLAG_SAFETY_ARMED),
-            mode
+            mode,
+            vtol_state: previous.vtol_state.clone(),
+            landed_state: previous.landed_state.clone()
         };
 
         if save_vehicle {
@@ -97,12 +134,52 @@ impl handler::Handler {
             }
         }
 
+        // Report only once, when the vehicle enters flight termination
+        if status.state == VehicleState::FlightTermination && previous.state != VehicleState::FlightTermination {
+            self.report_flight_termination(&status.id).await;
+        }
+
         // Update vehicle status in registry
         if let Err(err) = self.dal.update_vehicle_status(status).await {
             log::error!("Save vehicle status error: {:?}", &err);
         }
     }
 
+    pub async fn handle_extended_sys_state(&mut self, mav_id: u8, data: &EXTENDED_SYS_STATE_DATA) {
+        let vehicle_id = match self.vehicle_id_from_mav_id(&mav_id) {
+            Some(vehicle_id) => vehicle_id,
+            None => return
+        };
+        let mut status = self.dal.vehcile_status(&vehicle_id).await
+            .unwrap_or(VehicleStatus::default_for_id(&vehicle_id));
+
+        let vtol_state = VtolState::from_mavlink(data.vtol_state);
+        let landed_state = LandedState::from_mavlink(data.landed_state);
+        if status.vtol_state == vtol_state && status.landed_state == landed_state {
+            return;
+        }
+        status.vtol_state = vtol_state;
+        status.landed_state = landed_state;
+
+        if let Err(err) = self.dal.update_vehicle_status(status).await {
+            log::error!("Save vehicle status error: {:?}", &err);
+        }
+    }
+
+    async fn report_flight_termination(&mut self, vehicle_id: &VehicleId) {
+        log::error!("Vehicle {} entered flight termination", vehicle_id);
+        let result = self.dal.add_vehicle_message(VehicleMessage {
+            id: String::new(),
+            vehicle_id: vehicle_id.clone(),
+            timestamp: 0,
+            severity: MessageSeverity::Emergency,
+            text: "Flight termination".into()
+        }).await;
+        if let Err(err) = result {
+            log::error!("Add vehicle message error: {:?}", &err);
+        }
+    }
+
     async fn obtain_vehicle(&mut self, mav_id: u8) -> anyhow::Result<Option<VehicleDescription>> {
         let protocol_id = ProtocolId::MavlinkId { mav_id };
         let vehicle = self.dal.vehicle_by_protocol_id(&protocol_id).await?;
@@ -121,7 +198,9 @@ impl handler::Handler {
                         color: EntityColor::Cyan,
                         vehicle_type: VehicleType::Auto,
                         features: Vec::new(),
-                        available_modes: Vec::new()
+                        available_modes: Vec::new(),
+                        failsafe: FailsafePolicy::default(),
+                        fuel_tank_capacity: None
                     }).await?;
                     self.mav_vehicles.insert(mav_id, vehicle.id.clone());
                     log::info!("New MAVLink vehicle created: {:?}", &vehicle.id);
@@ -167,17 +167,13 @@ impl handler::Handler {
             };
 
             // Crop mission items
-            match self.dal.mission_route(&status.id).await {
-                Ok(mut route) => {
-                    if route.items.len() > data.count as usize {
-                        route.items.truncate(data.count as usize);
-                        if let Err(err) = self.dal.update_route(route).await {
-                            log::error!("Error updating mission route: {}", err);
-                        }
-                    }
-                },
-                Err(err) => {
-                    log::error!("Error getting mission route: {}", err);
+            if let Err(err) = self.dal.truncate_downloaded_route(&status.id, data.count).await {
+                log::error!("Error updating mission route: {}", err);
+            }
+
+            if let MissionUpdateState::Actual { .. } = status.state {
+                if let Err(err) = self.dal.sync_vehicle_route(&status.id, true).await {
+                    log::error!("Error saving vehicle route: {}", err);
                 }
             }
 
@@ -217,7 +213,7 @@ impl handler::Handler {
                 }
             } else {
                 // Add route item, zero-based index
-                if let Err(err) = self.dal.upsert_route_item(
+                if let Err(err) = self.dal.upsert_downloaded_route_item(
                     &status.id,
                     protocol::mission_route_item_from_mavlink(data),
                     progress - 1 // NOTE: -1 for HOME item
@@ -230,6 +226,9 @@ impl handler::Handler {
                 log::info!("Mission download completed for MAVLink {}", mav_id);
                 // TODO: send ACK
                 status.state = MissionUpdateState::Actual { total };
+                if let Err(err) = self.dal.sync_vehicle_route(&status.id, true).await {
+                    log::error!("Error saving vehicle route: {}", err);
+                }
             } else {
                 status.state = MissionUpdateState::Download { total, progress: progress + 1 };
             }
@@ -280,6 +279,9 @@ impl handler::Handler {
                     },
                     _ => {}
                 }
+                if let Err(err) = self.dal.sync_vehicle_route(&status.id, false).await {
+                    log::error!("Error saving vehicle route: {}", err);
+                }
             },
             MavMissionResult::MAV_MISSION_OPERATION_CANCELLED => {
                 log::info!("Mission operation canceled for MAVLink {}", mav_id);
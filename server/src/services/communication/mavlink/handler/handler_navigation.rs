@@ -0,0 +1,107 @@
+use mavlink::common::MavAutopilot;
+
+use crate::models::commands::{Command, CommandExecution, CommandId, CommandState};
+use crate::models::messages::MessageSeverity;
+use crate::models::spatial::Geodetic;
+use crate::models::telemetry::Navigation;
+use crate::models::vehicles::{VehicleId, VehicleMode};
+use super::{handler, super::protocol::{commands as protocol, navigation}};
+
+#[derive(Clone, Debug)]
+pub struct GoToTarget {
+    pub command_id: CommandId,
+    pub position: Geodetic,
+    pub arrival_radius: f64,
+    pub awaiting_feedback: bool, // Position targets are not acknowledged
+    pub confirmed: bool
+}
+
+impl handler::Handler {
+    pub async fn encode_nav_to(&mut self, execution: &CommandExecution, mav_id: u8, attempt: u8) -> Option<protocol::EncodedCommand> {
+        let (position, loiter_radius, ground_speed, switch_to_guided) = match &execution.command {
+            Command::NavTo { position, loiter_radius, ground_speed, switch_to_guided } =>
+                (position, *loiter_radius, *ground_speed, *switch_to_guided),
+            _ => return None
+        };
+        let autopilot = self.mav_autopilots.get(&mav_id).cloned().unwrap_or(MavAutopilot::MAV_AUTOPILOT_GENERIC);
+        let position_target = protocol::uses_position_target(autopilot, loiter_radius, ground_speed);
+
+        // Position targets are accepted only in Guided, so switch mode first and send target on the next attempt
+        if position_target && switch_to_guided && !self.is_in_guided(mav_id).await {
+            let mode_code = self.mav_modes.get(&mav_id)
+                .and_then(|modes| modes.iter()
+                    .filter(|(_, mode)| **mode == VehicleMode::Guided)
+                    .map(|(&code, _)| code)
+                    .min());
+            return match mode_code {
+                Some(mode_code) => {
+                    let mut encoded = protocol::encode_set_mode(mode_code, mav_id, attempt);
+                    encoded.ack_cmd = None; // Don't finish execution on mode ack
+                    Some(encoded)
+                },
+                None => {
+                    log::warn!("Guided mode is not available for vehicle: {}", mav_id);
+                    None
+                }
+            };
+        }
+
+        self.go_to_targets.insert(mav_id, GoToTarget {
+            command_id: execution.id.clone(),
+            position: position.clone(),
+            arrival_radius: navigation::arrival_radius(loiter_radius),
+            awaiting_feedback: position_target,
+            confirmed: false
+        });
+        Some(protocol::encode_nav_to(autopilot, position.clone(), loiter_radius, ground_speed, switch_to_guided, mav_id))
+    }
+
+    pub async fn track_go_to(&mut self, mav_id: u8, vehicle_id: &VehicleId, navigation: &Navigation) {
+        let mut target = match self.go_to_targets.get(&mav_id) {
+            Some(target) => target.clone(),
+            None => return
+        };
+
+        let matches = navigation::target_matches(&target.position, &navigation.target_position);
+        if !target.confirmed {
+            if !matches {
+                return;
+            }
+            target.confirmed = true;
+            if target.awaiting_feedback {
+                self.confirm_go_to_execution(&target.command_id).await;
+            }
+        } else if !matches {
+            log::info!("Go to target for vehicle {} was replaced", vehicle_id);
+            self.go_to_targets.remove(&mav_id);
+            return;
+        }
+
+        if navigation::has_arrived(&navigation.position, &target.position, target.arrival_radius) {
+            self.go_to_targets.remove(&mav_id);
+            self.add_vehicle_message(mav_id, MessageSeverity::Info, "Go to target reached".into()).await;
+        } else {
+            self.go_to_targets.insert(mav_id, target);
+        }
+    }
+
+    async fn is_in_guided(&self, mav_id: u8) -> bool {
+        let vehicle_id = match self.vehicle_id_from_mav_id(&mav_id) {
+            Some(vehicle_id) => vehicle_id,
+            None => return false
+        };
+        match self.dal.vehcile_status(&vehicle_id).await {
+            Ok(status) => status.mode == VehicleMode::Guided,
+            Err(_) => false
+        }
+    }
+
+    async fn confirm_go_to_execution(&mut self, command_id: &CommandId) {
+        // Execution can be already finished or canceled
+        if let Ok(execution) = self.dal.command_execution(command_id).await {
+            if let CommandState::Sent { .. } = execution.state {
+                self.finish_comand_execution(execution, CommandState::Accepted {}).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use mavlink::common::*;
+
+use crate::models::commands::{Command, CommandExecutor, ExecuteCommandRequest};
+use crate::models::spatial::Geodetic;
+use crate::models::vehicles::{VehicleMode, VehicleStatus};
+use super::handler::Handler;
+use super::test_utils::in_memory_handler;
+
+const GUIDED_MODE: u32 = 15;
+
+async fn setup(autopilot: MavAutopilot) -> Handler {
+    let mut handler = in_memory_handler().await;
+
+    handler.mav_vehicles.insert(1, "mav_1".into());
+    handler.mav_autopilots.insert(1, autopilot);
+    handler.mav_modes.insert(1, [(0, VehicleMode::Manual), (GUIDED_MODE, VehicleMode::Guided)].into());
+    set_mode(&handler, VehicleMode::Manual).await;
+    handler
+}
+
+async fn set_mode(handler: &Handler, mode: VehicleMode) {
+    handler.dal.update_vehicle_status(VehicleStatus { mode, ..VehicleStatus::default_for_id(&"mav_1".into()) }).await
+        .expect("Error updating vehicle status");
+}
+
+async fn go_to(handler: &mut Handler, ground_speed: Option<f32>) -> Vec<MavMessage> {
+    let request = ExecuteCommandRequest {
+        command: Command::NavTo {
+            position: Geodetic::default(),
+            loiter_radius: None,
+            ground_speed,
+            switch_to_guided: true
+        },
+        executor: CommandExecutor::Vehicle { vehicle_id: "mav_1".into() }
+    };
+    handler.add_command_execution(request, "command_1".into()).await;
+    handler.collect_command_messages().await
+}
+
+async fn resend(handler: &mut Handler) -> Vec<MavMessage> {
+    handler.command_executions_last_sent.clear();
+    handler.collect_command_messages().await
+}
+
+#[tokio::test]
+async fn test_switch_to_guided_then_position_target() {
+    let mut handler = setup(MavAutopilot::MAV_AUTOPILOT_ARDUPILOTMEGA).await;
+
+    // Mode switch goes first and its ack does not finish the execution
+    match go_to(&mut handler, None).await.as_slice() {
+        [MavMessage::COMMAND_LONG(data)] => {
+            assert_eq!(data.command, MavCmd::MAV_CMD_DO_SET_MODE);
+            assert_eq!(data.param2, GUIDED_MODE as f32);
+        },
+        messages => panic!("Unexpected messages {:?}", messages)
+    };
+    assert!(handler.waiting_ack_command_executions.is_empty());
+    assert!(handler.go_to_targets.is_empty());
+
+    // Still not in Guided, so switch again
+    assert!(matches!(resend(&mut handler).await.as_slice(), [MavMessage::COMMAND_LONG(_)]));
+
+    set_mode(&handler, VehicleMode::Guided).await;
+    assert!(matches!(resend(&mut handler).await.as_slice(), [MavMessage::SET_POSITION_TARGET_GLOBAL_INT(_)]));
+
+    let target = handler.go_to_targets.get(&1).expect("No go to target");
+    assert_eq!(target.command_id, "command_1");
+    assert!(target.awaiting_feedback && !target.confirmed);
+}
+
+#[tokio::test]
+async fn test_reposition_without_mode_switch() {
+    let mut handler = setup(MavAutopilot::MAV_AUTOPILOT_ARDUPILOTMEGA).await;
+
+    match go_to(&mut handler, Some(15.0)).await.as_slice() {
+        [MavMessage::COMMAND_INT(data)] => assert_eq!(data.command, MavCmd::MAV_CMD_DO_REPOSITION),
+        messages => panic!("Unexpected messages {:?}", messages)
+    };
+    assert!(!handler.go_to_targets.get(&1).expect("No go to target").awaiting_feedback);
+
+    // Accepted reposition is tracked further to arrival
+    handler.handle_command_ack(1, &COMMAND_ACK_DATA {
+        command: MavCmd::MAV_CMD_DO_REPOSITION,
+        result: MavResult::MAV_RESULT_ACCEPTED
+    }).await;
+    assert!(handler.go_to_targets.contains_key(&1));
+}
+
+#[tokio::test]
+async fn test_denied_reposition_drops_target() {
+    let mut handler = setup(MavAutopilot::MAV_AUTOPILOT_PX4).await;
+
+    go_to(&mut handler, None).await;
+    assert!(handler.go_to_targets.contains_key(&1));
+
+    handler.handle_command_ack(1, &COMMAND_ACK_DATA {
+        command: MavCmd::MAV_CMD_DO_REPOSITION,
+        result: MavResult::MAV_RESULT_DENIED
+    }).await;
+    assert!(handler.go_to_targets.is_empty());
+}
+
+#[tokio::test]
+async fn test_unconfirmed_position_target_dropped_on_failure() {
+    let mut handler = setup(MavAutopilot::MAV_AUTOPILOT_ARDUPILOTMEGA).await;
+    set_mode(&handler, VehicleMode::Guided).await;
+
+    go_to(&mut handler, None).await;
+    assert!(handler.go_to_targets.contains_key(&1));
+
+    // No position target feedback until the attempts are exhausted
+    while handler.dal.command_execution(&"command_1".into()).await.is_ok() {
+        resend(&mut handler).await;
+    }
+    assert!(handler.go_to_targets.is_empty());
+}
+
+#[tokio::test]
+async fn test_canceled_go_to_drops_target() {
+    let mut handler = setup(MavAutopilot::MAV_AUTOPILOT_ARDUPILOTMEGA).await;
+    set_mode(&handler, VehicleMode::Guided).await;
+
+    go_to(&mut handler, None).await;
+    handler.cancel_command_execution("command_1".into()).await;
+    assert!(handler.go_to_targets.is_empty());
+    assert!(handler.dal.command_execution(&"command_1".into()).await.is_err());
+}
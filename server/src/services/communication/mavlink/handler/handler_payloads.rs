@@ -0,0 +1,67 @@
+use mavlink::common::*;
+
+use crate::models::{payloads::*, vehicles::{PayloadId, VehicleId}};
+use super::handler;
+
+impl PayloadType {
+    pub fn from_mavlink(mavtype: MavType) -> Option<PayloadType> {
+        match mavtype {
+            MavType::MAV_TYPE_CAMERA => Some(PayloadType::Camera),
+            MavType::MAV_TYPE_GIMBAL => Some(PayloadType::Gimbal),
+            _ => None
+        }
+    }
+}
+
+impl handler::Handler {
+    pub fn mav_ids_from_payload_id(&self, payload_id: &PayloadId) -> Option<(u8, u8)> {
+        self.mav_payloads
+            .iter()
+            .find(|(_, p_id)| p_id == &payload_id)
+            .map(|((mav_id, comp_id, _), _)| (*mav_id, *comp_id))
+    }
+
+    pub async fn handle_payload_heartbeat(&mut self, mav_id: u8, comp_id: u8, payload_type: PayloadType) {
+        self.payload_id_from_mav_ids(mav_id, comp_id, payload_type).await;
+    }
+
+    pub async fn payload_id_from_mav_ids(&mut self, mav_id: u8, comp_id: u8, payload_type: PayloadType) -> Option<PayloadId> {
+        if let Some(payload_id) = self.mav_payloads.get(&(mav_id, comp_id, payload_type.clone())) {
+            return Some(payload_id.clone());
+        }
+
+        // Payloads are bound to the vehicle, so wait for the autopilot heartbeat first
+        let vehicle_id = self.vehicle_id_from_mav_id(&mav_id)?;
+
+        match self.obtain_payload(vehicle_id, comp_id, payload_type).await {
+            Ok(payload) => {
+                self.mav_payloads.insert((mav_id, comp_id, payload.payload_type.clone()), payload.id.clone());
+                if payload.payload_type == PayloadType::Camera {
+                    self.request_camera_information(payload.vehicle_id, payload.id.clone()).await;
+                }
+                Some(payload.id)
+            },
+            Err(err) => {
+                log::error!("Obtain payload error: {:?}", &err);
+                None
+            }
+        }
+    }
+
+    async fn obtain_payload(&mut self, vehicle_id: VehicleId, comp_id: u8, payload_type: PayloadType) -> anyhow::Result<Payload> {
+        let protocol_id = PayloadProtocolId::MavlinkComponent { comp_id };
+        if let Some(payload) = self.dal.payload_by_protocol_id(&vehicle_id, &protocol_id, &payload_type).await? {
+            return Ok(payload);
+        }
+
+        let payload = self.dal.save_payload(Payload {
+            id: String::new(),
+            name: format!("{:?} (Component {})", payload_type, comp_id),
+            vehicle_id,
+            payload_type,
+            protocol_id
+        }).await?;
+        log::info!("New MAVLink payload created: {:?}", &payload.id);
+        Ok(payload)
+    }
+}
@@ -0,0 +1,45 @@
+use mavlink::common::*;
+
+use crate::models::messages::{MessageSeverity, VehicleMessage};
+use super::{handler, super::protocol::status_text as protocol};
+
+impl MessageSeverity {
+    pub fn from_mavlink(severity: MavSeverity) -> MessageSeverity {
+        match severity {
+            MavSeverity::MAV_SEVERITY_EMERGENCY => MessageSeverity::Emergency,
+            MavSeverity::MAV_SEVERITY_ALERT => MessageSeverity::Alert,
+            MavSeverity::MAV_SEVERITY_CRITICAL => MessageSeverity::Critical,
+            MavSeverity::MAV_SEVERITY_ERROR => MessageSeverity::Error,
+            MavSeverity::MAV_SEVERITY_WARNING => MessageSeverity::Warning,
+            MavSeverity::MAV_SEVERITY_NOTICE => MessageSeverity::Notice,
+            MavSeverity::MAV_SEVERITY_INFO => MessageSeverity::Info,
+            MavSeverity::MAV_SEVERITY_DEBUG => MessageSeverity::Debug,
+        }
+    }
+}
+
+impl handler::Handler {
+    pub async fn add_vehicle_message(&mut self, mav_id: u8, severity: MessageSeverity, text: String) {
+        let vehicle_id = match self.vehicle_id_from_mav_id(&mav_id) {
+            Some(vehicle_id) => vehicle_id,
+            None => return
+        };
+
+        log::info!("Mav: {} {:?}: {}", mav_id, severity, text);
+        let message = VehicleMessage {
+            id: String::new(),
+            vehicle_id,
+            timestamp: 0,
+            severity,
+            text
+        };
+        if let Err(err) = self.dal.add_vehicle_message(message).await {
+            log::error!("Add vehicle message error: {}", err);
+        }
+    }
+
+    pub async fn handle_status_text(&mut self, mav_id: u8, data: &STATUSTEXT_DATA) {
+        let text = protocol::decode_status_text(&data.text);
+        self.add_vehicle_message(mav_id, MessageSeverity::from_mavlink(data.severity), text).await;
+    }
+}
@@ -50,6 +50,17 @@ impl handler::Handler {
         navigation.position.longitude = protocol::decode_lat_lon(global_pos.lon);
         navigation.position.altitude = protocol::decode_altitude(global_pos.alt);
         navigation.position.frame = GeodeticFrame::Wgs84AboveSeaLevel;
+        navigation.relative_altitude = protocol::decode_altitude(global_pos.relative_alt);
+        let (position, home) = (navigation.position.clone(), navigation.home_position.clone());
+        navigation.terrain_altitude = match self.dal.terrain.query(move |terrain| terrain
+            .convert(&position, GeodeticFrame::Wgs84AboveTerrain, Some(&home))
+            .map(|position| position.altitude)).await {
+            Ok(altitude) => altitude,
+            Err(err) => {
+                log::error!("Terrain query error: {}", err);
+                None
+            }
+        };
 
         if let Err(err) = self.dal.save_telemetry_navigation(vehicle_id, navigation).await {
             log::error!("Save navigation telemetry error: {}", err);
@@ -185,6 +196,8 @@ impl handler::Handler {
         navigation.target_position.altitude = data.alt;
         navigation.target_position.frame = GeodeticFrame::Wgs84AboveSeaLevel;
 
+        self.track_go_to(mav_id, &vehicle_id, &navigation).await;
+
         if let Err(err) = self.dal.save_telemetry_navigation(vehicle_id, navigation).await {
             log::error!("Save navigation telemetry error: {}", err);
         }
@@ -0,0 +1,67 @@
+use mavlink::common::*;
+
+use crate::models::{spatial::Geodetic, vehicles::VehicleId};
+use super::super::protocol::terrain as protocol;
+use super::handler;
+
+const TERRAIN_DATA_PER_CYCLE: usize = 8; // don't flood the link with the whole requested grid at once
+
+impl handler::Handler {
+    pub async fn handle_terrain_request(&mut self, mav_id: u8, data: &TERRAIN_REQUEST_DATA) {
+        if self.vehicle_id_from_mav_id(&mav_id).is_none() {
+            return;
+        }
+        let request = data.clone();
+        let messages = match self.dal.terrain.query(move |terrain| protocol::encode_terrain_response(
+            &request, |latitude, longitude| terrain.elevation(latitude, longitude))).await {
+            Ok(messages) => messages,
+            Err(err) => {
+                log::error!("Terrain query error: {}", err);
+                return;
+            }
+        };
+        if messages.is_empty() {
+            log::warn!("No local terrain for MAVLink {} request at {}, {}", mav_id, data.lat, data.lon);
+            return;
+        }
+
+        // Vehicle repeats requests until it gets the data, so queued blocks are not duplicated
+        for message in messages {
+            if !self.terrain_messages.contains(&message) {
+                self.terrain_messages.push_back(message);
+            }
+        }
+    }
+
+    pub async fn handle_terrain_report(&mut self, mav_id: u8, data: &TERRAIN_REPORT_DATA) {
+        let vehicle_id = match self.vehicle_id_from_mav_id(&mav_id) {
+            Some(vehicle_id) => vehicle_id,
+            None => return
+        };
+        let mut report = protocol::decode_terrain_report(&vehicle_id, data);
+        let (latitude, longitude) = (report.latitude, report.longitude);
+        report.local_elevation = match self.dal.terrain.query(move |terrain| terrain.elevation(latitude, longitude)).await {
+            Ok(elevation) => elevation,
+            Err(err) => {
+                log::error!("Terrain query error: {}", err);
+                None
+            }
+        };
+
+        if let Err(err) = self.dal.save_terrain_report(report).await {
+            log::error!("Save terrain report error: {}", err);
+        }
+    }
+
+    pub async fn check_terrain(&mut self, vehicle_id: VehicleId, position: Geodetic) {
+        match self.mav_id_from_vehicle_id(&vehicle_id) {
+            Some(_) => self.terrain_messages.push_back(protocol::terrain_check(&position)),
+            None => log::warn!("No MAVLink vehicle for terrain check: {}", vehicle_id)
+        }
+    }
+
+    pub fn collect_terrain_messages(&mut self) -> Vec<MavMessage> {
+        let count = self.terrain_messages.len().min(TERRAIN_DATA_PER_CYCLE);
+        self.terrain_messages.drain(..count).collect()
+    }
+}
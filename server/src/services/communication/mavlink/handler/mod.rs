@@ -1,5 +1,23 @@
 pub mod handler;
 pub mod handler_heartbeat;
+pub mod handler_payloads;
+pub mod handler_gimbal;
+#[cfg(test)]
+mod handler_gimbal_test;
+pub mod handler_camera;
+pub mod handler_status_text;
 pub mod handler_telemetry;
+pub mod handler_battery;
+pub mod handler_engine;
+#[cfg(test)]
+mod handler_engine_test;
 pub mod handler_commands;
+#[cfg(test)]
+mod handler_commands_test;
+pub mod handler_navigation;
+#[cfg(test)]
+mod handler_navigation_test;
 pub mod handler_missions;
+pub mod handler_terrain;
+#[cfg(test)]
+pub mod test_utils;
@@ -0,0 +1,12 @@
+use crate::bus::bus::EventBus;
+use crate::dal::test_utils::in_memory_dao;
+use crate::dal::dal::Dal;
+use crate::models::events::{ClientEvent, ServerEvent};
+use super::handler::Handler;
+
+// Handler over an empty in-memory database, with no MAVLink systems known yet
+pub async fn in_memory_handler() -> Handler {
+    let server_bus = EventBus::<ServerEvent>::new();
+    let client_bus = EventBus::<ClientEvent>::new();
+    Handler::new(Dal::new(in_memory_dao().await, server_bus.clone()), server_bus, client_bus.subscribe())
+}
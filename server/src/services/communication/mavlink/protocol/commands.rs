@@ -1,7 +1,10 @@
 use mavlink::common::*;
 
-use crate::models::commands::{Calibration, Command};
+use crate::models::commands::{Calibration, Command, ParachuteAction, VtolTarget};
 use crate::models::spatial::Geodetic;
+use crate::models::telemetry::CameraMode;
+
+const CAMERA_INFORMATION_MESSAGE_ID: u32 = 259;
 
 fn arm_disarm(mav_id: u8, arm: bool, attempt: u8) -> MavMessage {
     log::info!("Mav: {} Arm/Disarm: {}", mav_id, arm);
@@ -111,23 +114,58 @@ pub fn set_waypoint(mav_id: u8, wp: u16, attempt: u8) -> MavMessage {
     })
 }
 
-fn nav_to(mav_id: u8, position: Geodetic) -> MavMessage {
-    log::info!("Mav: {} Nav to: {:?}", mav_id, position);
+fn reposition(mav_id: u8, position: Geodetic, loiter_radius: Option<f32>, ground_speed: Option<f32>, change_mode: bool) -> MavMessage {
+    log::info!("Mav: {} Reposition to: {:?}", mav_id, position);
     let (frame, x, y, z) = position.to_mavlink();
     MavMessage::COMMAND_INT(COMMAND_INT_DATA{
-        param1: -1.0, // Ground speed in m/s
-        param2: 0.0, // TODO: MAV_DO_REPOSITION_FLAGS_CHANGE_MODE
-        param3: 0.0, // TODO: MAV_DO_REPOSITION_FLAGS_CONTINUE
-        param4: 0.0, // TODO: Yaw heading. NaN to use the current system yaw heading mode (e.g. yaw towards next waypoint, yaw to home, etc.). For planes indicates loiter direction (0: clockwise, 1: counter clockwise)
-        command: mavlink::common::MavCmd::MAV_CMD_DO_REPOSITION,
-        current: 2, // guided
+        param1: ground_speed.unwrap_or(-1.0), // Ground speed in m/s, -1 for default
+        param2: if change_mode { MavDoRepositionFlags::MAV_DO_REPOSITION_FLAGS_CHANGE_MODE as i32 as f32 } else { 0.0 },
+        param3: loiter_radius.unwrap_or(0.0), // Loiter radius for planes, 0 for default
+        param4: f32::NAN, // Use the current system yaw heading mode
+        command: MavCmd::MAV_CMD_DO_REPOSITION,
+        current: 0,
         autocontinue: 0,
         x,
         y,
         z,
         frame,
         target_system: mav_id,
-        target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+        target_component: MavComponent::MAV_COMP_ID_ALL as u8,
+    })
+}
+
+fn set_position_target(mav_id: u8, position: Geodetic) -> MavMessage {
+    log::info!("Mav: {} Set position target: {:?}", mav_id, position);
+    let (frame, lat_int, lon_int, alt) = position.to_mavlink();
+    MavMessage::SET_POSITION_TARGET_GLOBAL_INT(SET_POSITION_TARGET_GLOBAL_INT_DATA{
+        time_boot_ms: 0,
+        lat_int,
+        lon_int,
+        alt,
+        vx: 0.0,
+        vy: 0.0,
+        vz: 0.0,
+        afx: 0.0,
+        afy: 0.0,
+        afz: 0.0,
+        yaw: 0.0,
+        yaw_rate: 0.0,
+        // Position only
+        type_mask: PositionTargetTypemask::POSITION_TARGET_TYPEMASK_VX_IGNORE |
+            PositionTargetTypemask::POSITION_TARGET_TYPEMASK_VY_IGNORE |
+            PositionTargetTypemask::POSITION_TARGET_TYPEMASK_VZ_IGNORE |
+            PositionTargetTypemask::POSITION_TARGET_TYPEMASK_AX_IGNORE |
+            PositionTargetTypemask::POSITION_TARGET_TYPEMASK_AY_IGNORE |
+            PositionTargetTypemask::POSITION_TARGET_TYPEMASK_AZ_IGNORE |
+            PositionTargetTypemask::POSITION_TARGET_TYPEMASK_YAW_IGNORE |
+            PositionTargetTypemask::POSITION_TARGET_TYPEMASK_YAW_RATE_IGNORE,
+        target_system: mav_id,
+        target_component: MavComponent::MAV_COMP_ID_ALL as u8,
+        coordinate_frame: match frame {
+            MavFrame::MAV_FRAME_GLOBAL_RELATIVE_ALT => MavFrame::MAV_FRAME_GLOBAL_RELATIVE_ALT_INT,
+            MavFrame::MAV_FRAME_GLOBAL_TERRAIN_ALT => MavFrame::MAV_FRAME_GLOBAL_TERRAIN_ALT_INT,
+            _ => MavFrame::MAV_FRAME_GLOBAL_INT,
+        },
     })
 }
 
@@ -185,6 +223,98 @@ fn go_around(mav_id: u8, attempt: u8) -> MavMessage {
     })
 }
 
+fn return_to_launch(mav_id: u8, attempt: u8) -> MavMessage {
+    log::info!("Mav: {} Return To Launch", mav_id);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: 0.0,
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_NAV_RETURN_TO_LAUNCH,
+        target_system: mav_id,
+        target_component: mavlink::common::MavComponent::MAV_COMP_ID_ALL as u8,
+        confirmation: attempt,
+    })
+}
+
+fn parachute(mav_id: u8, action: ParachuteAction, attempt: u8) -> MavMessage {
+    log::info!("Mav: {} Parachute: {:?}", mav_id, action);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: match action {
+            ParachuteAction::Disable => mavlink::common::ParachuteAction::PARACHUTE_DISABLE,
+            ParachuteAction::Enable => mavlink::common::ParachuteAction::PARACHUTE_ENABLE,
+            ParachuteAction::Release => mavlink::common::ParachuteAction::PARACHUTE_RELEASE,
+        } as i32 as f32,
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_DO_PARACHUTE,
+        target_system: mav_id,
+        target_component: mavlink::common::MavComponent::MAV_COMP_ID_ALL as u8,
+        confirmation: attempt,
+    })
+}
+
+fn flight_termination(mav_id: u8, attempt: u8) -> MavMessage {
+    log::info!("Mav: {} Flight Termination", mav_id);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: 1.0,
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_DO_FLIGHTTERMINATION,
+        target_system: mav_id,
+        target_component: mavlink::common::MavComponent::MAV_COMP_ID_ALL as u8,
+        confirmation: attempt,
+    })
+}
+
+fn engine_control(mav_id: u8, start: bool, attempt: u8) -> MavMessage {
+    log::info!("Mav: {} Engine Control: {}", mav_id, start);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: start as i32 as f32,
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_DO_ENGINE_CONTROL,
+        target_system: mav_id,
+        target_component: mavlink::common::MavComponent::MAV_COMP_ID_ALL as u8,
+        confirmation: attempt,
+    })
+}
+
+fn vtol_transition(mav_id: u8, to: VtolTarget, attempt: u8) -> MavMessage {
+    log::info!("Mav: {} VTOL Transition: {:?}", mav_id, to);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: match to {
+            VtolTarget::Multicopter => MavVtolState::MAV_VTOL_STATE_MC,
+            VtolTarget::FixedWing => MavVtolState::MAV_VTOL_STATE_FW,
+        } as i32 as f32,
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_DO_VTOL_TRANSITION,
+        target_system: mav_id,
+        target_component: mavlink::common::MavComponent::MAV_COMP_ID_ALL as u8,
+        confirmation: attempt,
+    })
+}
+
 fn set_servo(mav_id: u8, channel: u16, value: u16, attempt: u8) -> MavMessage {
     log::info!("Mav: {} Set Servo: {} to {}", mav_id, channel, value);
     MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
@@ -218,6 +348,177 @@ fn override_servos(mav_id: u8, servos: std::collections::BTreeMap<u16, u16>) ->
     })
 }
 
+fn camera_trigger(mav_id: u8, comp_id: u8, attempt: u8) -> MavMessage {
+    log::info!("Mav: {}:{} Camera Trigger", mav_id, comp_id);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: 0.0,
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 1.0, // Shoot command
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_DO_DIGICAM_CONTROL,
+        target_system: mav_id,
+        target_component: comp_id,
+        confirmation: attempt,
+    })
+}
+
+fn camera_record(mav_id: u8, comp_id: u8, record: bool, attempt: u8) -> MavMessage {
+    log::info!("Mav: {}:{} Camera Record: {}", mav_id, comp_id, record);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: 0.0, // All video streams
+        param2: 0.0, // No CAMERA_CAPTURE_STATUS streaming
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: camera_record_cmd(record),
+        target_system: mav_id,
+        target_component: comp_id,
+        confirmation: attempt,
+    })
+}
+
+fn camera_record_cmd(record: bool) -> MavCmd {
+    if record {
+        MavCmd::MAV_CMD_VIDEO_START_CAPTURE
+    } else {
+        MavCmd::MAV_CMD_VIDEO_STOP_CAPTURE
+    }
+}
+
+fn image_start_capture(mav_id: u8, comp_id: u8, interval: f32, total_images: u32, attempt: u8) -> MavMessage {
+    log::info!("Mav: {}:{} Image Start Capture, interval: {}, total: {}", mav_id, comp_id, interval, total_images);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: 0.0, // All cameras of the component
+        param2: interval,
+        param3: total_images as f32, // 0 for capturing until stop
+        param4: 0.0, // No sequence number for the interval capture
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_IMAGE_START_CAPTURE,
+        target_system: mav_id,
+        target_component: comp_id,
+        confirmation: attempt,
+    })
+}
+
+fn image_stop_capture(mav_id: u8, comp_id: u8, attempt: u8) -> MavMessage {
+    log::info!("Mav: {}:{} Image Stop Capture", mav_id, comp_id);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: 0.0, // All cameras of the component
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_IMAGE_STOP_CAPTURE,
+        target_system: mav_id,
+        target_component: comp_id,
+        confirmation: attempt,
+    })
+}
+
+fn set_camera_mode(mav_id: u8, comp_id: u8, mode: CameraMode, attempt: u8) -> Option<MavMessage> {
+    let mav_mode = match mode {
+        CameraMode::Image => mavlink::common::CameraMode::CAMERA_MODE_IMAGE,
+        CameraMode::Video => mavlink::common::CameraMode::CAMERA_MODE_VIDEO,
+        CameraMode::Survey => mavlink::common::CameraMode::CAMERA_MODE_IMAGE_SURVEY,
+        CameraMode::Unknown => return None
+    };
+
+    log::info!("Mav: {}:{} Set Camera Mode: {:?}", mav_id, comp_id, mode);
+    Some(MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: 0.0,
+        param2: mav_mode as u32 as f32,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_SET_CAMERA_MODE,
+        target_system: mav_id,
+        target_component: comp_id,
+        confirmation: attempt,
+    }))
+}
+
+fn request_message(mav_id: u8, comp_id: u8, message_id: u32, attempt: u8) -> MavMessage {
+    log::info!("Mav: {}:{} Request Message: {}", mav_id, comp_id, message_id);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: message_id as f32,
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_REQUEST_MESSAGE,
+        target_system: mav_id,
+        target_component: comp_id,
+        confirmation: attempt,
+    })
+}
+
+fn gimbal_manager_pitch_yaw(mav_id: u8, comp_id: u8, angles: (f32, f32), rates: (f32, f32), flags: u32, attempt: u8) -> MavMessage {
+    log::info!("Mav: {}:{} Gimbal Pitch/Yaw: {:?}, rates: {:?}, flags: {:?}", mav_id, comp_id, angles, rates, flags);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: angles.0, // Pitch angle, NaN to ignore
+        param2: angles.1, // Yaw angle, NaN to ignore
+        param3: rates.0, // Pitch rate, NaN to ignore
+        param4: rates.1, // Yaw rate, NaN to ignore
+        param5: flags as f32, // GIMBAL_MANAGER_FLAGS
+        param6: 0.0,
+        param7: 0.0, // Gimbal device id, 0 for all gimbals of the component
+        command: MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW,
+        target_system: mav_id,
+        target_component: comp_id,
+        confirmation: attempt,
+    })
+}
+
+fn set_roi_location(mav_id: u8, comp_id: u8, position: Geodetic) -> MavMessage {
+    log::info!("Mav: {}:{} Set ROI: {:?}", mav_id, comp_id, position);
+    let (frame, x, y, z) = position.to_mavlink();
+    MavMessage::COMMAND_INT(COMMAND_INT_DATA{
+        param1: 0.0, // Gimbal device id, 0 for all gimbals of the component
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        command: MavCmd::MAV_CMD_DO_SET_ROI_LOCATION,
+        current: 0,
+        autocontinue: 0,
+        x,
+        y,
+        z,
+        frame,
+        target_system: mav_id,
+        target_component: comp_id,
+    })
+}
+
+fn set_roi_none(mav_id: u8, comp_id: u8, attempt: u8) -> MavMessage {
+    log::info!("Mav: {}:{} Reset ROI", mav_id, comp_id);
+    MavMessage::COMMAND_LONG(COMMAND_LONG_DATA{
+        param1: 0.0, // Gimbal device id, 0 for all gimbals of the component
+        param2: 0.0,
+        param3: 0.0,
+        param4: 0.0,
+        param5: 0.0,
+        param6: 0.0,
+        param7: 0.0,
+        command: MavCmd::MAV_CMD_DO_SET_ROI_NONE,
+        target_system: mav_id,
+        target_component: comp_id,
+        confirmation: attempt,
+    })
+}
+
 pub struct EncodedCommand {
     pub message: MavMessage,
     pub ack_cmd: Option<MavCmd>,
@@ -230,6 +531,33 @@ pub fn encode_set_mode(mode: u32, mav_id: u8, attempt: u8) -> EncodedCommand {
     }
 }
 
+// ArduPilot takes plain position targets in Guided, but speed and loiter radius only come with DO_REPOSITION
+pub fn uses_position_target(autopilot: MavAutopilot, loiter_radius: Option<f32>, ground_speed: Option<f32>) -> bool {
+    autopilot == MavAutopilot::MAV_AUTOPILOT_ARDUPILOTMEGA && loiter_radius.is_none() && ground_speed.is_none()
+}
+
+// Position targets are not acknowledged, execution is confirmed by POSITION_TARGET_GLOBAL_INT feedback
+pub fn encode_nav_to(
+    autopilot: MavAutopilot,
+    position: Geodetic,
+    loiter_radius: Option<f32>,
+    ground_speed: Option<f32>,
+    switch_to_guided: bool,
+    mav_id: u8
+) -> EncodedCommand {
+    if uses_position_target(autopilot, loiter_radius, ground_speed) {
+        EncodedCommand {
+            message: set_position_target(mav_id, position),
+            ack_cmd: None,
+        }
+    } else {
+        EncodedCommand {
+            message: reposition(mav_id, position, loiter_radius, ground_speed, switch_to_guided),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_REPOSITION),
+        }
+    }
+}
+
 pub fn encode_command(command: Command, mav_id: u8, attempt: u8) -> Option<EncodedCommand> {
     match command {
         Command::ArmDisarm { arm } => Some(EncodedCommand {
@@ -248,10 +576,9 @@ pub fn encode_command(command: Command, mav_id: u8, attempt: u8) -> Option<Encod
             message: set_waypoint(mav_id, wpt, attempt),
             ack_cmd: Some(MavCmd::MAV_CMD_DO_SET_MISSION_CURRENT),
         }),
-        Command::NavTo { position } => Some(EncodedCommand {
-            message: nav_to(mav_id, position),
-            ack_cmd: Some(MavCmd::MAV_CMD_NAV_WAYPOINT),
-        }),
+        Command::NavTo { position, loiter_radius, ground_speed, switch_to_guided } => Some(
+            encode_nav_to(MavAutopilot::MAV_AUTOPILOT_GENERIC, position, loiter_radius, ground_speed, switch_to_guided, mav_id)
+        ),
         Command::Takeoff { altitude } => Some(EncodedCommand {
             message: takeoff(mav_id, altitude, attempt),
             ack_cmd: Some(MavCmd::MAV_CMD_NAV_TAKEOFF),
@@ -264,6 +591,26 @@ pub fn encode_command(command: Command, mav_id: u8, attempt: u8) -> Option<Encod
             message: go_around(mav_id, attempt),
             ack_cmd: Some(MavCmd::MAV_CMD_DO_GO_AROUND),
         }),
+        Command::ReturnToLaunch {} => Some(EncodedCommand {
+            message: return_to_launch(mav_id, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_NAV_RETURN_TO_LAUNCH),
+        }),
+        Command::Parachute { action } => Some(EncodedCommand {
+            message: parachute(mav_id, action, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_PARACHUTE),
+        }),
+        Command::FlightTermination {} => Some(EncodedCommand {
+            message: flight_termination(mav_id, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_FLIGHTTERMINATION),
+        }),
+        Command::EngineControl { start } => Some(EncodedCommand {
+            message: engine_control(mav_id, start, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_ENGINE_CONTROL),
+        }),
+        Command::VtolTransition { to } => Some(EncodedCommand {
+            message: vtol_transition(mav_id, to, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_VTOL_TRANSITION),
+        }),
         Command::SetServo { channel, value } => Some(EncodedCommand {
             message: set_servo(mav_id, channel, value, attempt),
             ack_cmd: Some(MavCmd::MAV_CMD_DO_SET_SERVO),
@@ -272,6 +619,116 @@ pub fn encode_command(command: Command, mav_id: u8, attempt: u8) -> Option<Encod
             message: override_servos(mav_id, servos),
             ack_cmd: None,
         }),
+        Command::CameraTrigger {} => Some(EncodedCommand {
+            message: camera_trigger(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_DIGICAM_CONTROL),
+        }),
+        Command::CameraRecord { record } => Some(EncodedCommand {
+            message: camera_record(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, record, attempt),
+            ack_cmd: Some(camera_record_cmd(record)),
+        }),
+        Command::StartImageCapture { interval, total_images } => Some(EncodedCommand {
+            message: image_start_capture(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, interval, total_images, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_IMAGE_START_CAPTURE),
+        }),
+        Command::StopImageCapture {} => Some(EncodedCommand {
+            message: image_stop_capture(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_IMAGE_STOP_CAPTURE),
+        }),
+        Command::SetCameraMode { mode } => set_camera_mode(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, mode, attempt).map(|message| EncodedCommand {
+            message,
+            ack_cmd: Some(MavCmd::MAV_CMD_SET_CAMERA_MODE),
+        }),
+        Command::RequestCameraInformation {} => Some(EncodedCommand {
+            message: request_message(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, CAMERA_INFORMATION_MESSAGE_ID, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_REQUEST_MESSAGE),
+        }),
+        Command::SetGimbalPitchYaw { pitch, yaw } => Some(EncodedCommand {
+            message: gimbal_manager_pitch_yaw(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, (pitch, yaw), (f32::NAN, f32::NAN),
+                0, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW),
+        }),
+        Command::SetGimbalRates { pitch_rate, yaw_rate } => Some(EncodedCommand {
+            message: gimbal_manager_pitch_yaw(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, (f32::NAN, f32::NAN), (pitch_rate, yaw_rate),
+                0, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW),
+        }),
+        Command::GimbalRetract {} => Some(EncodedCommand {
+            message: gimbal_manager_pitch_yaw(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, (f32::NAN, f32::NAN), (f32::NAN, f32::NAN),
+                GimbalManagerFlags::GIMBAL_MANAGER_FLAGS_RETRACT as u32, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW),
+        }),
+        Command::GimbalNeutral {} => Some(EncodedCommand {
+            message: gimbal_manager_pitch_yaw(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, (f32::NAN, f32::NAN), (f32::NAN, f32::NAN),
+                GimbalManagerFlags::GIMBAL_MANAGER_FLAGS_NEUTRAL as u32, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW),
+        }),
+        Command::SetGimbalRoi { position } => Some(EncodedCommand {
+            message: set_roi_location(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, position),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_SET_ROI_LOCATION),
+        }),
+        Command::ResetGimbalRoi {} => Some(EncodedCommand {
+            message: set_roi_none(mav_id, MavComponent::MAV_COMP_ID_ALL as u8, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_SET_ROI_NONE),
+        }),
+        _ => None
+    }
+}
+
+pub fn encode_payload_command(command: Command, mav_id: u8, comp_id: u8, attempt: u8) -> Option<EncodedCommand> {
+    match command {
+        Command::CameraTrigger {} => Some(EncodedCommand {
+            message: camera_trigger(mav_id, comp_id, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_DIGICAM_CONTROL),
+        }),
+        Command::CameraRecord { record } => Some(EncodedCommand {
+            message: camera_record(mav_id, comp_id, record, attempt),
+            ack_cmd: Some(camera_record_cmd(record)),
+        }),
+        Command::StartImageCapture { interval, total_images } => Some(EncodedCommand {
+            message: image_start_capture(mav_id, comp_id, interval, total_images, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_IMAGE_START_CAPTURE),
+        }),
+        Command::StopImageCapture {} => Some(EncodedCommand {
+            message: image_stop_capture(mav_id, comp_id, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_IMAGE_STOP_CAPTURE),
+        }),
+        Command::SetCameraMode { mode } => set_camera_mode(mav_id, comp_id, mode, attempt).map(|message| EncodedCommand {
+            message,
+            ack_cmd: Some(MavCmd::MAV_CMD_SET_CAMERA_MODE),
+        }),
+        Command::RequestCameraInformation {} => Some(EncodedCommand {
+            message: request_message(mav_id, comp_id, CAMERA_INFORMATION_MESSAGE_ID, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_REQUEST_MESSAGE),
+        }),
+        Command::SetGimbalPitchYaw { pitch, yaw } => Some(EncodedCommand {
+            message: gimbal_manager_pitch_yaw(mav_id, comp_id, (pitch, yaw), (f32::NAN, f32::NAN),
+                0, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW),
+        }),
+        Command::SetGimbalRates { pitch_rate, yaw_rate } => Some(EncodedCommand {
+            message: gimbal_manager_pitch_yaw(mav_id, comp_id, (f32::NAN, f32::NAN), (pitch_rate, yaw_rate),
+                0, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW),
+        }),
+        Command::GimbalRetract {} => Some(EncodedCommand {
+            message: gimbal_manager_pitch_yaw(mav_id, comp_id, (f32::NAN, f32::NAN), (f32::NAN, f32::NAN),
+                GimbalManagerFlags::GIMBAL_MANAGER_FLAGS_RETRACT as u32, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW),
+        }),
+        Command::GimbalNeutral {} => Some(EncodedCommand {
+            message: gimbal_manager_pitch_yaw(mav_id, comp_id, (f32::NAN, f32::NAN), (f32::NAN, f32::NAN),
+                GimbalManagerFlags::GIMBAL_MANAGER_FLAGS_NEUTRAL as u32, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW),
+        }),
+        Command::SetGimbalRoi { position } => Some(EncodedCommand {
+            message: set_roi_location(mav_id, comp_id, position),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_SET_ROI_LOCATION),
+        }),
+        Command::ResetGimbalRoi {} => Some(EncodedCommand {
+            message: set_roi_none(mav_id, comp_id, attempt),
+            ack_cmd: Some(MavCmd::MAV_CMD_DO_SET_ROI_NONE),
+        }),
         _ => None
     }
 }
@@ -0,0 +1,146 @@
+use mavlink::common::*;
+use test_case::test_case;
+
+use crate::models::commands::Command;
+use crate::models::spatial::Geodetic;
+use crate::models::telemetry::CameraMode;
+use super::commands::*;
+
+fn command_long(message: &MavMessage) -> &COMMAND_LONG_DATA {
+    match message {
+        MavMessage::COMMAND_LONG(data) => data,
+        message => panic!("Unexpected message {:?}", message)
+    }
+}
+
+#[test_case(Command::CameraTrigger {}, MavCmd::MAV_CMD_DO_DIGICAM_CONTROL; "camera trigger")]
+#[test_case(Command::StopImageCapture {}, MavCmd::MAV_CMD_IMAGE_STOP_CAPTURE; "stop capture")]
+#[test_case(Command::GimbalNeutral {}, MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW; "gimbal neutral")]
+fn test_payload_command_target(command: Command, expected: MavCmd) {
+    let encoded = encode_payload_command(command, 1, 100, 2).expect("Payload command is not encoded");
+    let data = command_long(&encoded.message);
+
+    assert_eq!(data.command, expected);
+    assert_eq!(encoded.ack_cmd, Some(expected));
+    assert_eq!(data.target_system, 1);
+    assert_eq!(data.target_component, 100);
+    assert_eq!(data.confirmation, 2);
+}
+
+#[test_case(Command::ArmDisarm { arm: true }; "arm")]
+#[test_case(Command::ReturnToLaunch {}; "return to launch")]
+fn test_vehicle_command_to_payload(command: Command) {
+    assert!(encode_payload_command(command, 1, 100, 0).is_none());
+}
+
+fn gimbal_params(command: Command) -> [f32; 5] {
+    let encoded = encode_payload_command(command, 1, 154, 0).expect("Gimbal command is not encoded");
+    let data = command_long(&encoded.message);
+    [data.param1, data.param2, data.param3, data.param4, data.param5]
+}
+
+#[test]
+fn test_gimbal_pitch_yaw() {
+    let params = gimbal_params(Command::SetGimbalPitchYaw { pitch: -30.0, yaw: 45.0 });
+    assert_eq!(params[..2], [-30.0, 45.0]);
+    assert!(params[2].is_nan() && params[3].is_nan());
+    assert_eq!(params[4], 0.0);
+}
+
+// Vehicle gimbal commands go to the gimbal manager of any component, like the payload ones
+#[test_case(Command::SetGimbalPitchYaw { pitch: -30.0, yaw: 45.0 }; "pitch yaw")]
+#[test_case(Command::SetGimbalRates { pitch_rate: 5.0, yaw_rate: -10.0 }; "rates")]
+#[test_case(Command::GimbalRetract {}; "retract")]
+#[test_case(Command::GimbalNeutral {}; "neutral")]
+fn test_vehicle_gimbal_command(command: Command) {
+    let vehicle = encode_command(command.clone(), 1, 0).expect("Vehicle gimbal command is not encoded");
+    let payload = encode_payload_command(command, 1, MavComponent::MAV_COMP_ID_ALL as u8, 0)
+        .expect("Payload gimbal command is not encoded");
+
+    assert_eq!(vehicle.ack_cmd, Some(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW));
+    let (vehicle, payload) = (command_long(&vehicle.message), command_long(&payload.message));
+    assert_eq!(vehicle.command, MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW);
+    assert_eq!(vehicle.target_component, MavComponent::MAV_COMP_ID_ALL as u8);
+    let params = |data: &COMMAND_LONG_DATA| [data.param1, data.param2, data.param3, data.param4, data.param5]
+        .map(|param| if param.is_nan() { None } else { Some(param) });
+    assert_eq!(params(vehicle), params(payload));
+}
+
+#[test]
+fn test_gimbal_rates() {
+    let params = gimbal_params(Command::SetGimbalRates { pitch_rate: 5.0, yaw_rate: -10.0 });
+    assert!(params[0].is_nan() && params[1].is_nan());
+    assert_eq!(params[2..4], [5.0, -10.0]);
+}
+
+#[test]
+fn test_gimbal_retract() {
+    let params = gimbal_params(Command::GimbalRetract {});
+    assert!(params[..4].iter().all(|param| param.is_nan()));
+    assert_eq!(params[4], GimbalManagerFlags::GIMBAL_MANAGER_FLAGS_RETRACT as u32 as f32);
+}
+
+#[test]
+fn test_image_start_capture() {
+    let encoded = encode_payload_command(Command::StartImageCapture { interval: 2.5, total_images: 10 }, 1, 100, 0)
+        .expect("Capture command is not encoded");
+    let data = command_long(&encoded.message);
+    assert_eq!(data.command, MavCmd::MAV_CMD_IMAGE_START_CAPTURE);
+    assert_eq!([data.param1, data.param2, data.param3], [0.0, 2.5, 10.0]);
+}
+
+#[test_case(true, MavCmd::MAV_CMD_VIDEO_START_CAPTURE; "start")]
+#[test_case(false, MavCmd::MAV_CMD_VIDEO_STOP_CAPTURE; "stop")]
+fn test_camera_record(record: bool, expected: MavCmd) {
+    let encoded = encode_payload_command(Command::CameraRecord { record }, 1, 100, 0)
+        .expect("Record command is not encoded");
+    assert_eq!(command_long(&encoded.message).command, expected);
+    assert_eq!(encoded.ack_cmd, Some(expected));
+}
+
+#[test_case(CameraMode::Image, Some(mavlink::common::CameraMode::CAMERA_MODE_IMAGE); "image")]
+#[test_case(CameraMode::Video, Some(mavlink::common::CameraMode::CAMERA_MODE_VIDEO); "video")]
+#[test_case(CameraMode::Survey, Some(mavlink::common::CameraMode::CAMERA_MODE_IMAGE_SURVEY); "survey")]
+#[test_case(CameraMode::Unknown, None; "unknown")]
+fn test_set_camera_mode(mode: CameraMode, expected: Option<mavlink::common::CameraMode>) {
+    let encoded = encode_payload_command(Command::SetCameraMode { mode }, 1, 100, 0);
+    let param = encoded.map(|encoded| command_long(&encoded.message).param2);
+    assert_eq!(param, expected.map(|mode| mode as u32 as f32));
+}
+
+#[test]
+fn test_request_camera_information() {
+    let encoded = encode_payload_command(Command::RequestCameraInformation {}, 1, 100, 0)
+        .expect("Request command is not encoded");
+    let data = command_long(&encoded.message);
+    assert_eq!(data.command, MavCmd::MAV_CMD_REQUEST_MESSAGE);
+    assert_eq!(data.param1, 259.0);
+}
+
+#[test_case(true, 1.0; "start")]
+#[test_case(false, 0.0; "stop")]
+fn test_engine_control(start: bool, expected: f32) {
+    let encoded = encode_command(Command::EngineControl { start }, 1, 0).expect("Engine command is not encoded");
+    let data = command_long(&encoded.message);
+    assert_eq!(data.command, MavCmd::MAV_CMD_DO_ENGINE_CONTROL);
+    assert_eq!(encoded.ack_cmd, Some(MavCmd::MAV_CMD_DO_ENGINE_CONTROL));
+    assert_eq!(data.param1, expected);
+    assert_eq!(data.target_system, 1);
+}
+
+#[test_case(MavAutopilot::MAV_AUTOPILOT_ARDUPILOTMEGA, None, None, true; "ardupilot plain target")]
+#[test_case(MavAutopilot::MAV_AUTOPILOT_ARDUPILOTMEGA, Some(80.0), None, false; "ardupilot loiter radius")]
+#[test_case(MavAutopilot::MAV_AUTOPILOT_ARDUPILOTMEGA, None, Some(15.0), false; "ardupilot ground speed")]
+#[test_case(MavAutopilot::MAV_AUTOPILOT_PX4, None, None, false; "px4")]
+fn test_nav_to_dispatch(autopilot: MavAutopilot, loiter_radius: Option<f32>, ground_speed: Option<f32>, position_target: bool) {
+    assert_eq!(uses_position_target(autopilot, loiter_radius, ground_speed), position_target);
+
+    let encoded = encode_nav_to(autopilot, Geodetic::default(), loiter_radius, ground_speed, true, 1);
+    if position_target {
+        assert!(matches!(encoded.message, MavMessage::SET_POSITION_TARGET_GLOBAL_INT(_)));
+        assert_eq!(encoded.ack_cmd, None);
+    } else {
+        assert!(matches!(&encoded.message, MavMessage::COMMAND_INT(data) if data.command == MavCmd::MAV_CMD_DO_REPOSITION));
+        assert_eq!(encoded.ack_cmd, Some(MavCmd::MAV_CMD_DO_REPOSITION));
+    }
+}
@@ -211,6 +211,218 @@ pub fn send_mission_item(mav_id: &u8, item: &MissionRouteItem, seq: u16) -> Opti
                 current: 0,
                 autocontinue: 1
             }));
+        },
+        MissionRouteItem::DoSetRoi { position } => {
+            let (frame, x, y, z) = position.to_mavlink();
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_DO_SET_ROI_LOCATION,
+                frame,
+                x,
+                y,
+                z,
+                param1: 0.0,
+                param2: 0.0,
+                param3: 0.0,
+                param4: 0.0,
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::RoiNone {} => {
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_DO_SET_ROI_NONE,
+                frame: MavFrame::MAV_FRAME_GLOBAL,
+                x: 0,
+                y: 0,
+                z: 0.0,
+                param1: 0.0,
+                param2: 0.0,
+                param3: 0.0,
+                param4: 0.0,
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::VtolTakeoff { position, yaw } => {
+            let (frame, x, y, z) = position.to_mavlink();
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_NAV_VTOL_TAKEOFF,
+                frame,
+                x,
+                y,
+                z,
+                param1: 0.0,
+                param2: 0.0,
+                param3: 0.0,
+                param4: yaw_to_param(*yaw),
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::VtolLand { position, approach_altitude, yaw } => {
+            let (frame, x, y, z) = position.to_mavlink();
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_NAV_VTOL_LAND,
+                frame,
+                x,
+                y,
+                z,
+                param1: 0.0,
+                param2: 0.0,
+                param3: approach_altitude.unwrap_or(f32::NAN),
+                param4: yaw_to_param(*yaw),
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::ReturnToLaunch {} => {
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_NAV_RETURN_TO_LAUNCH,
+                frame: MavFrame::MAV_FRAME_GLOBAL,
+                x: 0,
+                y: 0,
+                z: 0.0,
+                param1: 0.0,
+                param2: 0.0,
+                param3: 0.0,
+                param4: 0.0,
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::Delay { seconds } => {
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_NAV_DELAY,
+                frame: MavFrame::MAV_FRAME_GLOBAL,
+                x: 0,
+                y: 0,
+                z: 0.0,
+                param1: *seconds,
+                param2: -1.0,
+                param3: -1.0,
+                param4: -1.0,
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::DoGimbalControl { pitch, roll, yaw } => {
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_DO_MOUNT_CONTROL,
+                frame: MavFrame::MAV_FRAME_GLOBAL,
+                x: 0,
+                y: 0,
+                z: MavMountMode::MAV_MOUNT_MODE_MAVLINK_TARGETING as i32 as f32,
+                param1: *pitch,
+                param2: *roll,
+                param3: *yaw,
+                param4: 0.0,
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::DoChangeSpeed { speed_type, speed, throttle } => {
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_DO_CHANGE_SPEED,
+                frame: MavFrame::MAV_FRAME_GLOBAL,
+                x: 0,
+                y: 0,
+                z: 0.0,
+                param1: speed_type_to_param(speed_type),
+                param2: *speed,
+                param3: throttle.unwrap_or(-1.0),
+                param4: 0.0,
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::DoJump { target, repeat } => {
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_DO_JUMP,
+                frame: MavFrame::MAV_FRAME_GLOBAL,
+                x: 0,
+                y: 0,
+                z: 0.0,
+                param1: target.saturating_add(1) as f32,
+                param2: *repeat as f32,
+                param3: 0.0,
+                param4: 0.0,
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::ConditionYaw { angle, speed, clockwise, relative } => {
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_CONDITION_YAW,
+                frame: MavFrame::MAV_FRAME_GLOBAL,
+                x: 0,
+                y: 0,
+                z: 0.0,
+                param1: *angle,
+                param2: *speed,
+                param3: if *clockwise { 1.0 } else { -1.0 },
+                param4: *relative as i32 as f32,
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
+        },
+        MissionRouteItem::DoSetServo { servo, pwm } => {
+            Option::Some(MavMessage::MISSION_ITEM_INT(MISSION_ITEM_INT_DATA {
+                command: MavCmd::MAV_CMD_DO_SET_SERVO,
+                frame: MavFrame::MAV_FRAME_GLOBAL,
+                x: 0,
+                y: 0,
+                z: 0.0,
+                param1: *servo as f32,
+                param2: *pwm as f32,
+                param3: 0.0,
+                param4: 0.0,
+                seq,
+                //mission_type: mavlink::common::MavMissionType::MAV_MISSION_TYPE_MISSION,
+                target_system: *mav_id,
+                target_component: mavlink::common::MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+                current: 0,
+                autocontinue: 1
+            }))
         }
     }
 }
@@ -266,7 +478,83 @@ pub fn mission_route_item_from_mavlink(item_data: &MISSION_ITEM_INT_DATA) -> Mis
                 shutter: item_data.param2 as i16,
                 trigger: item_data.param3 == 1.0,
             }
-        }
+        },
+        MavCmd::MAV_CMD_DO_SET_ROI_LOCATION => {
+            MissionRouteItem::DoSetRoi {
+                position: Geodetic::from_mavlink(item_data.x, item_data.y, item_data.z, item_data.frame)
+            }
+        },
+        // Legacy ROI command, zero location cancels the region of interest
+        MavCmd::MAV_CMD_DO_SET_ROI => {
+            if item_data.x == 0 && item_data.y == 0 {
+                MissionRouteItem::RoiNone {}
+            } else {
+                MissionRouteItem::DoSetRoi {
+                    position: Geodetic::from_mavlink(item_data.x, item_data.y, item_data.z, item_data.frame)
+                }
+            }
+        },
+        MavCmd::MAV_CMD_DO_SET_ROI_NONE => MissionRouteItem::RoiNone {},
+        MavCmd::MAV_CMD_NAV_VTOL_TAKEOFF => {
+            MissionRouteItem::VtolTakeoff {
+                position: Geodetic::from_mavlink(item_data.x, item_data.y, item_data.z, item_data.frame),
+                yaw: yaw_from_param(item_data.param4)
+            }
+        },
+        MavCmd::MAV_CMD_NAV_VTOL_LAND => {
+            MissionRouteItem::VtolLand {
+                position: Geodetic::from_mavlink(item_data.x, item_data.y, item_data.z, item_data.frame),
+                approach_altitude: if item_data.param3.is_nan() || item_data.param3 == 0.0 { Option::None } else { Option::Some(item_data.param3) },
+                yaw: yaw_from_param(item_data.param4)
+            }
+        },
+        MavCmd::MAV_CMD_NAV_RETURN_TO_LAUNCH => MissionRouteItem::ReturnToLaunch {},
+        // Negative delay means waiting for the time of day, which routes can't express
+        MavCmd::MAV_CMD_NAV_DELAY if item_data.param1 >= 0.0 => {
+            MissionRouteItem::Delay { seconds: item_data.param1 }
+        },
+        MavCmd::MAV_CMD_DO_MOUNT_CONTROL => {
+            MissionRouteItem::DoGimbalControl {
+                pitch: item_data.param1,
+                roll: item_data.param2,
+                yaw: item_data.param3
+            }
+        },
+        MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW => {
+            MissionRouteItem::DoGimbalControl {
+                pitch: item_data.param1,
+                roll: 0.0,
+                yaw: item_data.param2
+            }
+        },
+        MavCmd::MAV_CMD_DO_CHANGE_SPEED => {
+            MissionRouteItem::DoChangeSpeed {
+                speed_type: speed_type_from_param(item_data.param1),
+                speed: item_data.param2,
+                throttle: if item_data.param3 < 0.0 { Option::None } else { Option::Some(item_data.param3) }
+            }
+        },
+        // Mission sequence starts with home, so jump targets are one ahead of route indices
+        MavCmd::MAV_CMD_DO_JUMP => {
+            MissionRouteItem::DoJump {
+                target: (item_data.param1 as u16).saturating_sub(1),
+                repeat: item_data.param2 as i16
+            }
+        },
+        MavCmd::MAV_CMD_CONDITION_YAW => {
+            MissionRouteItem::ConditionYaw {
+                angle: item_data.param1,
+                speed: item_data.param2,
+                clockwise: item_data.param3 >= 0.0,
+                relative: item_data.param4 != 0.0
+            }
+        },
+        MavCmd::MAV_CMD_DO_SET_SERVO => {
+            MissionRouteItem::DoSetServo {
+                servo: item_data.param1 as u16,
+                pwm: item_data.param2 as u16
+            }
+        },
         _ => return {
             log::warn!("Unsupported mission item type: {:?}", &item_data.command);
             MissionRouteItem::Gap {}
@@ -292,3 +580,21 @@ fn yaw_from_param(param: f32) -> Option<u16> {
         return Option::Some(param as u16)
     }
 }
+
+fn speed_type_to_param(speed_type: &SpeedType) -> f32 {
+    match speed_type {
+        SpeedType::Airspeed => 0.0,
+        SpeedType::Groundspeed => 1.0,
+        SpeedType::ClimbSpeed => 2.0,
+        SpeedType::DescentSpeed => 3.0
+    }
+}
+
+fn speed_type_from_param(param: f32) -> SpeedType {
+    match param as i32 {
+        0 => SpeedType::Airspeed,
+        2 => SpeedType::ClimbSpeed,
+        3 => SpeedType::DescentSpeed,
+        _ => SpeedType::Groundspeed
+    }
+}
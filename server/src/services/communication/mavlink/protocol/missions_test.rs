@@ -0,0 +1,89 @@
+use mavlink::common::*;
+use test_case::test_case;
+
+use crate::models::missions::{MissionRouteItem, SpeedType};
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use super::missions::*;
+
+fn position() -> Geodetic {
+    Geodetic { latitude: 55.75, longitude: 37.6, altitude: 40.0, frame: GeodeticFrame::Wgs84RelativeHome }
+}
+
+fn item_data(item: &MissionRouteItem) -> MISSION_ITEM_INT_DATA {
+    match send_mission_item(&1, item, 5) {
+        Some(MavMessage::MISSION_ITEM_INT(data)) => data,
+        message => panic!("Unexpected message {:?}", message)
+    }
+}
+
+fn command_data(command: MavCmd, params: [f32; 4], x: i32, y: i32) -> MISSION_ITEM_INT_DATA {
+    MISSION_ITEM_INT_DATA {
+        command,
+        frame: MavFrame::MAV_FRAME_GLOBAL_RELATIVE_ALT,
+        x,
+        y,
+        z: 0.0,
+        param1: params[0],
+        param2: params[1],
+        param3: params[2],
+        param4: params[3],
+        seq: 1,
+        target_system: 1,
+        target_component: MavComponent::MAV_COMP_ID_MISSIONPLANNER as u8,
+        current: 0,
+        autocontinue: 1
+    }
+}
+
+#[test_case(MissionRouteItem::DoChangeSpeed { speed_type: SpeedType::Groundspeed, speed: 12.5, throttle: None }; "change speed")]
+#[test_case(MissionRouteItem::DoChangeSpeed { speed_type: SpeedType::ClimbSpeed, speed: 3.0, throttle: Some(60.0) }; "change climb speed with throttle")]
+#[test_case(MissionRouteItem::DoSetRoi { position: position() }; "set roi")]
+#[test_case(MissionRouteItem::RoiNone {}; "roi none")]
+#[test_case(MissionRouteItem::Delay { seconds: 15.0 }; "delay")]
+#[test_case(MissionRouteItem::DoJump { target: 2, repeat: 3 }; "jump")]
+#[test_case(MissionRouteItem::DoJump { target: 0, repeat: -1 }; "jump forever")]
+#[test_case(MissionRouteItem::ConditionYaw { angle: 90.0, speed: 10.0, clockwise: true, relative: false }; "absolute yaw")]
+#[test_case(MissionRouteItem::ConditionYaw { angle: 45.0, speed: 0.0, clockwise: false, relative: true }; "relative yaw")]
+#[test_case(MissionRouteItem::DoSetServo { servo: 9, pwm: 1900 }; "set servo")]
+#[test_case(MissionRouteItem::DoGimbalControl { pitch: -45.0, roll: 0.0, yaw: 30.0 }; "gimbal control")]
+#[test_case(MissionRouteItem::VtolTakeoff { position: position(), yaw: Some(90) }; "vtol takeoff")]
+#[test_case(MissionRouteItem::VtolLand { position: position(), approach_altitude: Some(30.0), yaw: None }; "vtol land")]
+#[test_case(MissionRouteItem::VtolLand { position: position(), approach_altitude: None, yaw: Some(180) }; "vtol land without approach")]
+#[test_case(MissionRouteItem::ReturnToLaunch {}; "return to launch")]
+fn test_item_round_trip(item: MissionRouteItem) {
+    let data = item_data(&item);
+    assert_eq!(data.seq, 5);
+    assert_eq!(mission_route_item_from_mavlink(&data), item);
+}
+
+#[test_case(MissionRouteItem::DoJump { target: 2, repeat: 3 }, MavCmd::MAV_CMD_DO_JUMP, [3.0, 3.0]; "jump to sequence after home")]
+#[test_case(MissionRouteItem::DoJump { target: u16::MAX, repeat: 1 }, MavCmd::MAV_CMD_DO_JUMP, [u16::MAX as f32, 1.0]; "jump to last sequence")]
+#[test_case(MissionRouteItem::DoChangeSpeed { speed_type: SpeedType::Airspeed, speed: 20.0, throttle: None }, MavCmd::MAV_CMD_DO_CHANGE_SPEED, [0.0, 20.0]; "airspeed")]
+#[test_case(MissionRouteItem::DoSetServo { servo: 9, pwm: 1100 }, MavCmd::MAV_CMD_DO_SET_SERVO, [9.0, 1100.0]; "servo")]
+fn test_item_params(item: MissionRouteItem, command: MavCmd, params: [f32; 2]) {
+    let data = item_data(&item);
+    assert_eq!(data.command, command);
+    assert_eq!([data.param1, data.param2], params);
+}
+
+#[test]
+fn test_gimbal_targeting_mode() {
+    let data = item_data(&MissionRouteItem::DoGimbalControl { pitch: -90.0, roll: 0.0, yaw: 0.0 });
+    assert_eq!(data.command, MavCmd::MAV_CMD_DO_MOUNT_CONTROL);
+    assert_eq!(data.z, MavMountMode::MAV_MOUNT_MODE_MAVLINK_TARGETING as i32 as f32);
+}
+
+#[test_case(command_data(MavCmd::MAV_CMD_DO_SET_ROI, [0.0; 4], 0, 0), MissionRouteItem::RoiNone {}; "legacy roi none")]
+#[test_case(command_data(MavCmd::MAV_CMD_DO_SET_ROI, [0.0; 4], 557_500_000, 376_000_000),
+    MissionRouteItem::DoSetRoi { position: Geodetic { altitude: 0.0, ..position() } }; "legacy roi")]
+#[test_case(command_data(MavCmd::MAV_CMD_DO_GIMBAL_MANAGER_PITCHYAW, [-30.0, 15.0, 0.0, 0.0], 0, 0),
+    MissionRouteItem::DoGimbalControl { pitch: -30.0, roll: 0.0, yaw: 15.0 }; "gimbal manager")]
+#[test_case(command_data(MavCmd::MAV_CMD_NAV_VTOL_LAND, [0.0, 0.0, f32::NAN, f32::NAN], 0, 0),
+    MissionRouteItem::VtolLand { position: Geodetic { latitude: 0.0, longitude: 0.0, altitude: 0.0, ..position() },
+        approach_altitude: None, yaw: None }; "vtol land defaults")]
+#[test_case(command_data(MavCmd::MAV_CMD_DO_JUMP, [0.0, 1.0, 0.0, 0.0], 0, 0),
+    MissionRouteItem::DoJump { target: 0, repeat: 1 }; "jump to home")]
+#[test_case(command_data(MavCmd::MAV_CMD_NAV_DELAY, [-1.0, 12.0, 30.0, 0.0], 0, 0), MissionRouteItem::Gap {}; "time of day delay")]
+fn test_item_from_mavlink(data: MISSION_ITEM_INT_DATA, expected: MissionRouteItem) {
+    assert_eq!(mission_route_item_from_mavlink(&data), expected);
+}
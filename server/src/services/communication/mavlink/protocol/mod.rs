@@ -1,5 +1,20 @@
 pub mod modes;
 pub mod geodetic;
 pub mod telemetry;
+#[cfg(test)]
+mod telemetry_test;
 pub mod commands;
+#[cfg(test)]
+mod commands_test;
 pub mod missions;
+#[cfg(test)]
+mod missions_test;
+pub mod terrain;
+#[cfg(test)]
+mod terrain_test;
+pub mod navigation;
+#[cfg(test)]
+mod navigation_test;
+pub mod status_text;
+#[cfg(test)]
+mod status_text_test;
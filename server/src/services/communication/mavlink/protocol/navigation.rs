@@ -0,0 +1,18 @@
+use crate::models::spatial::Geodetic;
+
+const TARGET_MATCH_DISTANCE: f64 = 1.0; // Reported target rounding, meters
+const ARRIVAL_TOLERANCE: f64 = 5.0; // Meters
+
+// Vehicle reports requested target back in POSITION_TARGET_GLOBAL_INT, altitude frame may differ
+pub fn target_matches(requested: &Geodetic, reported: &Geodetic) -> bool {
+    requested.distance_to(reported) <= TARGET_MATCH_DISTANCE
+}
+
+// Planes can't hold a point, so loitering on the circle is an arrival too
+pub fn arrival_radius(loiter_radius: Option<f32>) -> f64 {
+    loiter_radius.map(|radius| radius.abs() as f64).unwrap_or(0.0) + ARRIVAL_TOLERANCE
+}
+
+pub fn has_arrived(position: &Geodetic, target: &Geodetic, radius: f64) -> bool {
+    position.distance_to(target) <= radius
+}
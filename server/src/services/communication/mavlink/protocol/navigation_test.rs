@@ -0,0 +1,33 @@
+use test_case::test_case;
+
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use super::navigation::*;
+
+fn point(latitude: f64, longitude: f64, altitude: f32, frame: GeodeticFrame) -> Geodetic {
+    Geodetic { latitude, longitude, altitude, frame }
+}
+
+#[test_case(55.0, 37.0, true; "same point")]
+#[test_case(55.000_005, 37.0, true; "rounded")]
+#[test_case(55.000_1, 37.0, false; "other target")]
+fn test_target_matches(latitude: f64, longitude: f64, expected: bool) {
+    let requested = point(55.0, 37.0, 100.0, GeodeticFrame::Wgs84RelativeHome);
+    let reported = point(latitude, longitude, 250.0, GeodeticFrame::Wgs84AboveSeaLevel);
+    assert_eq!(target_matches(&requested, &reported), expected);
+}
+
+#[test_case(None, 5.0; "no loiter")]
+#[test_case(Some(80.0), 85.0; "loiter")]
+#[test_case(Some(-80.0), 85.0; "counter clockwise loiter")]
+fn test_arrival_radius(loiter_radius: Option<f32>, expected: f64) {
+    assert_eq!(arrival_radius(loiter_radius), expected);
+}
+
+#[test_case(0.000_01, 5.0, true; "within tolerance")]
+#[test_case(0.000_1, 5.0, false; "on the way")]
+#[test_case(0.000_7, 85.0, true; "on loiter circle")]
+fn test_has_arrived(offset: f64, radius: f64, expected: bool) {
+    let target = point(55.0, 37.0, 100.0, GeodeticFrame::Wgs84AboveSeaLevel);
+    let position = point(55.0 + offset, 37.0, 100.0, GeodeticFrame::Wgs84AboveSeaLevel);
+    assert_eq!(has_arrived(&position, &target, radius), expected);
+}
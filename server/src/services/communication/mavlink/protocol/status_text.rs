@@ -0,0 +1,6 @@
+// NOTE: long texts are split into chunks by MAVLink 2 id & chunk_seq extensions (https://mavlink.io/en/services/statustext.html),
+// but STATUSTEXT of this mavlink crate version has no extensions, so every chunk is taken as a separate text
+pub fn decode_status_text(text: &[u8]) -> String {
+    let len = text.iter().position(|&byte| byte == 0).unwrap_or(text.len());
+    String::from_utf8_lossy(&text[..len]).to_string()
+}
@@ -0,0 +1,28 @@
+use test_case::test_case;
+
+use super::status_text::decode_status_text;
+
+const STATUS_TEXT_LEN: usize = 50;
+
+fn field(text: &str) -> Vec<u8> {
+    let mut bytes = text.as_bytes().to_vec();
+    bytes.resize(STATUS_TEXT_LEN, 0);
+    bytes
+}
+
+#[test_case("PreArm: Throttle below failsafe"; "short text")]
+#[test_case(""; "empty text")]
+fn test_null_terminated_text(text: &str) {
+    assert_eq!(decode_status_text(&field(text)), text);
+}
+
+#[test]
+fn test_full_length_text() {
+    let text = "A".repeat(STATUS_TEXT_LEN);
+    assert_eq!(decode_status_text(text.as_bytes()), text);
+}
+
+#[test]
+fn test_invalid_utf8() {
+    assert_eq!(decode_status_text(&[b'o', b'k', 0xFF, 0]), "ok\u{FFFD}");
+}
@@ -35,3 +35,57 @@ pub fn decode_voltage(value: u16) -> f32 {
 pub fn decode_current(value: i16) -> f32 {
     return value as f32 / 100.0;
 }
+
+pub fn decode_quaternion(q: [f32; 4]) -> (f32, f32, f32) {
+    let (w, x, y, z) = (q[0], q[1], q[2], q[3]);
+    let roll = (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y));
+    let pitch = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0).asin();
+    let yaw = (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z));
+    (decode_angles(roll), decode_angles(pitch), decode_angles(yaw))
+}
+
+pub fn decode_string(bytes: &[u8]) -> String {
+    let len = bytes.iter().position(|&byte| byte == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..len]).to_string()
+}
+
+pub fn decode_cell_voltages(voltages: &[u16]) -> Vec<f32> {
+    voltages.iter()
+        .filter(|&&voltage| voltage != u16::MAX)
+        .map(|&voltage| decode_voltage(voltage))
+        .collect()
+}
+
+pub fn decode_temperature(value: i16) -> Option<f32> {
+    if value == i16::MAX {
+        return None;
+    }
+    Some(value as f32 / 100.0)
+}
+
+pub fn decode_energy(value: i32) -> Option<f32> {
+    if value < 0 {
+        return None;
+    }
+    Some(value as f32 / 36.0) // hJ to Wh
+}
+
+// Exponential moving average of battery current, to avoid endurance jumps on throttle changes
+pub fn smooth_consumption_rate(previous: Option<f32>, current: f32) -> f32 {
+    const SMOOTHING_FACTOR: f32 = 0.1;
+    match previous {
+        Some(previous) => previous + SMOOTHING_FACTOR * (current - previous),
+        None => current
+    }
+}
+
+// Remaining capacity is derived from the consumed charge and the remaining percentage
+pub fn estimate_endurance(consumed_mah: i32, remaining: i8, consumption_rate: f32) -> Option<f32> {
+    const MIN_CONSUMPTION_RATE: f32 = 0.1;
+    if consumed_mah <= 0 || remaining <= 0 || remaining >= 100 || consumption_rate < MIN_CONSUMPTION_RATE {
+        return None;
+    }
+
+    let remaining_mah = consumed_mah as f32 * remaining as f32 / (100 - remaining) as f32;
+    Some(remaining_mah / (consumption_rate * 1000.0) * 3600.0)
+}
@@ -0,0 +1,51 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+use test_case::test_case;
+
+use super::telemetry::*;
+
+#[test_case([1.0, 0.0, 0.0, 0.0], (0.0, 0.0, 0.0); "identity")]
+#[test_case([FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2], (0.0, 0.0, 90.0); "yaw 90")]
+#[test_case([0.923_879_5, 0.0, -0.382_683_4, 0.0], (0.0, -45.0, 0.0); "pitch down")]
+#[test_case([0.965_925_8, 0.258_819, 0.0, 0.0], (30.0, 0.0, 0.0); "roll 30")]
+fn test_decode_quaternion(q: [f32; 4], expected: (f32, f32, f32)) {
+    let (roll, pitch, yaw) = decode_quaternion(q);
+    assert!((roll - expected.0).abs() < 0.01, "roll {}", roll);
+    assert!((pitch - expected.1).abs() < 0.01, "pitch {}", pitch);
+    assert!((yaw - expected.2).abs() < 0.01, "yaw {}", yaw);
+}
+
+#[test]
+fn test_decode_cell_voltages() {
+    let mut voltages = [u16::MAX; 10];
+    voltages[0] = 4150;
+    voltages[1] = 4120;
+    voltages[2] = 4100;
+    assert_eq!(decode_cell_voltages(&voltages), vec![4.15, 4.12, 4.1]);
+}
+
+#[test_case(i16::MAX, None; "unknown")]
+#[test_case(2550, Some(25.5); "positive")]
+#[test_case(-1000, Some(-10.0); "negative")]
+fn test_decode_temperature(value: i16, expected: Option<f32>) {
+    assert_eq!(decode_temperature(value), expected);
+}
+
+#[test]
+fn test_smooth_consumption_rate() {
+    assert_eq!(smooth_consumption_rate(None, 12.0), 12.0);
+    assert_eq!(smooth_consumption_rate(Some(10.0), 20.0), 11.0);
+    assert_eq!(smooth_consumption_rate(Some(10.0), 10.0), 10.0);
+}
+
+#[test_case(1000, 50, 10.0, Some(360.0); "half consumed")]
+#[test_case(3000, 25, 5.0, Some(720.0); "quarter left")]
+#[test_case(0, 100, 10.0, None; "nothing consumed")]
+#[test_case(1000, 0, 10.0, None; "empty battery")]
+#[test_case(1000, 50, 0.0, None; "no consumption")]
+fn test_estimate_endurance(consumed_mah: i32, remaining: i8, rate: f32, expected: Option<f32>) {
+    let endurance = estimate_endurance(consumed_mah, remaining, rate);
+    match (endurance, expected) {
+        (Some(endurance), Some(expected)) => assert!((endurance - expected).abs() < 0.1, "endurance {}", endurance),
+        _ => assert_eq!(endurance, expected)
+    }
+}
@@ -0,0 +1,79 @@
+use mavlink::common::*;
+
+use crate::models::{spatial::Geodetic, terrain::TerrainReport, vehicles::VehicleId};
+use super::telemetry::{decode_lat_lon, encode_lat_lon};
+
+// Request grid is 8 blocks to the east by 7 blocks to the north, each block is 4x4 points
+const GRID_BLOCKS_EAST: u8 = 8;
+const GRID_BLOCKS: u8 = 56;
+const BLOCK_SIZE: usize = 4;
+
+// Same flat earth offset as ArduPilot's Location::offset, so points match the vehicle grid
+const METERS_PER_DEGREE: f64 = 111318.84502145034;
+
+pub fn offset_position(latitude: f64, longitude: f64, north: f64, east: f64) -> (f64, f64) {
+    let d_lat = north / METERS_PER_DEGREE;
+    let scale = (latitude + d_lat / 2.0).to_radians().cos().clamp(0.01, 1.0);
+    (latitude + d_lat, longitude + east / METERS_PER_DEGREE / scale)
+}
+
+pub fn requested_blocks(mask: u64) -> Vec<u8> {
+    (0..GRID_BLOCKS).filter(|bit| mask & (1 << bit) != 0).collect()
+}
+
+// None if any block point has no elevation, the vehicle will ask again for it later
+pub fn encode_terrain_data<F>(request: &TERRAIN_REQUEST_DATA, gridbit: u8, elevation: F) -> Option<MavMessage>
+where F: Fn(f64, f64) -> Option<f32> {
+    let spacing = request.grid_spacing as f64;
+    let block_span = spacing * BLOCK_SIZE as f64;
+    let (latitude, longitude) = offset_position(
+        decode_lat_lon(request.lat),
+        decode_lat_lon(request.lon),
+        block_span * (gridbit / GRID_BLOCKS_EAST) as f64,
+        block_span * (gridbit % GRID_BLOCKS_EAST) as f64
+    );
+
+    let mut data = [0; BLOCK_SIZE * BLOCK_SIZE];
+    for (index, height) in data.iter_mut().enumerate() {
+        let (north, east) = ((index / BLOCK_SIZE) as f64, (index % BLOCK_SIZE) as f64);
+        let (latitude, longitude) = offset_position(latitude, longitude, spacing * north, spacing * east);
+        *height = elevation(latitude, longitude)?.round().clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+    }
+
+    Some(MavMessage::TERRAIN_DATA(TERRAIN_DATA_DATA {
+        lat: request.lat,
+        lon: request.lon,
+        grid_spacing: request.grid_spacing,
+        data,
+        gridbit
+    }))
+}
+
+pub fn encode_terrain_response<F>(request: &TERRAIN_REQUEST_DATA, elevation: F) -> Vec<MavMessage>
+where F: Fn(f64, f64) -> Option<f32> {
+    requested_blocks(request.mask).into_iter()
+        .filter_map(|gridbit| encode_terrain_data(request, gridbit, &elevation))
+        .collect()
+}
+
+pub fn terrain_check(position: &Geodetic) -> MavMessage {
+    MavMessage::TERRAIN_CHECK(TERRAIN_CHECK_DATA {
+        lat: encode_lat_lon(position.latitude),
+        lon: encode_lat_lon(position.longitude)
+    })
+}
+
+pub fn decode_terrain_report(vehicle_id: &VehicleId, data: &TERRAIN_REPORT_DATA) -> TerrainReport {
+    TerrainReport {
+        id: vehicle_id.clone(),
+        timestamp: 0,
+        latitude: decode_lat_lon(data.lat),
+        longitude: decode_lat_lon(data.lon),
+        terrain_height: data.terrain_height,
+        current_height: data.current_height,
+        spacing: data.spacing,
+        pending: data.pending,
+        loaded: data.loaded,
+        local_elevation: None
+    }
+}
@@ -0,0 +1,110 @@
+use mavlink::common::*;
+use test_case::test_case;
+
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use super::terrain::*;
+
+const LAT: i32 = 559_700_000;
+const LON: i32 = 374_100_000;
+
+fn request(mask: u64) -> TERRAIN_REQUEST_DATA {
+    TERRAIN_REQUEST_DATA { mask, lat: LAT, lon: LON, grid_spacing: 100 }
+}
+
+// Elevation grows by a meter per ~1.1 m to the north and per ~0.6 m to the east
+fn slope(latitude: f64, longitude: f64) -> Option<f32> {
+    Some(((latitude - 55.97) * 100_000.0 + (longitude - 37.41) * 100_000.0) as f32)
+}
+
+fn data(message: &MavMessage) -> &TERRAIN_DATA_DATA {
+    match message {
+        MavMessage::TERRAIN_DATA(data) => data,
+        _ => panic!("Unexpected message {:?}", message)
+    }
+}
+
+#[test_case(0.0, 0.0, 55.97, 37.41; "no offset")]
+#[test_case(1113.1884502145034, 0.0, 55.98, 37.41; "north")]
+#[test_case(0.0, 1113.1884502145034, 55.97, 37.427_869; "east")]
+fn test_offset_position(north: f64, east: f64, latitude: f64, longitude: f64) {
+    let (lat, lon) = offset_position(55.97, 37.41, north, east);
+    assert!((lat - latitude).abs() < 1e-6, "latitude {}", lat);
+    assert!((lon - longitude).abs() < 1e-6, "longitude {}", lon);
+}
+
+#[test_case(0, vec![]; "empty")]
+#[test_case(0b1011, vec![0, 1, 3]; "first blocks")]
+#[test_case((1 << 55) | (1 << 60), vec![55]; "bits over grid")]
+fn test_requested_blocks(mask: u64, expected: Vec<u8>) {
+    assert_eq!(requested_blocks(mask), expected);
+}
+
+#[test]
+fn test_first_block_points() {
+    let message = encode_terrain_data(&request(1), 0, slope).unwrap();
+    let data = data(&message);
+
+    assert_eq!((data.lat, data.lon, data.grid_spacing, data.gridbit), (LAT, LON, 100, 0));
+    // Rows go to the north, columns to the east
+    assert_eq!(data.data[0], 0);
+    assert_eq!(data.data[1], 161);
+    assert_eq!(data.data[4], 90);
+    assert_eq!(data.data[15], 751);
+}
+
+#[test]
+fn test_block_offset() {
+    let first = encode_terrain_data(&request(1), 0, slope).unwrap();
+    // Block 9 is one block to the north and one to the east, 400 meters each
+    let ninth = encode_terrain_data(&request(1 << 9), 9, slope).unwrap();
+
+    assert_eq!(data(&ninth).gridbit, 9);
+    assert_eq!(data(&first).data[0], 0);
+    assert_eq!(data(&ninth).data[0], 1001);
+}
+
+#[test]
+fn test_missing_elevation_skips_block() {
+    let partial = |latitude: f64, longitude: f64| if longitude < 37.4155 { slope(latitude, longitude) } else { None };
+
+    assert!(encode_terrain_data(&request(1), 0, partial).is_some());
+    assert!(encode_terrain_data(&request(2), 1, partial).is_none());
+    assert_eq!(encode_terrain_response(&request(0b11), partial).len(), 1);
+}
+
+#[test]
+fn test_terrain_response() {
+    let messages = encode_terrain_response(&request(u64::MAX), slope);
+
+    assert_eq!(messages.len(), 56);
+    assert_eq!(data(&messages[55]).gridbit, 55);
+}
+
+#[test]
+fn test_terrain_check() {
+    let position = Geodetic { latitude: 55.5, longitude: 37.25, altitude: 0.0, frame: GeodeticFrame::None };
+    match terrain_check(&position) {
+        MavMessage::TERRAIN_CHECK(data) => assert_eq!((data.lat, data.lon), (555_000_000, 372_500_000)),
+        message => panic!("Unexpected message {:?}", message)
+    }
+}
+
+#[test]
+fn test_decode_terrain_report() {
+    let data = TERRAIN_REPORT_DATA {
+        lat: LAT,
+        lon: LON,
+        terrain_height: 180.0,
+        current_height: 45.0,
+        spacing: 100,
+        pending: 2,
+        loaded: 40
+    };
+    let report = decode_terrain_report(&"vehicle".to_string(), &data);
+
+    assert_eq!(report.id, "vehicle");
+    assert_eq!((report.latitude, report.longitude), (55.97, 37.41));
+    assert_eq!((report.terrain_height, report.current_height), (180.0, 45.0));
+    assert_eq!((report.spacing, report.pending, report.loaded), (100, 2, 40));
+    assert_eq!(report.local_elevation, None);
+}
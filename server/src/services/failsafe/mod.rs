@@ -0,0 +1,6 @@
+pub mod monitor;
+#[cfg(test)]
+mod monitor_test;
+pub mod service;
+#[cfg(test)]
+mod service_test;
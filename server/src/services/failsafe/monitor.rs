@@ -0,0 +1,59 @@
+use crate::models::failsafe::FailsafePolicy;
+use crate::models::telemetry::System;
+use crate::models::vehicles::VehicleStatus;
+
+// Trigger condition: violated fires the failsafe, recovered rearms it
+#[derive(Debug, PartialEq)]
+pub struct Condition {
+    pub violated: bool,
+    pub recovered: bool
+}
+
+// Debounces a failsafe trigger: fires once after the condition holds for the delay
+// and rearms only after the condition is recovered
+#[derive(Default)]
+pub struct TriggerState {
+    violated_since: Option<i64>,
+    fired: bool
+}
+
+impl TriggerState {
+    pub fn update(&mut self, condition: &Condition, now: i64, delay_ms: i64) -> bool {
+        if condition.violated {
+            let since = *self.violated_since.get_or_insert(now);
+            if !self.fired && now - since >= delay_ms {
+                self.fired = true;
+                return true;
+            }
+        } else {
+            self.violated_since = None;
+            if condition.recovered {
+                self.fired = false;
+            }
+        }
+        false
+    }
+
+    pub fn is_fired(&self) -> bool {
+        self.fired
+    }
+}
+
+pub fn link_loss_condition(policy: &FailsafePolicy, status: &VehicleStatus, now: i64) -> Condition {
+    // Vehicle never seen online can't lose the link
+    let lost = status.last_heartbeat > 0 &&
+        (now - status.last_heartbeat) as f32 > policy.link_loss_timeout * 1000.0;
+    Condition { violated: lost && status.armed, recovered: !lost }
+}
+
+pub fn battery_condition(policy: &FailsafePolicy, status: &VehicleStatus, system: &System) -> Condition {
+    // Negative remaining means it is not estimated by the autopilot
+    if system.battery_remaining < 0 {
+        return Condition { violated: false, recovered: false };
+    }
+
+    let low = system.battery_remaining < policy.battery_threshold;
+    let recovered = system.battery_remaining as i16 >=
+        policy.battery_threshold as i16 + policy.battery_hysteresis as i16;
+    Condition { violated: low && status.armed, recovered }
+}
@@ -0,0 +1,86 @@
+use test_case::test_case;
+
+use crate::models::failsafe::FailsafePolicy;
+use crate::models::telemetry::System;
+use crate::models::vehicles::VehicleStatus;
+use super::monitor::{battery_condition, link_loss_condition, Condition, TriggerState};
+
+const NOW: i64 = 1_700_000_000_000;
+const DELAY_MS: i64 = 2000;
+
+fn armed_status(last_heartbeat: i64) -> VehicleStatus {
+    let mut status = VehicleStatus::default_for_id(&"mav_1".to_string());
+    status.armed = true;
+    status.last_heartbeat = last_heartbeat;
+    status
+}
+
+fn system(battery_remaining: i8) -> System {
+    let mut system = System::default_for_id(&"mav_1".to_string());
+    system.battery_remaining = battery_remaining;
+    system
+}
+
+const VIOLATED: Condition = Condition { violated: true, recovered: false };
+const HOLD: Condition = Condition { violated: false, recovered: false };
+const RECOVERED: Condition = Condition { violated: false, recovered: true };
+
+#[test]
+fn test_trigger_fires_once_after_delay() {
+    let mut state = TriggerState::default();
+    assert!(!state.update(&VIOLATED, NOW, DELAY_MS));
+    assert!(!state.update(&VIOLATED, NOW + 1000, DELAY_MS));
+    assert!(state.update(&VIOLATED, NOW + 2000, DELAY_MS));
+    assert!(state.is_fired());
+    assert!(!state.update(&VIOLATED, NOW + 3000, DELAY_MS));
+    assert!(state.is_fired());
+}
+
+#[test]
+fn test_trigger_short_violation_ignored() {
+    let mut state = TriggerState::default();
+    assert!(!state.update(&VIOLATED, NOW, DELAY_MS));
+    assert!(!state.update(&RECOVERED, NOW + 1000, DELAY_MS));
+    assert!(!state.update(&VIOLATED, NOW + 1500, DELAY_MS));
+    assert!(!state.update(&VIOLATED, NOW + 3000, DELAY_MS));
+    assert!(!state.is_fired());
+    assert!(state.update(&VIOLATED, NOW + 3500, DELAY_MS));
+}
+
+#[test]
+fn test_trigger_rearms_only_after_recovery() {
+    let mut state = TriggerState::default();
+    state.update(&VIOLATED, NOW, DELAY_MS);
+    assert!(state.update(&VIOLATED, NOW + DELAY_MS, DELAY_MS));
+
+    // Within hysteresis band the trigger stays fired
+    assert!(!state.update(&HOLD, NOW + 3000, DELAY_MS));
+    assert!(state.is_fired());
+    state.update(&VIOLATED, NOW + 4000, DELAY_MS);
+    assert!(!state.update(&VIOLATED, NOW + 7000, DELAY_MS));
+
+    assert!(!state.update(&RECOVERED, NOW + 8000, DELAY_MS));
+    assert!(!state.is_fired());
+    state.update(&VIOLATED, NOW + 9000, DELAY_MS);
+    assert!(state.update(&VIOLATED, NOW + 11000, DELAY_MS));
+}
+
+#[test_case(NOW - 1000, true, Condition { violated: false, recovered: true }; "online")]
+#[test_case(NOW - 6000, true, Condition { violated: true, recovered: false }; "lost armed")]
+#[test_case(NOW - 6000, false, Condition { violated: false, recovered: false }; "lost disarmed")]
+#[test_case(0, true, Condition { violated: false, recovered: true }; "never seen")]
+fn test_link_loss_condition(last_heartbeat: i64, armed: bool, expected: Condition) {
+    let mut status = armed_status(last_heartbeat);
+    status.armed = armed;
+    assert_eq!(link_loss_condition(&FailsafePolicy::default(), &status, NOW), expected);
+}
+
+#[test_case(50, Condition { violated: false, recovered: true }; "full")]
+#[test_case(25, Condition { violated: false, recovered: true }; "hysteresis edge")]
+#[test_case(22, Condition { violated: false, recovered: false }; "hysteresis band")]
+#[test_case(15, Condition { violated: true, recovered: false }; "low")]
+#[test_case(-1, Condition { violated: false, recovered: false }; "unknown")]
+fn test_battery_condition(battery_remaining: i8, expected: Condition) {
+    let policy = FailsafePolicy::default();
+    assert_eq!(battery_condition(&policy, &armed_status(NOW), &system(battery_remaining)), expected);
+}
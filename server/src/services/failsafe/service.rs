@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use tokio::time;
+
+use crate::models::commands::{CommandExecutor, CommandRejection, ExecuteCommandRequest};
+use crate::services::capabilities::validation;
+use crate::models::events::ClientEvent;
+use crate::models::failsafe::{FailsafeEvent, FailsafeTrigger};
+use crate::models::vehicles::{VehicleDescription, VehicleId};
+use crate::{bus::bus, dal::dal};
+use super::monitor::{self, Condition, TriggerState};
+
+const CHECK_FAILSAFE_INTERVAL: time::Duration = time::Duration::from_millis(500);
+
+pub struct Service {
+    dal: dal::Dal,
+    client_bus: bus::EventBus::<ClientEvent>,
+    triggers: HashMap<(VehicleId, FailsafeTrigger), TriggerState>
+}
+
+impl Service {
+    pub fn new(dal: dal::Dal, client_bus: bus::EventBus::<ClientEvent>) -> Self {
+        Self { dal, client_bus, triggers: HashMap::new() }
+    }
+
+    pub async fn start(&mut self) -> anyhow::Result<()> {
+        let mut interval = time::interval(CHECK_FAILSAFE_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            if let Err(err) = self.check_vehicles().await {
+                log::error!("Failsafe check error: {}", err);
+            }
+        }
+    }
+
+    async fn check_vehicles(&mut self) -> anyhow::Result<()> {
+        let now = chrono::Utc::now().timestamp_millis();
+        let vehicles = self.dal.all_vehicles().await?;
+
+        // Drop trigger states of removed vehicles and of vehicles with failsafe disabled
+        self.triggers.retain(|(vehicle_id, _), _| vehicles.iter()
+            .any(|vehicle| &vehicle.id == vehicle_id && vehicle.failsafe.enabled));
+
+        for vehicle in vehicles {
+            if !vehicle.failsafe.enabled {
+                continue;
+            }
+            let status = match self.dal.vehcile_status(&vehicle.id).await {
+                Ok(status) => status,
+                Err(_) => continue
+            };
+
+            let condition = monitor::link_loss_condition(&vehicle.failsafe, &status, now);
+            self.check_trigger(&vehicle, FailsafeTrigger::LinkLoss, &condition, now).await?;
+
+            if let Ok(system) = self.dal.telemetry_system(&vehicle.id).await {
+                let condition = monitor::battery_condition(&vehicle.failsafe, &status, &system);
+                self.check_trigger(&vehicle, FailsafeTrigger::LowBattery, &condition, now).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn check_trigger(
+        &mut self,
+        vehicle: &VehicleDescription,
+        trigger: FailsafeTrigger,
+        condition: &Condition,
+        now: i64
+    ) -> anyhow::Result<()> {
+        let state = self.triggers.entry((vehicle.id.clone(), trigger.clone())).or_default();
+        let fired = state.update(condition, now, (vehicle.failsafe.delay * 1000.0) as i64);
+        let active = state.is_fired();
+
+        let mut status = self.dal.failsafe_status(&vehicle.id).await?;
+        if active != status.triggered.contains(&trigger) {
+            if active {
+                status.triggered.push(trigger.clone());
+            } else {
+                status.triggered.retain(|triggered| triggered != &trigger);
+                log::info!("Failsafe {:?} recovered for vehicle {}", &trigger, &vehicle.id);
+            }
+            status = self.dal.update_failsafe_status(status).await?;
+        }
+
+        if fired {
+            self.react(vehicle, trigger, status.overridden).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn react(&self, vehicle: &VehicleDescription, trigger: FailsafeTrigger, overridden: bool) -> anyhow::Result<()> {
+        let command = vehicle.failsafe.action(&trigger);
+        let mut command_id = None;
+        let mut rejection = None;
+
+        if overridden {
+            log::warn!("Failsafe {:?} for vehicle {} is overridden by operator", &trigger, &vehicle.id);
+        } else if let Some(command) = &command {
+            // Policies saved before confirmation was required are still refused here
+            let result = if command.requires_confirmation() {
+                Err(CommandRejection::ConfirmationRequired {})
+            } else {
+                validation::validate(vehicle, command)
+            };
+            match result {
+                Ok(()) => {
+                    log::warn!("Failsafe {:?} for vehicle {}, executing {:?}", &trigger, &vehicle.id, command);
+
+                    let id = uuid::Uuid::new_v4().to_string();
+                    self.client_bus.publish(ClientEvent::ExecuteCommand {
+                        request: ExecuteCommandRequest {
+                            command: command.clone(),
+                            executor: CommandExecutor::Vehicle { vehicle_id: vehicle.id.clone() }
+                        },
+                        command_id: id.clone()
+                    })?;
+                    command_id = Some(id);
+                },
+                Err(err) => {
+                    log::error!("Failsafe {:?} for vehicle {}, {:?} is rejected: {:?}", &trigger, &vehicle.id, command, &err);
+                    rejection = Some(err);
+                }
+            }
+        } else {
+            log::warn!("Failsafe {:?} for vehicle {} has no action", &trigger, &vehicle.id);
+        }
+
+        self.dal.add_failsafe_event(FailsafeEvent {
+            id: String::new(),
+            vehicle_id: vehicle.id.clone(),
+            timestamp: 0,
+            trigger,
+            command,
+            command_id,
+            overridden,
+            rejection
+        }).await?;
+        Ok(())
+    }
+}
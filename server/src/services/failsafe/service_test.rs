@@ -0,0 +1,90 @@
+use test_case::test_case;
+
+use crate::bus::bus;
+use crate::dal::test_utils::in_memory_dal;
+use crate::models::colors::EntityColor;
+use crate::models::commands::{Command, CommandExecutor, CommandRejection, ParachuteAction};
+use crate::models::events::ClientEvent;
+use crate::models::failsafe::{FailsafePolicy, FailsafeTrigger};
+use crate::models::vehicles::*;
+use super::service::Service;
+
+fn vehicle(link_loss_action: Option<Command>) -> VehicleDescription {
+    VehicleDescription {
+        id: "mav_1".into(),
+        name: "Test".into(),
+        color: EntityColor::Cyan,
+        vehicle_type: VehicleType::FixedWing,
+        protocol_id: ProtocolId::MavlinkId { mav_id: 1 },
+        features: Vec::new(),
+        available_modes: vec![VehicleMode::Loiter, VehicleMode::RTL],
+        failsafe: FailsafePolicy { enabled: true, link_loss_action, ..FailsafePolicy::default() },
+        fuel_tank_capacity: None
+    }
+}
+
+#[test_case(Command::SetMode { mode: VehicleMode::Guided }, CommandRejection::ModeUnavailable { mode: VehicleMode::Guided }; "unavailable mode")]
+#[test_case(Command::EngineControl { start: false }, CommandRejection::FeatureMissing { feature: VehicleFeatures::PetrolEngine }; "missing feature")]
+#[tokio::test]
+async fn test_rejected_action_is_not_executed(action: Command, expected: CommandRejection) {
+    let (dal, _) = in_memory_dal().await;
+    let client_bus = bus::EventBus::<ClientEvent>::new();
+    let mut client_rx = client_bus.subscribe();
+    let service = Service::new(dal.clone(), client_bus);
+
+    service.react(&vehicle(Some(action.clone())), FailsafeTrigger::LinkLoss, false).await
+        .expect("Error reacting to failsafe");
+
+    assert!(client_rx.try_recv().is_err());
+
+    let events = dal.failsafe_events(&"mav_1".into()).await.expect("Error getting failsafe events");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].command, Some(action));
+    assert_eq!(events[0].command_id, None);
+    assert_eq!(events[0].rejection, Some(expected));
+}
+
+#[tokio::test]
+async fn test_stored_action_requiring_confirmation_is_not_executed() {
+    let (dal, _) = in_memory_dal().await;
+    let client_bus = bus::EventBus::<ClientEvent>::new();
+    let mut client_rx = client_bus.subscribe();
+    let service = Service::new(dal.clone(), client_bus);
+
+    let mut vehicle = vehicle(Some(Command::Parachute { action: ParachuteAction::Release }));
+    vehicle.features = vec![VehicleFeatures::Parachute];
+    service.react(&vehicle, FailsafeTrigger::LinkLoss, false).await
+        .expect("Error reacting to failsafe");
+
+    assert!(client_rx.try_recv().is_err());
+
+    let events = dal.failsafe_events(&"mav_1".into()).await.expect("Error getting failsafe events");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].command_id, None);
+    assert_eq!(events[0].rejection, Some(CommandRejection::ConfirmationRequired {}));
+}
+
+#[tokio::test]
+async fn test_capable_action_is_executed() {
+    let (dal, _) = in_memory_dal().await;
+    let client_bus = bus::EventBus::<ClientEvent>::new();
+    let mut client_rx = client_bus.subscribe();
+    let service = Service::new(dal.clone(), client_bus);
+
+    service.react(&vehicle(Some(Command::SetMode { mode: VehicleMode::RTL })), FailsafeTrigger::LinkLoss, false).await
+        .expect("Error reacting to failsafe");
+
+    let command_id = match client_rx.try_recv().expect("Error receiving event") {
+        ClientEvent::ExecuteCommand { request, command_id } => {
+            assert_eq!(request.command, Command::SetMode { mode: VehicleMode::RTL });
+            assert_eq!(request.executor, CommandExecutor::Vehicle { vehicle_id: "mav_1".into() });
+            command_id
+        },
+        event => panic!("Unexpected event: {:?}", event)
+    };
+
+    let events = dal.failsafe_events(&"mav_1".into()).await.expect("Error getting failsafe events");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].command_id, Some(command_id));
+    assert_eq!(events[0].rejection, None);
+}
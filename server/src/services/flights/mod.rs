@@ -0,0 +1,6 @@
+pub mod tracker;
+#[cfg(test)]
+mod tracker_test;
+pub mod service;
+#[cfg(test)]
+mod service_test;
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use tokio::time;
+
+use crate::dal::dal;
+use crate::models::telemetry::Flight;
+use crate::models::vehicles::{VehicleDescription, VehicleId};
+use super::tracker::{FlightSample, SessionTracker, SessionUpdate};
+
+const TRACK_FLIGHTS_INTERVAL: time::Duration = time::Duration::from_millis(1000);
+const MAX_ARM_TIME_STEP_MS: i64 = 5000; // don't count arm time over link gaps
+const LINK_ONLINE_TIMEOUT_MS: i64 = 3000;
+
+pub struct Service {
+    dal: dal::Dal,
+    trackers: HashMap<VehicleId, SessionTracker>,
+    last_armed: HashMap<VehicleId, i64>
+}
+
+impl Service {
+    pub fn new(dal: dal::Dal) -> Self {
+        Self { dal, trackers: HashMap::new(), last_armed: HashMap::new() }
+    }
+
+    pub async fn start(&mut self) -> anyhow::Result<()> {
+        if let Err(err) = self.restore_sessions().await {
+            log::error!("Flight sessions restore error: {}", err);
+        }
+
+        let mut interval = time::interval(TRACK_FLIGHTS_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            if let Err(err) = self.track_vehicles(chrono::Utc::now().timestamp_millis()).await {
+                log::error!("Flight tracking error: {}", err);
+            }
+        }
+    }
+
+    // Sessions left open by a restart are continued, so they are closed and counted on landing
+    pub async fn restore_sessions(&mut self) -> anyhow::Result<()> {
+        for vehicle in self.dal.all_vehicles().await? {
+            if let Some(session) = self.dal.active_flight_session(&vehicle.id).await? {
+                log::info!("Vehicle {} flight session {} restored", &vehicle.id, &session.id);
+                self.trackers.insert(vehicle.id.clone(), SessionTracker::resume(session));
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn track_vehicles(&mut self, now: i64) -> anyhow::Result<()> {
+        let vehicles = self.dal.all_vehicles().await?;
+
+        // Drop tracking states of removed vehicles
+        self.trackers.retain(|vehicle_id, _| vehicles.iter().any(|vehicle| &vehicle.id == vehicle_id));
+        self.last_armed.retain(|vehicle_id, _| vehicles.iter().any(|vehicle| &vehicle.id == vehicle_id));
+
+        for vehicle in vehicles {
+            if let Err(err) = self.track_vehicle(&vehicle, now).await {
+                log::error!("Flight tracking error: {}", err);
+            }
+        }
+        Ok(())
+    }
+
+    async fn track_vehicle(&mut self, vehicle: &VehicleDescription, now: i64) -> anyhow::Result<()> {
+        let (status, navigation) = match (
+            self.dal.vehcile_status(&vehicle.id).await,
+            self.dal.telemetry_navigation(&vehicle.id).await
+        ) {
+            (Ok(status), Ok(navigation)) => (status, navigation),
+            _ => return Ok(())
+        };
+        let flight = self.dal.telemetry_flight(&vehicle.id).await
+            .unwrap_or(Flight::default_for_id(&vehicle.id));
+
+        if status.armed && now - status.last_heartbeat < LINK_ONLINE_TIMEOUT_MS {
+            if let Some(last_armed) = self.last_armed.insert(vehicle.id.clone(), now) {
+                let step = now - last_armed;
+                if step < MAX_ARM_TIME_STEP_MS {
+                    self.dal.add_vehicle_counters(&vehicle.id, 0.0, 0, step as f64 / 1000.0).await?;
+                }
+            }
+        } else {
+            self.last_armed.remove(&vehicle.id);
+        }
+
+        let sample = FlightSample {
+            now,
+            armed: status.armed,
+            landed_state: status.landed_state,
+            ground_speed: flight.ground_speed,
+            position: navigation.position,
+            home_altitude: navigation.home_position.altitude
+        };
+
+        // Mission is bound to the session on takeoff only
+        let flying = self.trackers.get(&vehicle.id).is_some_and(|tracker| tracker.is_flying());
+        let mission_id = if flying {
+            None
+        } else {
+            self.dal.active_mission_assignment(&vehicle.id).await?.map(|assignment| assignment.mission_id)
+        };
+
+        let tracker = self.trackers.entry(vehicle.id.clone()).or_default();
+        let (update, session) = match tracker.update(&vehicle.id, mission_id, &sample) {
+            Some(update) => update,
+            None => return Ok(())
+        };
+
+        match update {
+            SessionUpdate::Started => {
+                log::info!("Vehicle {} took off", &vehicle.id);
+                self.dal.add_vehicle_counters(&vehicle.id, 0.0, 1, 0.0).await?;
+            },
+            SessionUpdate::Finished => {
+                log::info!("Vehicle {} landed after {:.0} s, {:.0} m", &vehicle.id, session.duration, session.distance);
+                self.dal.add_vehicle_counters(&vehicle.id, session.duration as f64, 0, 0.0).await?;
+            },
+            SessionUpdate::Progress => {}
+        }
+
+        let session = self.dal.save_flight_session(session).await?;
+        if update == SessionUpdate::Started {
+            if let Some(tracker) = self.trackers.get_mut(&vehicle.id) {
+                tracker.set_session_id(&session.id);
+            }
+        }
+        Ok(())
+    }
+}
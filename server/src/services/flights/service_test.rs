@@ -0,0 +1,86 @@
+use crate::dal::dal;
+use crate::dal::test_utils::in_memory_dal;
+use crate::models::colors::EntityColor;
+use crate::models::failsafe::FailsafePolicy;
+use crate::models::telemetry::Navigation;
+use crate::models::vehicles::*;
+use super::service::Service;
+
+const NOW: i64 = 1_700_000_000_000;
+
+async fn setup_vehicle(dal: &dal::Dal) -> VehicleDescription {
+    let vehicle = dal.save_vehicle(VehicleDescription {
+        id: String::new(),
+        name: "Test".into(),
+        color: EntityColor::Cyan,
+        vehicle_type: VehicleType::FixedWing,
+        protocol_id: ProtocolId::MavlinkId { mav_id: 1 },
+        features: Vec::new(),
+        available_modes: Vec::new(),
+        failsafe: FailsafePolicy::default(),
+        fuel_tank_capacity: None
+    }).await.expect("Error saving vehicle");
+    dal.save_telemetry_navigation(vehicle.id.clone(), Navigation::default_for_id(&vehicle.id)).await
+        .expect("Error saving navigation");
+    vehicle
+}
+
+async fn report(dal: &dal::Dal, vehicle_id: &VehicleId, now: i64, landed_state: LandedState) {
+    let mut status = VehicleStatus::default_for_id(vehicle_id);
+    status.last_heartbeat = now;
+    status.armed = true;
+    status.landed_state = landed_state;
+    dal.update_vehicle_status(status).await.expect("Error updating status");
+}
+
+#[tokio::test]
+async fn test_session_finished_after_restart() {
+    let (dal, _) = in_memory_dal().await;
+    let vehicle = setup_vehicle(&dal).await;
+
+    let mut service = Service::new(dal.clone());
+    for now in [NOW, NOW + 1000, NOW + 2000] {
+        report(&dal, &vehicle.id, now, LandedState::InAir).await;
+        service.track_vehicles(now).await.expect("Error tracking vehicles");
+    }
+    let sessions = dal.vehicle_flight_sessions(&vehicle.id).await.expect("Error getting sessions");
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].landing_time, None);
+
+    // Restart mid-flight
+    drop(service);
+    let mut service = Service::new(dal.clone());
+    service.restore_sessions().await.expect("Error restoring sessions");
+
+    for now in [NOW + 60_000, NOW + 61_000, NOW + 66_000] {
+        report(&dal, &vehicle.id, now, LandedState::OnGround).await;
+        service.track_vehicles(now).await.expect("Error tracking vehicles");
+    }
+
+    let sessions = dal.vehicle_flight_sessions(&vehicle.id).await.expect("Error getting sessions");
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].landing_time, Some(NOW + 60_000));
+    assert_eq!(dal.active_flight_session(&vehicle.id).await.expect("Error getting session"), None);
+
+    let counters = dal.vehicle_counters(&vehicle.id).await.expect("Error getting counters");
+    assert_eq!(counters.cycles, 1);
+    assert_eq!(counters.flight_time, 60.0);
+}
+
+#[tokio::test]
+async fn test_sessions_deleted_with_vehicle() {
+    let (dal, _) = in_memory_dal().await;
+    let vehicle = setup_vehicle(&dal).await;
+
+    let mut service = Service::new(dal.clone());
+    for now in [NOW, NOW + 2000] {
+        report(&dal, &vehicle.id, now, LandedState::InAir).await;
+        service.track_vehicles(now).await.expect("Error tracking vehicles");
+    }
+    assert_eq!(dal.vehicle_flight_sessions(&vehicle.id).await.expect("Error getting sessions").len(), 1);
+
+    dal.delete_vehicle(&vehicle.id).await.expect("Error deleting vehicle");
+    service.track_vehicles(NOW + 3000).await.expect("Error tracking vehicles");
+
+    assert!(dal.all_flight_sessions().await.expect("Error getting sessions").is_empty());
+}
@@ -0,0 +1,128 @@
+use crate::models::flights::FlightSession;
+use crate::models::missions::MissionId;
+use crate::models::spatial::Geodetic;
+use crate::models::vehicles::{LandedState, VehicleId};
+
+const AIRBORNE_GROUND_SPEED: f32 = 3.0; // m/s
+const AIRBORNE_ALTITUDE: f32 = 5.0; // meters above home
+const TAKEOFF_CONFIRM_MS: i64 = 2000;
+const LANDING_CONFIRM_MS: i64 = 5000;
+
+// Vehicle state the flight session is tracked against
+pub struct FlightSample {
+    pub now: i64,
+    pub armed: bool,
+    pub landed_state: LandedState,
+    pub ground_speed: f32,
+    pub position: Geodetic,
+    pub home_altitude: f32
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SessionUpdate {
+    Started,
+    Progress,
+    Finished
+}
+
+pub fn is_airborne(sample: &FlightSample) -> bool {
+    match sample.landed_state {
+        LandedState::InAir | LandedState::Takeoff | LandedState::Landing => true,
+        LandedState::OnGround => false,
+        // Fallback for autopilots not reporting landed state
+        LandedState::Undefined => sample.armed && (
+            sample.ground_speed > AIRBORNE_GROUND_SPEED ||
+            sample.position.altitude - sample.home_altitude > AIRBORNE_ALTITUDE)
+    }
+}
+
+#[derive(Default)]
+pub struct SessionTracker {
+    session: Option<FlightSession>,
+    last_position: Option<Geodetic>,
+    airborne_since: Option<i64>,
+    grounded_since: Option<i64>
+}
+
+impl SessionTracker {
+    // Continues the stored session, distance over the tracking gap is not counted
+    pub fn resume(session: FlightSession) -> Self {
+        Self { session: Some(session), ..Self::default() }
+    }
+
+    pub fn is_flying(&self) -> bool {
+        self.session.is_some()
+    }
+
+    pub fn update(&mut self, vehicle_id: &VehicleId, mission_id: Option<MissionId>, sample: &FlightSample
+    ) -> Option<(SessionUpdate, FlightSession)> {
+        let airborne = is_airborne(sample);
+        if airborne {
+            self.grounded_since = None;
+        } else {
+            self.airborne_since = None;
+        }
+
+        match self.session.as_mut() {
+            None => {
+                if !airborne {
+                    return None;
+                }
+                let since = *self.airborne_since.get_or_insert(sample.now);
+                if sample.now - since < TAKEOFF_CONFIRM_MS {
+                    return None;
+                }
+
+                let session = FlightSession {
+                    id: String::new(),
+                    vehicle_id: vehicle_id.clone(),
+                    mission_id,
+                    takeoff_time: since,
+                    landing_time: None,
+                    duration: (sample.now - since) as f32 / 1000.0,
+                    distance: 0.0,
+                    max_altitude: sample.position.altitude,
+                    takeoff_position: sample.position.clone(),
+                    landing_position: None
+                };
+                self.last_position = Some(sample.position.clone());
+                self.session = Some(session.clone());
+                Some((SessionUpdate::Started, session))
+            },
+            Some(session) => {
+                if let Some(last_position) = &self.last_position {
+                    session.distance += last_position.distance_to(&sample.position) as f32;
+                }
+                self.last_position = Some(sample.position.clone());
+                session.max_altitude = session.max_altitude.max(sample.position.altitude);
+
+                if airborne {
+                    session.duration = (sample.now - session.takeoff_time) as f32 / 1000.0;
+                    return Some((SessionUpdate::Progress, session.clone()));
+                }
+
+                let since = *self.grounded_since.get_or_insert(sample.now);
+                if sample.now - since < LANDING_CONFIRM_MS {
+                    return Some((SessionUpdate::Progress, session.clone()));
+                }
+
+                session.landing_time = Some(since);
+                session.duration = (since - session.takeoff_time) as f32 / 1000.0;
+                session.landing_position = Some(sample.position.clone());
+                let session = session.clone();
+
+                self.session = None;
+                self.last_position = None;
+                self.grounded_since = None;
+                Some((SessionUpdate::Finished, session))
+            }
+        }
+    }
+
+    // Stored session gets its id on first save
+    pub fn set_session_id(&mut self, id: &str) {
+        if let Some(session) = self.session.as_mut() {
+            session.id = id.into();
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use test_case::test_case;
+
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use crate::models::vehicles::LandedState;
+use super::tracker::{is_airborne, FlightSample, SessionTracker, SessionUpdate};
+
+const NOW: i64 = 1_700_000_000_000;
+const HOME_ALTITUDE: f32 = 150.0;
+
+fn sample(now: i64, landed_state: LandedState, latitude: f64, altitude: f32) -> FlightSample {
+    FlightSample {
+        now,
+        armed: true,
+        landed_state,
+        ground_speed: 0.0,
+        position: Geodetic {
+            latitude,
+            longitude: 37.0,
+            altitude,
+            frame: GeodeticFrame::Wgs84AboveSeaLevel
+        },
+        home_altitude: HOME_ALTITUDE
+    }
+}
+
+#[test_case(LandedState::InAir, true; "in air")]
+#[test_case(LandedState::Takeoff, true; "takeoff")]
+#[test_case(LandedState::Landing, true; "landing")]
+#[test_case(LandedState::OnGround, false; "on ground")]
+fn test_airborne_from_landed_state(landed_state: LandedState, expected: bool) {
+    // Landed state takes precedence over altitude
+    assert_eq!(is_airborne(&sample(NOW, landed_state, 55.0, HOME_ALTITUDE + 50.0)), expected);
+}
+
+#[test_case(true, 0.0, HOME_ALTITUDE, false; "armed on ground")]
+#[test_case(true, 10.0, HOME_ALTITUDE, true; "armed moving")]
+#[test_case(true, 0.0, HOME_ALTITUDE + 20.0, true; "armed above home")]
+#[test_case(false, 10.0, HOME_ALTITUDE + 20.0, false; "disarmed")]
+fn test_airborne_fallback(armed: bool, ground_speed: f32, altitude: f32, expected: bool) {
+    let mut sample = sample(NOW, LandedState::Undefined, 55.0, altitude);
+    sample.armed = armed;
+    sample.ground_speed = ground_speed;
+    assert_eq!(is_airborne(&sample), expected);
+}
+
+#[test]
+fn test_flight_session() {
+    let vehicle_id = "mav_1".to_string();
+    let mission_id = Some("mission_1".to_string());
+    let mut tracker = SessionTracker::default();
+
+    assert!(tracker.update(&vehicle_id, mission_id.clone(), &sample(NOW, LandedState::OnGround, 55.0, 150.0)).is_none());
+    // Takeoff must be confirmed
+    assert!(tracker.update(&vehicle_id, mission_id.clone(), &sample(NOW + 1000, LandedState::InAir, 55.0, 160.0)).is_none());
+
+    let (update, session) = tracker.update(&vehicle_id, mission_id.clone(),
+        &sample(NOW + 3000, LandedState::InAir, 55.0, 170.0)).unwrap();
+    assert_eq!(update, SessionUpdate::Started);
+    assert_eq!(session.takeoff_time, NOW + 1000);
+    assert_eq!(session.mission_id, mission_id);
+    assert!(tracker.is_flying());
+
+    // 0.01 deg of latitude is about 1112 m
+    let (update, session) = tracker.update(&vehicle_id, None,
+        &sample(NOW + 60000, LandedState::InAir, 55.01, 300.0)).unwrap();
+    assert_eq!(update, SessionUpdate::Progress);
+    assert!((session.distance - 1112.0).abs() < 1.0);
+    assert_eq!(session.max_altitude, 300.0);
+    assert_eq!(session.duration, 59.0);
+
+    let (update, _) = tracker.update(&vehicle_id, None,
+        &sample(NOW + 120000, LandedState::OnGround, 55.0, 150.0)).unwrap();
+    assert_eq!(update, SessionUpdate::Progress);
+
+    let (update, session) = tracker.update(&vehicle_id, None,
+        &sample(NOW + 126000, LandedState::OnGround, 55.0, 150.0)).unwrap();
+    assert_eq!(update, SessionUpdate::Finished);
+    assert_eq!(session.landing_time, Some(NOW + 120000));
+    assert_eq!(session.duration, 119.0);
+    assert!((session.distance - 2224.0).abs() < 2.0);
+    assert_eq!(session.max_altitude, 300.0);
+    assert_eq!(session.mission_id, mission_id);
+    assert!(!tracker.is_flying());
+}
+
+#[test]
+fn test_touch_and_go_continues_session() {
+    let vehicle_id = "mav_1".to_string();
+    let mut tracker = SessionTracker::default();
+
+    tracker.update(&vehicle_id, None, &sample(NOW, LandedState::InAir, 55.0, 200.0));
+    tracker.update(&vehicle_id, None, &sample(NOW + 2000, LandedState::InAir, 55.0, 200.0));
+    assert!(tracker.is_flying());
+
+    let (update, _) = tracker.update(&vehicle_id, None, &sample(NOW + 10000, LandedState::OnGround, 55.0, 150.0)).unwrap();
+    assert_eq!(update, SessionUpdate::Progress);
+    let (update, session) = tracker.update(&vehicle_id, None, &sample(NOW + 12000, LandedState::InAir, 55.0, 160.0)).unwrap();
+    assert_eq!(update, SessionUpdate::Progress);
+    assert_eq!(session.landing_time, None);
+}
@@ -0,0 +1,3 @@
+pub mod schedule;
+#[cfg(test)]
+mod schedule_test;
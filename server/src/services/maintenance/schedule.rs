@@ -0,0 +1,68 @@
+use crate::dal::dal;
+use crate::models::maintenance::*;
+use crate::models::vehicles::VehicleId;
+
+const DUE_RATIO: f32 = 0.9; // used share of the interval when maintenance becomes due
+const MS_IN_DAY: f32 = 86_400_000.0;
+
+pub fn evaluate(
+    interval: &MaintenanceInterval,
+    counters: &VehicleCounters,
+    last_entry: Option<&MaintenanceEntry>,
+    now: i64
+) -> Option<MaintenanceNotice> {
+    // Count from the last performed maintenance, or from the interval creation
+    let (flight_time, cycles, timestamp) = match last_entry {
+        Some(entry) => (entry.flight_time, entry.cycles, entry.timestamp),
+        None => (0.0, 0, interval.created)
+    };
+    let mut used: f32 = 0.0;
+
+    let remaining_hours = interval.flight_hours.filter(|hours| *hours > 0.0).map(|hours| {
+        let spent = ((counters.flight_time - flight_time) / 3600.0) as f32;
+        used = used.max(spent / hours);
+        hours - spent
+    });
+    let remaining_cycles = interval.cycles.filter(|limit| *limit > 0).map(|limit| {
+        let spent = counters.cycles as i64 - cycles as i64;
+        used = used.max(spent as f32 / limit as f32);
+        limit as i64 - spent
+    });
+    let remaining_days = interval.days.filter(|days| *days > 0).map(|days| {
+        let spent = (now - timestamp) as f32 / MS_IN_DAY;
+        used = used.max(spent / days as f32);
+        days as f32 - spent
+    });
+
+    let state = if used >= 1.0 {
+        MaintenanceState::Overdue
+    } else if used >= DUE_RATIO {
+        MaintenanceState::Due
+    } else {
+        return None;
+    };
+
+    Some(MaintenanceNotice {
+        interval_id: interval.id.clone(),
+        vehicle_id: interval.vehicle_id.clone(),
+        name: interval.name.clone(),
+        state,
+        remaining_hours,
+        remaining_cycles,
+        remaining_days
+    })
+}
+
+pub async fn maintenance_notices(dal: &dal::Dal, vehicle_id: &VehicleId) -> anyhow::Result<Vec<MaintenanceNotice>> {
+    let counters = dal.vehicle_counters(vehicle_id).await?;
+    let entries = dal.vehicle_maintenance_entries(vehicle_id).await?;
+    let now = chrono::Utc::now().timestamp_millis();
+
+    Ok(dal.vehicle_maintenance_intervals(vehicle_id).await?.iter()
+        .filter_map(|interval| {
+            let last_entry = entries.iter().rev()
+                .find(|entry| entry.interval_id.as_ref() == Some(&interval.id));
+            evaluate(interval, &counters, last_entry, now)
+        })
+        .collect())
+}
@@ -0,0 +1,79 @@
+use test_case::test_case;
+
+use crate::models::maintenance::*;
+use super::schedule::evaluate;
+
+const NOW: i64 = 1_700_000_000_000;
+const DAY_MS: i64 = 86_400_000;
+
+fn interval(flight_hours: Option<f32>, cycles: Option<u32>, days: Option<u32>) -> MaintenanceInterval {
+    MaintenanceInterval {
+        id: "interval_1".into(),
+        vehicle_id: "mav_1".into(),
+        name: "Propeller check".into(),
+        flight_hours,
+        cycles,
+        days,
+        created: NOW - 10 * DAY_MS
+    }
+}
+
+fn counters(flight_hours: f64, cycles: u32) -> VehicleCounters {
+    VehicleCounters {
+        id: "mav_1".into(),
+        flight_time: flight_hours * 3600.0,
+        cycles,
+        arm_time: 0.0
+    }
+}
+
+#[test_case(10.0, None; "half used")]
+#[test_case(18.5, Some(MaintenanceState::Due); "due")]
+#[test_case(20.0, Some(MaintenanceState::Overdue); "exactly overdue")]
+#[test_case(25.0, Some(MaintenanceState::Overdue); "overdue")]
+fn test_flight_hours_interval(flight_hours: f64, expected: Option<MaintenanceState>) {
+    let notice = evaluate(&interval(Some(20.0), None, None), &counters(flight_hours, 0), None, NOW);
+    assert_eq!(notice.as_ref().map(|notice| notice.state.clone()), expected);
+    if let Some(notice) = notice {
+        assert_eq!(notice.remaining_hours, Some(20.0 - flight_hours as f32));
+        assert_eq!(notice.remaining_cycles, None);
+        assert_eq!(notice.remaining_days, None);
+    }
+}
+
+#[test]
+fn test_counted_from_last_entry() {
+    let entry = MaintenanceEntry {
+        id: "entry_1".into(),
+        vehicle_id: "mav_1".into(),
+        interval_id: Some("interval_1".into()),
+        timestamp: NOW - DAY_MS,
+        notes: "Replaced propellers".into(),
+        flight_time: 19.0 * 3600.0,
+        cycles: 40
+    };
+
+    let interval = interval(Some(20.0), Some(50), Some(30));
+    assert!(evaluate(&interval, &counters(21.0, 45), None, NOW).is_some());
+    assert_eq!(evaluate(&interval, &counters(21.0, 45), Some(&entry), NOW), None);
+
+    let notice = evaluate(&interval, &counters(21.0, 86), Some(&entry), NOW).unwrap();
+    assert_eq!(notice.state, MaintenanceState::Due);
+    assert_eq!(notice.remaining_hours, Some(18.0));
+    assert_eq!(notice.remaining_cycles, Some(4));
+    assert_eq!(notice.remaining_days, Some(29.0));
+}
+
+#[test]
+fn test_calendar_interval() {
+    let interval = interval(None, None, Some(10));
+    let notice = evaluate(&interval, &counters(0.0, 0), None, NOW + DAY_MS).unwrap();
+    assert_eq!(notice.state, MaintenanceState::Overdue);
+    assert_eq!(notice.remaining_days, Some(-1.0));
+}
+
+#[test]
+fn test_empty_interval_never_due() {
+    let interval = interval(Some(0.0), None, None);
+    assert_eq!(evaluate(&interval, &counters(100.0, 100), None, NOW), None);
+}
@@ -0,0 +1,87 @@
+use crate::models::mission_patterns::{CorridorRequest, GeneratedPattern};
+use super::patterns::{camera_spacing, distance, ground_sample_distance, order_passes, pass_items, pattern_altitude,
+    spread_lines, validate_camera, LocalPlane, Pass};
+
+// Limits the mitre of sharp corners to this many offsets
+const MAX_MITRE: f64 = 4.0;
+
+fn validate_request(request: &CorridorRequest) -> anyhow::Result<()> {
+    if request.width <= 0.0 {
+        return Err(anyhow::anyhow!("Corridor width must be positive"));
+    }
+    validate_camera(&request.camera, request.front_overlap, request.side_overlap)?;
+    if request.turnaround < 0.0 {
+        return Err(anyhow::anyhow!("Corridor turnaround distance can't be negative"));
+    }
+    Ok(())
+}
+
+// Right-hand unit normal of the leg
+fn normal(from: (f64, f64), to: (f64, f64)) -> (f64, f64) {
+    let length = distance(from, to);
+    ((to.1 - from.1) / length, -(to.0 - from.0) / length)
+}
+
+// Parallel of the path at the offset to the right, negative offsets go to the left
+fn offset_path(points: &[(f64, f64)], offset: f64) -> Vec<(f64, f64)> {
+    let count = points.len();
+    (0..count).map(|index| {
+        let point = points[index];
+        let incoming = if index > 0 { Some(normal(points[index - 1], point)) } else { None };
+        let outgoing = if index + 1 < count { Some(normal(point, points[index + 1])) } else { None };
+
+        let (x, y) = match (incoming, outgoing) {
+            (Some(incoming), Some(outgoing)) => {
+                let bisector = (incoming.0 + outgoing.0, incoming.1 + outgoing.1);
+                let length = bisector.0.hypot(bisector.1);
+                if length < f64::EPSILON {
+                    incoming
+                } else {
+                    let bisector = (bisector.0 / length, bisector.1 / length);
+                    let mitre = 1.0 / (bisector.0 * incoming.0 + bisector.1 * incoming.1).max(1.0 / MAX_MITRE);
+                    (bisector.0 * mitre, bisector.1 * mitre)
+                }
+            },
+            (Some(normal), None) | (None, Some(normal)) => normal,
+            (None, None) => (0.0, 0.0)
+        };
+        (point.0 + x * offset, point.1 + y * offset)
+    }).collect()
+}
+
+// Parallel passes along the centerline, spread over the corridor width
+pub fn corridor_pattern(request: &CorridorRequest) -> anyhow::Result<GeneratedPattern> {
+    validate_request(request)?;
+    let altitude = pattern_altitude(&request.camera, &request.resolution)?;
+    let (line_spacing, trigger_distance) = camera_spacing(&request.camera, altitude, request.front_overlap, request.side_overlap);
+
+    let plane = LocalPlane::around(&request.path);
+    let mut points: Vec<(f64, f64)> = request.path.iter().map(|position| plane.point(position)).collect();
+    points.dedup_by(|point, previous| distance(*previous, *point) < f64::EPSILON);
+    if points.len() < 2 {
+        return Err(anyhow::anyhow!("Corridor path needs at least 2 distinct points"));
+    }
+
+    let (count, first) = spread_lines(request.width as f64, line_spacing as f64)?;
+    let passes: Vec<Pass> = (0..count)
+        .map(|line| Pass { points: offset_path(&points, first + line as f64 * line_spacing as f64) })
+        .collect();
+    let passes = order_passes(
+        &passes,
+        request.entry.as_ref().map(|entry| plane.point(entry)),
+        request.exit.as_ref().map(|exit| plane.point(exit))
+    );
+    let (items, length, images) = pass_items(&passes, &plane, altitude, &request.frame,
+        request.turnaround as f64, trigger_distance);
+
+    Ok(GeneratedPattern {
+        items,
+        altitude,
+        gsd: ground_sample_distance(&request.camera, altitude),
+        line_spacing,
+        trigger_distance,
+        lines: passes.len() as u16,
+        length,
+        images
+    })
+}
@@ -0,0 +1,123 @@
+use test_case::test_case;
+
+use crate::models::missions::MissionRouteItem;
+use crate::models::mission_patterns::{CorridorRequest, SurveyCamera, SurveyResolution};
+use crate::models::spatial::{Enu, Geodetic, GeodeticFrame};
+use super::corridor::corridor_pattern;
+use super::patterns::LocalPlane;
+
+// Footprint at 100 meters is 150 by 100, so passes are 45 and images 20 meters apart
+fn camera() -> SurveyCamera {
+    SurveyCamera { sensor_width: 13.2, sensor_height: 8.8, focal_length: 8.8, image_width: 5472, image_height: 3648 }
+}
+
+fn point(east: f64, north: f64) -> Geodetic {
+    Geodetic { latitude: 55.75, longitude: 37.6, altitude: 0.0, frame: GeodeticFrame::None }
+        .offset_enu(&Enu { east, north, up: 0.0 })
+}
+
+// Straight corridor of 410 meters to the north
+fn request() -> CorridorRequest {
+    CorridorRequest {
+        path: vec![point(0.0, -205.0), point(0.0, 205.0)],
+        width: 100.0,
+        camera: camera(),
+        resolution: SurveyResolution::Altitude { altitude: 100.0 },
+        frame: GeodeticFrame::Wgs84RelativeHome,
+        front_overlap: 80.0,
+        side_overlap: 70.0,
+        turnaround: 20.0,
+        entry: None,
+        exit: None
+    }
+}
+
+fn points(request: &CorridorRequest, items: &[MissionRouteItem]) -> Vec<(f64, f64)> {
+    let plane = LocalPlane::around(&request.path);
+    items.iter().filter_map(|item| match item {
+        MissionRouteItem::Waypoint { position, .. } => Some(plane.point(position)),
+        _ => None
+    }).collect()
+}
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!((actual - expected).abs() <= tolerance, "{} != {} within {}", actual, expected, tolerance);
+}
+
+fn assert_points(actual: &[(f64, f64)], expected: &[(f64, f64)]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual, expected) in actual.iter().zip(expected.iter()) {
+        assert_close(actual.0, expected.0, 0.01);
+        assert_close(actual.1, expected.1, 0.01);
+    }
+}
+
+#[test]
+fn test_straight_corridor() {
+    let request = request();
+    let pattern = corridor_pattern(&request).unwrap();
+
+    assert_eq!(pattern.lines, 3);
+    assert_eq!(pattern.items.len(), 3 * 6);
+    assert_eq!(pattern.images, 3 * 21);
+    assert_close(pattern.length, 3.0 * 450.0 + 2.0 * 45.0, 0.1);
+    assert_points(&points(&request, &pattern.items)[..8], &[
+        (-45.0, -225.0), (-45.0, -205.0), (-45.0, 205.0), (-45.0, 225.0),
+        (0.0, 225.0), (0.0, 205.0), (0.0, -205.0), (0.0, -225.0)
+    ]);
+}
+
+#[test]
+fn test_corridor_corner() {
+    let request = CorridorRequest {
+        path: vec![point(0.0, -200.0), point(0.0, 0.0), point(200.0, 0.0)],
+        turnaround: 0.0,
+        ..request()
+    };
+    let pattern = corridor_pattern(&request).unwrap();
+    let plane = LocalPlane::around(&request.path);
+    let corner = plane.point(&point(0.0, 0.0));
+    let points: Vec<(f64, f64)> = points(&request, &pattern.items).iter()
+        .map(|(east, north)| (east - corner.0, north - corner.1))
+        .collect();
+
+    assert_eq!(pattern.items.len(), 3 * 5);
+    // Outer corner on the left, inner one on the right
+    assert_points(&points, &[
+        (-45.0, -200.0), (-45.0, 45.0), (200.0, 45.0),
+        (200.0, 0.0), (0.0, 0.0), (0.0, -200.0),
+        (45.0, -200.0), (45.0, -45.0), (200.0, -45.0)
+    ]);
+}
+
+#[test]
+fn test_corridor_entry() {
+    let request = CorridorRequest { entry: Some(point(60.0, 260.0)), ..request() };
+    let pattern = corridor_pattern(&request).unwrap();
+
+    assert_points(&points(&request, &pattern.items)[..2], &[(45.0, 225.0), (45.0, 205.0)]);
+}
+
+#[test]
+fn test_corridor_items() {
+    let pattern = corridor_pattern(&request()).unwrap();
+
+    match &pattern.items[0] {
+        MissionRouteItem::Waypoint { position, .. } => {
+            assert_eq!(position.altitude, 100.0);
+            assert_eq!(position.frame, GeodeticFrame::Wgs84RelativeHome);
+        },
+        item => panic!("Unexpected item {:?}", item)
+    }
+    assert!(matches!(pattern.items[2], MissionRouteItem::TriggerCam { trigger: true, .. }));
+    assert!(matches!(pattern.items[4], MissionRouteItem::TriggerCam { trigger: false, .. }));
+}
+
+#[test_case(CorridorRequest { width: 0.0, ..request() }; "no width")]
+#[test_case(CorridorRequest { path: vec![point(0.0, 0.0)], ..request() }; "single point")]
+#[test_case(CorridorRequest { path: vec![point(0.0, 0.0), point(0.0, 0.0)], ..request() }; "same points")]
+#[test_case(CorridorRequest { front_overlap: 100.0, ..request() }; "full overlap")]
+#[test_case(CorridorRequest { turnaround: -5.0, ..request() }; "negative turnaround")]
+fn test_invalid_request(request: CorridorRequest) {
+    assert!(corridor_pattern(&request).is_err());
+}
@@ -0,0 +1,21 @@
+pub mod route_edit;
+#[cfg(test)]
+mod route_edit_test;
+pub mod route_diff;
+#[cfg(test)]
+mod route_diff_test;
+pub mod validation;
+#[cfg(test)]
+mod validation_test;
+pub mod patterns;
+#[cfg(test)]
+mod patterns_test;
+pub mod survey;
+#[cfg(test)]
+mod survey_test;
+pub mod corridor;
+#[cfg(test)]
+mod corridor_test;
+pub mod structure;
+#[cfg(test)]
+mod structure_test;
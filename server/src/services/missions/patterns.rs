@@ -0,0 +1,220 @@
+use crate::models::missions::MissionRouteItem;
+use crate::models::mission_patterns::{GeneratedPattern, PatternParameters, SurveyCamera, SurveyResolution};
+use crate::models::spatial::{Enu, Geodetic, GeodeticFrame};
+use super::{corridor, structure, survey};
+
+pub const MAX_PATTERN_LINES: usize = 1000;
+
+// Local tangent plane of the pattern, points are east and north offsets in meters
+pub struct LocalPlane {
+    origin: Geodetic
+}
+
+impl LocalPlane {
+    pub fn new(origin: &Geodetic) -> Self {
+        Self { origin: Geodetic { altitude: 0.0, frame: GeodeticFrame::None, ..origin.clone() } }
+    }
+
+    pub fn around(positions: &[Geodetic]) -> Self {
+        let count = positions.len().max(1) as f64;
+        Self::new(&Geodetic {
+            latitude: positions.iter().map(|position| position.latitude).sum::<f64>() / count,
+            longitude: positions.iter().map(|position| position.longitude).sum::<f64>() / count,
+            ..Geodetic::default()
+        })
+    }
+
+    pub fn point(&self, position: &Geodetic) -> (f64, f64) {
+        let enu = self.origin.enu_to(&Geodetic { altitude: 0.0, ..position.clone() });
+        (enu.east, enu.north)
+    }
+
+    pub fn position(&self, (east, north): (f64, f64), altitude: f32, frame: &GeodeticFrame) -> Geodetic {
+        let position = self.origin.offset_enu(&Enu { east, north, up: 0.0 });
+        Geodetic { altitude, frame: frame.clone(), ..position }
+    }
+}
+
+// Larger of the pixel width and height on the sensor, millimeters
+fn pixel_size(camera: &SurveyCamera) -> f32 {
+    (camera.sensor_width / camera.image_width as f32).max(camera.sensor_height / camera.image_height as f32)
+}
+
+// Centimeters per pixel at the distance to the ground or structure
+pub fn ground_sample_distance(camera: &SurveyCamera, distance: f32) -> f32 {
+    pixel_size(camera) * distance * 100.0 / camera.focal_length
+}
+
+pub fn altitude_for_gsd(camera: &SurveyCamera, gsd: f32) -> f32 {
+    gsd * camera.focal_length / (pixel_size(camera) * 100.0)
+}
+
+// Size of one image across and along the track, meters
+pub fn image_footprint(camera: &SurveyCamera, distance: f32) -> (f32, f32) {
+    (camera.sensor_width * distance / camera.focal_length, camera.sensor_height * distance / camera.focal_length)
+}
+
+pub fn validate_camera(camera: &SurveyCamera, front_overlap: f32, side_overlap: f32) -> anyhow::Result<()> {
+    if camera.sensor_width <= 0.0 || camera.sensor_height <= 0.0 || camera.focal_length <= 0.0 ||
+        camera.image_width == 0 || camera.image_height == 0 {
+        return Err(anyhow::anyhow!("Camera parameters must be positive"));
+    }
+    if !(0.0..100.0).contains(&front_overlap) || !(0.0..100.0).contains(&side_overlap) {
+        return Err(anyhow::anyhow!("Overlap must be within [0, 100) percents"));
+    }
+    Ok(())
+}
+
+pub fn pattern_altitude(camera: &SurveyCamera, resolution: &SurveyResolution) -> anyhow::Result<f32> {
+    let altitude = match resolution {
+        SurveyResolution::Gsd { gsd } => altitude_for_gsd(camera, *gsd),
+        SurveyResolution::Altitude { altitude } => *altitude
+    };
+    if altitude <= 0.0 || !altitude.is_finite() {
+        return Err(anyhow::anyhow!("Pattern altitude must be positive"));
+    }
+    Ok(altitude)
+}
+
+// Spacing between lines and distance between images for the overlaps
+pub fn camera_spacing(camera: &SurveyCamera, distance: f32, front_overlap: f32, side_overlap: f32) -> (f32, f32) {
+    let (across, along) = image_footprint(camera, distance);
+    (across * (1.0 - side_overlap / 100.0), along * (1.0 - front_overlap / 100.0))
+}
+
+// Count of lines evenly spread over the width and the offset of the first one from the middle
+pub fn spread_lines(width: f64, spacing: f64) -> anyhow::Result<(usize, f64)> {
+    let count = ((width / spacing).ceil() as usize).max(1);
+    if count > MAX_PATTERN_LINES {
+        return Err(anyhow::anyhow!("Pattern needs {} lines, more than {} allowed", count, MAX_PATTERN_LINES));
+    }
+    Ok((count, -((count - 1) as f64) * spacing / 2.0))
+}
+
+pub fn distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+    (to.0 - from.0).hypot(to.1 - from.1)
+}
+
+// Camera is triggered from the first point of the pass to the last one
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pass {
+    pub points: Vec<(f64, f64)>
+}
+
+impl Pass {
+    fn start(&self) -> (f64, f64) {
+        self.points[0]
+    }
+
+    fn end(&self) -> (f64, f64) {
+        self.points[self.points.len() - 1]
+    }
+
+    fn length(&self) -> f64 {
+        self.points.windows(2).map(|leg| distance(leg[0], leg[1])).sum()
+    }
+
+    // Continues the first or last leg of the pass by the distance
+    fn extended(&self, distance_before: f64, distance_after: f64) -> ((f64, f64), (f64, f64)) {
+        let extend = |from: (f64, f64), to: (f64, f64), by: f64| {
+            let length = distance(from, to);
+            if length > 0.0 {
+                (to.0 + (to.0 - from.0) / length * by, to.1 + (to.1 - from.1) / length * by)
+            } else {
+                to
+            }
+        };
+        let count = self.points.len();
+        (extend(self.points[1], self.points[0], distance_before), extend(self.points[count - 2], self.points[count - 1], distance_after))
+    }
+}
+
+// Boustrophedon order, every other pass is flown backwards
+fn oriented_passes(passes: &[Pass], reversed: bool, forward: bool) -> Vec<Pass> {
+    let mut ordered = passes.to_vec();
+    if reversed {
+        ordered.reverse();
+    }
+    for (index, pass) in ordered.iter_mut().enumerate() {
+        if forward != (index % 2 == 0) {
+            pass.points.reverse();
+        }
+    }
+    ordered
+}
+
+// Picks the corner to enter the passes at, so that approach and departure legs are shortest
+pub fn order_passes(passes: &[Pass], entry: Option<(f64, f64)>, exit: Option<(f64, f64)>) -> Vec<Pass> {
+    [(false, true), (false, false), (true, true), (true, false)].into_iter()
+        .map(|(reversed, forward)| {
+            let ordered = oriented_passes(passes, reversed, forward);
+            let cost = entry.zip(ordered.first()).map_or(0.0, |(entry, pass)| distance(entry, pass.start())) +
+                exit.zip(ordered.last()).map_or(0.0, |(exit, pass)| distance(pass.end(), exit));
+            (ordered, cost)
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(ordered, _)| ordered)
+        .unwrap_or_default()
+}
+
+pub fn waypoint(position: Geodetic) -> MissionRouteItem {
+    MissionRouteItem::Waypoint { position, hold: 0, pass_radius: 0.0, accept_radius: 0.0, yaw: None }
+}
+
+pub fn start_trigger(distance: f32) -> MissionRouteItem {
+    MissionRouteItem::TriggerCam { distance, shutter: 0, trigger: true }
+}
+
+pub fn stop_trigger() -> MissionRouteItem {
+    MissionRouteItem::TriggerCam { distance: 0.0, shutter: 0, trigger: false }
+}
+
+pub fn images_count(length: f64, trigger_distance: f32) -> u32 {
+    (length / trigger_distance as f64).floor() as u32 + 1
+}
+
+// Waypoints of ordered passes with run-in and run-out turnaround legs, path length and images count
+pub fn pass_items(passes: &[Pass], plane: &LocalPlane, altitude: f32, frame: &GeodeticFrame,
+    turnaround: f64, trigger_distance: f32) -> (Vec<MissionRouteItem>, f64, u32) {
+    let mut items = Vec::new();
+    let mut path = Vec::new();
+    let mut images = 0;
+    for pass in passes.iter() {
+        let (run_in, run_out) = pass.extended(turnaround, turnaround);
+        let position = |point: (f64, f64)| plane.position(point, altitude, frame);
+
+        if turnaround > 0.0 {
+            items.push(waypoint(position(run_in)));
+        }
+        items.push(waypoint(position(pass.start())));
+        items.push(start_trigger(trigger_distance));
+        items.extend(pass.points[1..].iter().map(|point| waypoint(position(*point))));
+        items.push(stop_trigger());
+        if turnaround > 0.0 {
+            items.push(waypoint(position(run_out)));
+        }
+
+        path.push(run_in);
+        path.extend(pass.points.iter().cloned());
+        path.push(run_out);
+        images += images_count(pass.length(), trigger_distance);
+    }
+    let length = path.windows(2).map(|leg| distance(leg[0], leg[1])).sum();
+    (items, length, images)
+}
+
+pub fn generate_pattern(parameters: &PatternParameters) -> anyhow::Result<GeneratedPattern> {
+    match parameters {
+        PatternParameters::Survey { request } => survey::survey_pattern(request),
+        PatternParameters::Corridor { request } => corridor::corridor_pattern(request),
+        PatternParameters::Structure { request } => structure::structure_pattern(request)
+    }
+}
+
+// Start of the generated items in the route, if they are still there unchanged
+pub fn find_pattern_items(route: &[MissionRouteItem], items: &[MissionRouteItem]) -> Option<usize> {
+    if items.is_empty() {
+        return None;
+    }
+    route.windows(items.len()).position(|window| window == items)
+}
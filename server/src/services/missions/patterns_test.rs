@@ -0,0 +1,127 @@
+use test_case::test_case;
+
+use crate::models::missions::MissionRouteItem;
+use crate::models::mission_patterns::{SurveyCamera, SurveyResolution};
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use super::patterns::*;
+
+// 1" sensor with 20 MP, footprint at 100 meters is 150 by 100
+fn camera() -> SurveyCamera {
+    SurveyCamera { sensor_width: 13.2, sensor_height: 8.8, focal_length: 8.8, image_width: 5472, image_height: 3648 }
+}
+
+fn plane() -> LocalPlane {
+    LocalPlane::new(&Geodetic { latitude: 55.75, longitude: 37.6, altitude: 0.0, frame: GeodeticFrame::None })
+}
+
+fn wpt(hold: u16) -> MissionRouteItem {
+    MissionRouteItem::Waypoint { position: Geodetic::default(), hold, pass_radius: 0.0, accept_radius: 0.0, yaw: None }
+}
+
+fn points(items: &[MissionRouteItem], plane: &LocalPlane) -> Vec<(f64, f64)> {
+    items.iter().filter_map(|item| match item {
+        MissionRouteItem::Waypoint { position, .. } => Some(plane.point(position)),
+        _ => None
+    }).collect()
+}
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!((actual - expected).abs() <= tolerance, "{} != {} within {}", actual, expected, tolerance);
+}
+
+fn assert_point(actual: (f64, f64), expected: (f64, f64)) {
+    assert_close(actual.0, expected.0, 0.01);
+    assert_close(actual.1, expected.1, 0.01);
+}
+
+#[test_case(100.0, 2.7412; "at hundred meters")]
+#[test_case(50.0, 1.3706; "at fifty meters")]
+fn test_ground_sample_distance(distance: f32, gsd: f64) {
+    assert_close(ground_sample_distance(&camera(), distance) as f64, gsd, 1e-4);
+}
+
+#[test]
+fn test_gsd_worst_pixel_side() {
+    let camera = SurveyCamera { image_height: 1824, ..camera() };
+    assert_close(ground_sample_distance(&camera, 100.0) as f64, 5.4825, 1e-4);
+}
+
+#[test]
+fn test_altitude_for_gsd() {
+    let gsd = ground_sample_distance(&camera(), 120.0);
+    assert_close(altitude_for_gsd(&camera(), gsd) as f64, 120.0, 1e-3);
+    assert_close(pattern_altitude(&camera(), &SurveyResolution::Gsd { gsd }).unwrap() as f64, 120.0, 1e-3);
+}
+
+#[test]
+fn test_camera_spacing() {
+    let (across, along) = image_footprint(&camera(), 100.0);
+    assert_close(across as f64, 150.0, 1e-3);
+    assert_close(along as f64, 100.0, 1e-3);
+
+    let (line_spacing, trigger_distance) = camera_spacing(&camera(), 100.0, 80.0, 70.0);
+    assert_close(line_spacing as f64, 45.0, 1e-3);
+    assert_close(trigger_distance as f64, 20.0, 1e-3);
+}
+
+#[test_case(300.0, 45.0, 7, -135.0; "partial spacing")]
+#[test_case(90.0, 45.0, 2, -22.5; "exact spacing")]
+#[test_case(10.0, 45.0, 1, 0.0; "narrow")]
+fn test_spread_lines(width: f64, spacing: f64, count: usize, first: f64) {
+    let (actual_count, actual_first) = spread_lines(width, spacing).unwrap();
+    assert_eq!(actual_count, count);
+    assert_close(actual_first, first, 1e-9);
+}
+
+#[test]
+fn test_spread_too_many_lines() {
+    assert!(spread_lines(10000.0, 1.0).is_err());
+}
+
+#[test]
+fn test_order_passes() {
+    let passes: Vec<Pass> = [0.0, 10.0, 20.0].iter()
+        .map(|x| Pass { points: vec![(*x, 0.0), (*x, 100.0)] })
+        .collect();
+
+    let ordered = order_passes(&passes, None, None);
+    assert_eq!(ordered[0].points, vec![(0.0, 0.0), (0.0, 100.0)]);
+    assert_eq!(ordered[1].points, vec![(10.0, 100.0), (10.0, 0.0)]);
+
+    let ordered = order_passes(&passes, Some((25.0, 110.0)), None);
+    assert_eq!(ordered[0].points, vec![(20.0, 100.0), (20.0, 0.0)]);
+    assert_eq!(ordered[2].points, vec![(0.0, 100.0), (0.0, 0.0)]);
+
+    let ordered = order_passes(&passes, None, Some((25.0, -10.0)));
+    assert_eq!(ordered[2].points, vec![(20.0, 100.0), (20.0, 0.0)]);
+}
+
+#[test]
+fn test_pass_items_turnaround() {
+    let plane = plane();
+    let passes = vec![Pass { points: vec![(0.0, 0.0), (0.0, 100.0), (100.0, 100.0)] }];
+    let (items, length, images) = pass_items(&passes, &plane, 50.0, &GeodeticFrame::Wgs84RelativeHome, 10.0, 20.0);
+
+    assert_eq!(items.len(), 7);
+    assert!(matches!(items[2], MissionRouteItem::TriggerCam { distance, trigger: true, .. } if distance == 20.0));
+    assert!(matches!(items[5], MissionRouteItem::TriggerCam { distance, trigger: false, .. } if distance == 0.0));
+
+    let points = points(&items, &plane);
+    assert_point(points[0], (0.0, -10.0));
+    assert_point(points[1], (0.0, 0.0));
+    assert_point(points[3], (100.0, 100.0));
+    assert_point(points[4], (110.0, 100.0));
+    assert_close(length, 220.0, 1e-9);
+    assert_eq!(images, 11);
+}
+
+#[test_case(&[0, 1, 2, 3], &[1, 2], Some(1); "inside")]
+#[test_case(&[0, 1, 2, 3], &[2, 3], Some(2); "at end")]
+#[test_case(&[0, 1, 2, 3], &[2, 1], None; "reordered")]
+#[test_case(&[0, 1, 2, 3], &[], None; "empty pattern")]
+#[test_case(&[0], &[0, 1], None; "longer than route")]
+fn test_find_pattern_items(route: &[u16], pattern: &[u16], expected: Option<usize>) {
+    let route: Vec<MissionRouteItem> = route.iter().map(|hold| wpt(*hold)).collect();
+    let pattern: Vec<MissionRouteItem> = pattern.iter().map(|hold| wpt(*hold)).collect();
+    assert_eq!(find_pattern_items(&route, &pattern), expected);
+}
@@ -0,0 +1,28 @@
+use crate::models::missions::{MissionRouteItem, RouteDiff, RouteItemChange};
+
+// Common head and tail are skipped, the rest is compared pairwise, so single insertion
+// or removal doesn't mark all following items as changed
+pub fn diff_routes(before: &[MissionRouteItem], after: &[MissionRouteItem]) -> RouteDiff {
+    let head = before.iter().zip(after.iter())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let tail = before[head..].iter().rev().zip(after[head..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let removed = &before[head..before.len() - tail];
+    let added = &after[head..after.len() - tail];
+    let common = removed.len().min(added.len());
+
+    let mut changes = Vec::new();
+    for (offset, (old, new)) in removed.iter().zip(added.iter()).enumerate() {
+        changes.push(RouteItemChange::Changed { index: (head + offset) as u16, before: old.clone(), after: new.clone() });
+    }
+    for (offset, item) in removed.iter().enumerate().skip(common) {
+        changes.push(RouteItemChange::Removed { index: (head + offset) as u16, item: item.clone() });
+    }
+    for (offset, item) in added.iter().enumerate().skip(common) {
+        changes.push(RouteItemChange::Added { index: (head + offset) as u16, item: item.clone() });
+    }
+    RouteDiff { changes }
+}
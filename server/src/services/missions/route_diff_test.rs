@@ -0,0 +1,40 @@
+use test_case::test_case;
+
+use crate::models::missions::{MissionRouteItem, RouteItemChange};
+use crate::models::spatial::Geodetic;
+use super::route_diff::diff_routes;
+
+fn wpt(hold: u16) -> MissionRouteItem {
+    MissionRouteItem::Waypoint { position: Geodetic::default(), hold, pass_radius: 0.0, accept_radius: 0.0, yaw: None }
+}
+
+fn route(holds: &[u16]) -> Vec<MissionRouteItem> {
+    holds.iter().map(|hold| wpt(*hold)).collect()
+}
+
+#[test_case(&[], &[], vec![]; "both empty")]
+#[test_case(&[0, 1, 2], &[0, 1, 2], vec![]; "same routes")]
+#[test_case(&[], &[0, 1], vec![
+    RouteItemChange::Added { index: 0, item: wpt(0) },
+    RouteItemChange::Added { index: 1, item: wpt(1) }
+]; "new route")]
+#[test_case(&[0, 1, 2], &[0, 7, 1, 2], vec![
+    RouteItemChange::Added { index: 1, item: wpt(7) }
+]; "insertion")]
+#[test_case(&[0, 1, 2, 3], &[0, 3], vec![
+    RouteItemChange::Removed { index: 1, item: wpt(1) },
+    RouteItemChange::Removed { index: 2, item: wpt(2) }
+]; "removal")]
+#[test_case(&[0, 1, 2], &[0, 5, 2], vec![
+    RouteItemChange::Changed { index: 1, before: wpt(1), after: wpt(5) }
+]; "change")]
+#[test_case(&[0, 1, 2, 3], &[0, 5, 3], vec![
+    RouteItemChange::Changed { index: 1, before: wpt(1), after: wpt(5) },
+    RouteItemChange::Removed { index: 2, item: wpt(2) }
+]; "change and removal")]
+#[test_case(&[1, 1], &[1, 1, 1], vec![
+    RouteItemChange::Added { index: 2, item: wpt(1) }
+]; "repeated items")]
+fn test_diff_routes(before: &[u16], after: &[u16], expected: Vec<RouteItemChange>) {
+    assert_eq!(diff_routes(&route(before), &route(after)).changes, expected);
+}
@@ -0,0 +1,119 @@
+use crate::models::missions::{MissionRouteItem, RouteEdit};
+
+// Applies edit to a copy, so failed edit leaves items untouched. Jumps of the edited items follow their targets.
+pub fn apply_edit(items: &[MissionRouteItem], edit: &RouteEdit) -> anyhow::Result<Vec<MissionRouteItem>> {
+    // Each item keeps its source index, new items have none and their jumps are taken as is
+    let mut result: Vec<(Option<usize>, MissionRouteItem)> = items.iter().cloned()
+        .enumerate()
+        .map(|(index, item)| (Some(index), item))
+        .collect();
+    match edit {
+        RouteEdit::Replace { items } => {
+            result = items.iter().cloned().map(|item| (None, item)).collect();
+        },
+        RouteEdit::Insert { index, items } => {
+            let index = *index as usize;
+            // Fill with gaps like upserting an item beyond the route end
+            while index > result.len() {
+                result.push((None, MissionRouteItem::Gap {}));
+            }
+            result.splice(index..index, items.iter().cloned().map(|item| (None, item)));
+        },
+        RouteEdit::Move { from, count, to } => {
+            let range = checked_range(*from, *count, result.len())?;
+            let moved: Vec<(Option<usize>, MissionRouteItem)> = result.drain(range).collect();
+            let to = *to as usize;
+            if to > result.len() {
+                return Err(anyhow::anyhow!("Move target {} is out of route bounds", to));
+            }
+            result.splice(to..to, moved);
+        },
+        RouteEdit::Reorder { order } => {
+            if !is_permutation(order, result.len()) {
+                return Err(anyhow::anyhow!("Reorder must contain each of {} route indices once", result.len()));
+            }
+            result = order.iter().map(|&index| result[index as usize].clone()).collect();
+        },
+        RouteEdit::Duplicate { from, count, to } => {
+            let range = checked_range(*from, *count, result.len())?;
+            let to = *to as usize;
+            if to > result.len() {
+                return Err(anyhow::anyhow!("Duplicate target {} is out of route bounds", to));
+            }
+            // Jumps inside the copied block stay inside the copy
+            let shifted = |target: usize| if target < to { target } else { target + range.len() };
+            let copies: Vec<(Option<usize>, MissionRouteItem)> = result[range.clone()].iter()
+                .map(|(_, item)| (None, with_jump_target(item, |target| if range.contains(&target) {
+                    Some(to + target - range.start)
+                } else {
+                    Some(shifted(target))
+                })))
+                .collect();
+            result.splice(to..to, copies);
+        },
+    }
+    if result.len() > u16::MAX as usize {
+        return Err(anyhow::anyhow!("Route is too long: {} items", result.len()));
+    }
+
+    let mut positions = vec![None; items.len()];
+    for (position, (source, _)) in result.iter().enumerate() {
+        if let Some(source) = source {
+            positions[*source] = Some(position);
+        }
+    }
+    Ok(result.into_iter()
+        .map(|(source, item)| match source {
+            Some(_) => with_jump_target(&item, |target| positions.get(target).copied().flatten()),
+            None => item
+        })
+        .collect())
+}
+
+// Replaces range of items, jumps of the other items follow their targets, jumps into the range go to its start
+pub fn splice_items(items: &mut Vec<MissionRouteItem>, range: std::ops::Range<usize>, replacement: Vec<MissionRouteItem>) {
+    let inserted = replacement.len();
+    items.splice(range.clone(), replacement);
+    let target_of = |target: usize| Some(if target < range.start {
+        target
+    } else if target < range.end {
+        range.start
+    } else {
+        target - range.len() + inserted
+    });
+    for (index, item) in items.iter_mut().enumerate() {
+        if index < range.start || index >= range.start + inserted {
+            *item = with_jump_target(item, target_of);
+        }
+    }
+}
+
+// Targets with no new position are left as they were
+fn with_jump_target<F>(item: &MissionRouteItem, target_of: F) -> MissionRouteItem
+where F: Fn(usize) -> Option<usize> {
+    match item {
+        MissionRouteItem::DoJump { target, repeat } => MissionRouteItem::DoJump {
+            target: target_of(*target as usize)
+                .and_then(|target| u16::try_from(target).ok())
+                .unwrap_or(*target),
+            repeat: *repeat
+        },
+        _ => item.clone()
+    }
+}
+
+fn checked_range(from: u16, count: u16, len: usize) -> anyhow::Result<std::ops::Range<usize>> {
+    let range = from as usize..from as usize + count as usize;
+    if count == 0 || range.end > len {
+        return Err(anyhow::anyhow!("Range {:?} is out of route bounds", range));
+    }
+    Ok(range)
+}
+
+fn is_permutation(order: &[u16], len: usize) -> bool {
+    let mut seen = vec![false; len];
+    order.len() == len && order.iter().all(|&index| {
+        let index = index as usize;
+        index < len && !std::mem::replace(&mut seen[index], true)
+    })
+}
@@ -0,0 +1,98 @@
+use test_case::test_case;
+
+use crate::models::missions::{MissionRouteItem, RouteEdit};
+use crate::models::spatial::Geodetic;
+use super::route_edit::{apply_edit, splice_items};
+
+// Items are told apart by hold, None is a gap
+fn route(holds: &[Option<u16>]) -> Vec<MissionRouteItem> {
+    holds.iter().map(|hold| match hold {
+        Some(hold) => MissionRouteItem::Waypoint {
+            position: Geodetic::default(),
+            hold: *hold,
+            pass_radius: 0.0,
+            accept_radius: 0.0,
+            yaw: None
+        },
+        None => MissionRouteItem::Gap {}
+    }).collect()
+}
+
+#[test_case(RouteEdit::Replace { items: route(&[Some(7)]) }, &[Some(7)]; "replace")]
+#[test_case(RouteEdit::Replace { items: Vec::new() }, &[]; "replace with empty")]
+#[test_case(RouteEdit::Insert { index: 0, items: route(&[Some(7), Some(8)]) },
+    &[Some(7), Some(8), Some(0), Some(1), Some(2), Some(3)]; "insert at start")]
+#[test_case(RouteEdit::Insert { index: 2, items: route(&[Some(7)]) },
+    &[Some(0), Some(1), Some(7), Some(2), Some(3)]; "insert shifts")]
+#[test_case(RouteEdit::Insert { index: 4, items: route(&[Some(7)]) },
+    &[Some(0), Some(1), Some(2), Some(3), Some(7)]; "insert at end")]
+#[test_case(RouteEdit::Insert { index: 6, items: route(&[Some(7)]) },
+    &[Some(0), Some(1), Some(2), Some(3), None, None, Some(7)]; "insert with gaps")]
+#[test_case(RouteEdit::Move { from: 0, count: 1, to: 3 },
+    &[Some(1), Some(2), Some(3), Some(0)]; "move first to end")]
+#[test_case(RouteEdit::Move { from: 2, count: 2, to: 0 },
+    &[Some(2), Some(3), Some(0), Some(1)]; "move range to start")]
+#[test_case(RouteEdit::Move { from: 1, count: 2, to: 1 },
+    &[Some(0), Some(1), Some(2), Some(3)]; "move in place")]
+#[test_case(RouteEdit::Reorder { order: vec![3, 1, 0, 2] },
+    &[Some(3), Some(1), Some(0), Some(2)]; "reorder")]
+#[test_case(RouteEdit::Duplicate { from: 1, count: 2, to: 3 },
+    &[Some(0), Some(1), Some(2), Some(1), Some(2), Some(3)]; "duplicate in the middle")]
+#[test_case(RouteEdit::Duplicate { from: 0, count: 4, to: 4 },
+    &[Some(0), Some(1), Some(2), Some(3), Some(0), Some(1), Some(2), Some(3)]; "duplicate whole route")]
+fn test_apply_edit(edit: RouteEdit, expected: &[Option<u16>]) {
+    let items = route(&[Some(0), Some(1), Some(2), Some(3)]);
+    assert_eq!(apply_edit(&items, &edit).expect("Edit must be applied"), route(expected));
+}
+
+#[test_case(RouteEdit::Move { from: 3, count: 2, to: 0 }; "move range out of bounds")]
+#[test_case(RouteEdit::Move { from: 0, count: 0, to: 1 }; "move empty range")]
+#[test_case(RouteEdit::Move { from: 0, count: 2, to: 3 }; "move target out of bounds")]
+#[test_case(RouteEdit::Reorder { order: vec![0, 1, 2] }; "reorder missing index")]
+#[test_case(RouteEdit::Reorder { order: vec![0, 1, 1, 2] }; "reorder duplicated index")]
+#[test_case(RouteEdit::Reorder { order: vec![0, 1, 2, 4] }; "reorder out of bounds")]
+#[test_case(RouteEdit::Duplicate { from: 4, count: 1, to: 0 }; "duplicate range out of bounds")]
+#[test_case(RouteEdit::Duplicate { from: 0, count: 1, to: 5 }; "duplicate target out of bounds")]
+fn test_apply_invalid_edit(edit: RouteEdit) {
+    let items = route(&[Some(0), Some(1), Some(2), Some(3)]);
+    assert!(apply_edit(&items, &edit).is_err());
+}
+
+fn wpt(hold: u16) -> MissionRouteItem {
+    route(&[Some(hold)]).remove(0)
+}
+
+fn jump(target: u16) -> MissionRouteItem {
+    MissionRouteItem::DoJump { target, repeat: 2 }
+}
+
+#[test_case(RouteEdit::Replace { items: vec![jump(5)] }, vec![jump(5)]; "replace keeps new jumps")]
+#[test_case(RouteEdit::Insert { index: 0, items: vec![wpt(7)] },
+    vec![wpt(7), wpt(0), wpt(1), jump(2), wpt(3)]; "insert shifts target")]
+#[test_case(RouteEdit::Insert { index: 0, items: vec![jump(3)] },
+    vec![jump(3), wpt(0), wpt(1), jump(2), wpt(3)]; "insert keeps inserted jump")]
+#[test_case(RouteEdit::Insert { index: 3, items: vec![wpt(7)] },
+    vec![wpt(0), wpt(1), jump(1), wpt(7), wpt(3)]; "insert after target")]
+#[test_case(RouteEdit::Move { from: 1, count: 1, to: 3 },
+    vec![wpt(0), jump(3), wpt(3), wpt(1)]; "move target")]
+#[test_case(RouteEdit::Move { from: 2, count: 1, to: 0 },
+    vec![jump(2), wpt(0), wpt(1), wpt(3)]; "move jump")]
+#[test_case(RouteEdit::Reorder { order: vec![3, 2, 1, 0] },
+    vec![wpt(3), jump(2), wpt(1), wpt(0)]; "reorder")]
+#[test_case(RouteEdit::Duplicate { from: 1, count: 2, to: 4 },
+    vec![wpt(0), wpt(1), jump(1), wpt(3), wpt(1), jump(4)]; "duplicate loop")]
+#[test_case(RouteEdit::Duplicate { from: 2, count: 1, to: 0 },
+    vec![jump(2), wpt(0), wpt(1), jump(2), wpt(3)]; "duplicate jump before target")]
+fn test_apply_edit_jumps(edit: RouteEdit, expected: Vec<MissionRouteItem>) {
+    let items = vec![wpt(0), wpt(1), jump(1), wpt(3)];
+    assert_eq!(apply_edit(&items, &edit).expect("Edit must be applied"), expected);
+}
+
+#[test_case(2..4, vec![wpt(7)], vec![wpt(0), jump(2), wpt(7), wpt(4), jump(3)]; "shrink")]
+#[test_case(2..4, vec![wpt(7), wpt(8), wpt(9)], vec![wpt(0), jump(2), wpt(7), wpt(8), wpt(9), wpt(4), jump(5)]; "grow")]
+#[test_case(2..3, Vec::new(), vec![wpt(0), jump(2), wpt(3), wpt(4), jump(3)]; "remove")]
+fn test_splice_items_jumps(range: std::ops::Range<usize>, replacement: Vec<MissionRouteItem>, expected: Vec<MissionRouteItem>) {
+    let mut items = vec![wpt(0), jump(3), wpt(2), wpt(3), wpt(4), jump(4)];
+    splice_items(&mut items, range, replacement);
+    assert_eq!(items, expected);
+}
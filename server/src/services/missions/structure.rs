@@ -0,0 +1,68 @@
+use std::f64::consts::PI;
+
+use crate::models::missions::MissionRouteItem;
+use crate::models::mission_patterns::{GeneratedPattern, StructureRequest};
+use super::patterns::{ground_sample_distance, image_footprint, images_count, start_trigger, stop_trigger,
+    validate_camera, waypoint, LocalPlane, MAX_PATTERN_LINES};
+
+// Orbits are flown as polygons with a waypoint every this many degrees
+const ORBIT_STEP: f64 = 15.0;
+
+fn validate_request(request: &StructureRequest) -> anyhow::Result<()> {
+    if request.radius <= 0.0 {
+        return Err(anyhow::anyhow!("Structure scan radius must be positive"));
+    }
+    if request.top < request.bottom {
+        return Err(anyhow::anyhow!("Structure scan top is below the bottom"));
+    }
+    validate_camera(&request.camera, request.front_overlap, request.side_overlap)
+}
+
+// Layered orbits from the bottom up, camera looks at the center all the way
+pub fn structure_pattern(request: &StructureRequest) -> anyhow::Result<GeneratedPattern> {
+    validate_request(request)?;
+    // Image width goes along the orbit and image height goes up the structure
+    let (horizontal, vertical) = image_footprint(&request.camera, request.radius);
+    let trigger_distance = horizontal * (1.0 - request.front_overlap / 100.0);
+    let layer_spacing = vertical * (1.0 - request.side_overlap / 100.0);
+
+    let height = (request.top - request.bottom) as f64;
+    let layers = (height / layer_spacing as f64).ceil() as usize + 1;
+    if layers > MAX_PATTERN_LINES {
+        return Err(anyhow::anyhow!("Structure scan needs {} layers, more than {} allowed", layers, MAX_PATTERN_LINES));
+    }
+    let step = if layers > 1 { height / (layers - 1) as f64 } else { 0.0 };
+
+    let plane = LocalPlane::new(&request.center);
+    let radius = request.radius as f64;
+    let start = request.entry.as_ref().map_or(0.0, |entry| request.center.bearing_to(entry));
+    let direction = if request.clockwise { 1.0 } else { -1.0 };
+    let points = (360.0 / ORBIT_STEP).round() as usize;
+    let orbit = points as f64 * 2.0 * radius * (PI / points as f64).sin();
+
+    let mut items = vec![MissionRouteItem::DoSetRoi { position: request.center.clone() }];
+    for layer in 0..layers {
+        let altitude = request.bottom + (step * layer as f64) as f32;
+        for point in 0..=points {
+            let bearing = (start + direction * ORBIT_STEP * point as f64).to_radians();
+            let position = plane.position((radius * bearing.sin(), radius * bearing.cos()), altitude, &request.center.frame);
+            items.push(waypoint(position));
+            if point == 0 {
+                items.push(start_trigger(trigger_distance));
+            }
+        }
+        items.push(stop_trigger());
+    }
+    items.push(MissionRouteItem::RoiNone {});
+
+    Ok(GeneratedPattern {
+        items,
+        altitude: request.bottom,
+        gsd: ground_sample_distance(&request.camera, request.radius),
+        line_spacing: layer_spacing,
+        trigger_distance,
+        lines: layers as u16,
+        length: orbit * layers as f64 + height,
+        images: images_count(orbit, trigger_distance) * layers as u32
+    })
+}
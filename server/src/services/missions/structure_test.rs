@@ -0,0 +1,108 @@
+use test_case::test_case;
+
+use crate::models::missions::MissionRouteItem;
+use crate::models::mission_patterns::{StructureRequest, SurveyCamera};
+use crate::models::spatial::{Enu, Geodetic, GeodeticFrame};
+use super::structure::structure_pattern;
+
+// Footprint at 50 meters is 75 by 50, so images are 15 meters apart and layers 15 meters above each other
+fn camera() -> SurveyCamera {
+    SurveyCamera { sensor_width: 13.2, sensor_height: 8.8, focal_length: 8.8, image_width: 5472, image_height: 3648 }
+}
+
+fn center() -> Geodetic {
+    Geodetic { latitude: 55.75, longitude: 37.6, altitude: 30.0, frame: GeodeticFrame::Wgs84RelativeHome }
+}
+
+fn request() -> StructureRequest {
+    StructureRequest {
+        center: center(),
+        radius: 50.0,
+        bottom: 20.0,
+        top: 60.0,
+        camera: camera(),
+        front_overlap: 80.0,
+        side_overlap: 70.0,
+        clockwise: true,
+        entry: None
+    }
+}
+
+fn waypoints(items: &[MissionRouteItem]) -> Vec<Geodetic> {
+    items.iter().filter_map(|item| match item {
+        MissionRouteItem::Waypoint { position, .. } => Some(position.clone()),
+        _ => None
+    }).collect()
+}
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!((actual - expected).abs() <= tolerance, "{} != {} within {}", actual, expected, tolerance);
+}
+
+fn assert_bearing(actual: f64, expected: f64, tolerance: f64) {
+    assert_close((actual - expected + 180.0).rem_euclid(360.0) - 180.0, 0.0, tolerance);
+}
+
+#[test]
+fn test_structure_layers() {
+    let pattern = structure_pattern(&request()).unwrap();
+
+    assert_eq!(pattern.lines, 4);
+    assert_eq!(pattern.items.len(), 1 + 4 * (25 + 2) + 1);
+    assert_eq!(pattern.images, 4 * 21);
+    assert_close(pattern.gsd as f64, 1.3706, 1e-4);
+    assert_close(pattern.line_spacing as f64, 15.0, 1e-3);
+    assert_close(pattern.trigger_distance as f64, 15.0, 1e-3);
+    assert_close(pattern.length, 4.0 * 313.263 + 40.0, 0.01);
+
+    let waypoints = waypoints(&pattern.items);
+    let altitudes: Vec<f32> = waypoints.chunks(25).map(|layer| layer[0].altitude).collect();
+    assert_eq!(altitudes.len(), 4);
+    for (altitude, expected) in altitudes.iter().zip([20.0, 33.333, 46.667, 60.0]) {
+        assert_close(*altitude as f64, expected, 1e-3);
+    }
+    for waypoint in waypoints.iter() {
+        let offset = center().enu_to(&Geodetic { altitude: center().altitude, ..waypoint.clone() });
+        assert_close(offset.east.hypot(offset.north), 50.0, 0.01);
+        assert_eq!(waypoint.frame, GeodeticFrame::Wgs84RelativeHome);
+    }
+}
+
+#[test]
+fn test_structure_roi() {
+    let pattern = structure_pattern(&request()).unwrap();
+
+    assert_eq!(pattern.items[0], MissionRouteItem::DoSetRoi { position: center() });
+    assert!(matches!(pattern.items[2], MissionRouteItem::TriggerCam { distance, trigger: true, .. } if distance > 14.99 && distance < 15.01));
+    assert!(matches!(pattern.items[27], MissionRouteItem::TriggerCam { trigger: false, .. }));
+    assert_eq!(pattern.items.last(), Some(&MissionRouteItem::RoiNone {}));
+}
+
+#[test_case(true, None, 0.0, 15.0; "clockwise from north")]
+#[test_case(false, None, 0.0, 345.0; "counter clockwise from north")]
+#[test_case(true, Some((200.0, 0.0)), 90.0, 105.0; "clockwise from entry")]
+fn test_orbit_direction(clockwise: bool, entry: Option<(f64, f64)>, first: f64, second: f64) {
+    let entry = entry.map(|(east, north)| center().offset_enu(&Enu { east, north, up: 0.0 }));
+    let pattern = structure_pattern(&StructureRequest { clockwise, entry, ..request() }).unwrap();
+    let waypoints = waypoints(&pattern.items);
+
+    assert_bearing(center().bearing_to(&waypoints[0]), first, 0.1);
+    assert_bearing(center().bearing_to(&waypoints[1]), second, 0.1);
+    assert_close(waypoints[0].distance_to(&waypoints[24]), 0.0, 0.01);
+}
+
+#[test]
+fn test_single_layer() {
+    let pattern = structure_pattern(&StructureRequest { top: 20.0, ..request() }).unwrap();
+
+    assert_eq!(pattern.lines, 1);
+    assert_eq!(pattern.items.len(), 1 + 25 + 2 + 1);
+}
+
+#[test_case(StructureRequest { radius: 0.0, ..request() }; "no radius")]
+#[test_case(StructureRequest { top: 10.0, ..request() }; "top below bottom")]
+#[test_case(StructureRequest { side_overlap: 100.0, ..request() }; "full overlap")]
+#[test_case(StructureRequest { camera: SurveyCamera { image_width: 0, ..camera() }, ..request() }; "no image size")]
+fn test_invalid_request(request: StructureRequest) {
+    assert!(structure_pattern(&request).is_err());
+}
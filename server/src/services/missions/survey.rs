@@ -0,0 +1,90 @@
+use crate::models::mission_patterns::{GeneratedPattern, SurveyRequest};
+use super::patterns::{camera_spacing, ground_sample_distance, order_passes, pass_items, pattern_altitude,
+    spread_lines, validate_camera, LocalPlane, Pass};
+
+// Rotation of the local plane to the survey heading, cross axis points to the right of the passes
+struct TrackAxes {
+    sin: f64,
+    cos: f64
+}
+
+impl TrackAxes {
+    fn new(heading: f32) -> Self {
+        let heading = (heading as f64).to_radians();
+        Self { sin: heading.sin(), cos: heading.cos() }
+    }
+
+    // Along and cross track offsets of the point
+    fn to_track(&self, (east, north): (f64, f64)) -> (f64, f64) {
+        (east * self.sin + north * self.cos, east * self.cos - north * self.sin)
+    }
+
+    fn to_plane(&self, along: f64, cross: f64) -> (f64, f64) {
+        (along * self.sin + cross * self.cos, along * self.cos - cross * self.sin)
+    }
+}
+
+fn validate_request(request: &SurveyRequest) -> anyhow::Result<()> {
+    if request.polygon.len() < 3 {
+        return Err(anyhow::anyhow!("Survey area needs at least 3 vertices"));
+    }
+    validate_camera(&request.camera, request.front_overlap, request.side_overlap)?;
+    if request.turnaround < 0.0 {
+        return Err(anyhow::anyhow!("Survey turnaround distance can't be negative"));
+    }
+    Ok(())
+}
+
+// Passes are centered over the area, each one spans the outermost crossings with the polygon
+fn sweep_passes(vertices: &[(f64, f64)], axes: &TrackAxes, spacing: f64) -> anyhow::Result<Vec<Pass>> {
+    let vertices: Vec<(f64, f64)> = vertices.iter().map(|vertex| axes.to_track(*vertex)).collect();
+    let min = vertices.iter().map(|(_, cross)| *cross).fold(f64::INFINITY, f64::min);
+    let max = vertices.iter().map(|(_, cross)| *cross).fold(f64::NEG_INFINITY, f64::max);
+    let (count, first) = spread_lines(max - min, spacing)?;
+
+    let mut passes = Vec::new();
+    for line in 0..count {
+        let cross = (min + max) / 2.0 + first + line as f64 * spacing;
+        let crossings: Vec<f64> = vertices.iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .filter(|((_, from), (_, to))| (*from <= cross) != (*to <= cross))
+            .map(|((from_along, from), (to_along, to))| from_along + (cross - from) / (to - from) * (to_along - from_along))
+            .collect();
+        if crossings.len() < 2 {
+            continue;
+        }
+        let start = crossings.iter().cloned().fold(f64::INFINITY, f64::min);
+        let end = crossings.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        passes.push(Pass { points: vec![axes.to_plane(start, cross), axes.to_plane(end, cross)] });
+    }
+    Ok(passes)
+}
+
+// Lawnmower pattern over the polygon, camera is triggered by distance along each pass only
+pub fn survey_pattern(request: &SurveyRequest) -> anyhow::Result<GeneratedPattern> {
+    validate_request(request)?;
+    let altitude = pattern_altitude(&request.camera, &request.resolution)?;
+    let (line_spacing, trigger_distance) = camera_spacing(&request.camera, altitude, request.front_overlap, request.side_overlap);
+
+    let plane = LocalPlane::around(&request.polygon);
+    let vertices: Vec<(f64, f64)> = request.polygon.iter().map(|vertex| plane.point(vertex)).collect();
+    let passes = sweep_passes(&vertices, &TrackAxes::new(request.heading), line_spacing as f64)?;
+    let passes = order_passes(
+        &passes,
+        request.entry.as_ref().map(|entry| plane.point(entry)),
+        request.exit.as_ref().map(|exit| plane.point(exit))
+    );
+    let (items, length, images) = pass_items(&passes, &plane, altitude, &request.frame,
+        request.turnaround as f64, trigger_distance);
+
+    Ok(GeneratedPattern {
+        items,
+        altitude,
+        gsd: ground_sample_distance(&request.camera, altitude),
+        line_spacing,
+        trigger_distance,
+        lines: passes.len() as u16,
+        length,
+        images
+    })
+}
@@ -0,0 +1,164 @@
+use test_case::test_case;
+
+use crate::models::missions::MissionRouteItem;
+use crate::models::mission_patterns::{SurveyCamera, SurveyRequest, SurveyResolution};
+use crate::models::spatial::{Enu, Geodetic, GeodeticFrame};
+use super::survey::survey_pattern;
+
+// 1" sensor with 20 MP, footprint at 100 meters is 150 by 100
+fn camera() -> SurveyCamera {
+    SurveyCamera { sensor_width: 13.2, sensor_height: 8.8, focal_length: 8.8, image_width: 5472, image_height: 3648 }
+}
+
+fn center() -> Geodetic {
+    Geodetic { latitude: 55.75, longitude: 37.6, altitude: 0.0, frame: GeodeticFrame::None }
+}
+
+fn corner(east: f64, north: f64) -> Geodetic {
+    center().offset_enu(&Enu { east, north, up: 0.0 })
+}
+
+// Square of 310 meters around the center
+fn request() -> SurveyRequest {
+    SurveyRequest {
+        polygon: vec![corner(-155.0, -155.0), corner(-155.0, 155.0), corner(155.0, 155.0), corner(155.0, -155.0)],
+        camera: camera(),
+        resolution: SurveyResolution::Altitude { altitude: 100.0 },
+        frame: GeodeticFrame::Wgs84RelativeHome,
+        front_overlap: 80.0,
+        side_overlap: 70.0,
+        heading: 0.0,
+        turnaround: 20.0,
+        entry: None,
+        exit: None
+    }
+}
+
+fn waypoints(items: &[MissionRouteItem]) -> Vec<Geodetic> {
+    items.iter().filter_map(|item| match item {
+        MissionRouteItem::Waypoint { position, .. } => Some(position.clone()),
+        _ => None
+    }).collect()
+}
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!((actual - expected).abs() <= tolerance, "{} != {} within {}", actual, expected, tolerance);
+}
+
+fn assert_bearing(actual: f64, expected: f64, tolerance: f64) {
+    assert_close((actual - expected + 180.0).rem_euclid(360.0) - 180.0, 0.0, tolerance);
+}
+
+#[test]
+fn test_square_survey() {
+    let pattern = survey_pattern(&request()).unwrap();
+
+    assert_eq!(pattern.lines, 7);
+    assert_eq!(pattern.items.len(), 7 * 6);
+    assert_eq!(pattern.images, 7 * 16);
+    assert_close(pattern.line_spacing as f64, 45.0, 1e-3);
+    assert_close(pattern.trigger_distance as f64, 20.0, 1e-3);
+    assert_close(pattern.gsd as f64, 2.7412, 1e-4);
+    // Passes with turnarounds and transitions between them
+    assert_close(pattern.length, 7.0 * 350.0 + 6.0 * 45.0, 0.1);
+}
+
+#[test]
+fn test_survey_by_gsd() {
+    let request = SurveyRequest { resolution: SurveyResolution::Gsd { gsd: 2.0 }, ..request() };
+    let pattern = survey_pattern(&request).unwrap();
+
+    assert_close(pattern.altitude as f64, 72.96, 0.01);
+    assert_close(pattern.gsd as f64, 2.0, 1e-4);
+    assert!(waypoints(&pattern.items).iter().all(|position| position.altitude == pattern.altitude));
+}
+
+#[test]
+fn test_pass_items() {
+    let pattern = survey_pattern(&request()).unwrap();
+    let center = center();
+
+    match &pattern.items[0] {
+        MissionRouteItem::Waypoint { position, .. } => {
+            let enu = center.enu_to(&Geodetic { altitude: 0.0, ..position.clone() });
+            assert_close(enu.east, -135.0, 0.01);
+            assert_close(enu.north, -175.0, 0.01);
+            assert_eq!(position.altitude, 100.0);
+            assert_eq!(position.frame, GeodeticFrame::Wgs84RelativeHome);
+        },
+        item => panic!("Unexpected item {:?}", item)
+    }
+    assert!(matches!(pattern.items[2], MissionRouteItem::TriggerCam { distance, trigger: true, .. } if distance > 19.99 && distance < 20.01));
+    assert!(matches!(pattern.items[4], MissionRouteItem::TriggerCam { distance, trigger: false, .. } if distance == 0.0));
+}
+
+#[test]
+fn test_no_turnaround() {
+    let pattern = survey_pattern(&SurveyRequest { turnaround: 0.0, ..request() }).unwrap();
+
+    assert_eq!(pattern.items.len(), 7 * 4);
+    assert_close(pattern.length, 7.0 * 310.0 + 6.0 * 45.0, 0.1);
+}
+
+#[test_case(0.0, 0.0, 180.0; "north")]
+#[test_case(90.0, 90.0, 270.0; "east")]
+#[test_case(45.0, 45.0, 225.0; "diagonal")]
+fn test_passes_alternate(heading: f32, first: f64, second: f64) {
+    let pattern = survey_pattern(&SurveyRequest { heading, ..request() }).unwrap();
+    let waypoints = waypoints(&pattern.items);
+
+    assert_bearing(waypoints[0].bearing_to(&waypoints[3]), first, 0.1);
+    assert_bearing(waypoints[4].bearing_to(&waypoints[7]), second, 0.1);
+}
+
+#[test_case(1.0, 1.0; "north east")]
+#[test_case(1.0, -1.0; "south east")]
+#[test_case(-1.0, 1.0; "north west")]
+#[test_case(-1.0, -1.0; "south west")]
+fn test_entry_corner(east: f64, north: f64) {
+    let request = SurveyRequest { entry: Some(corner(east * 300.0, north * 300.0)), ..request() };
+    let pattern = survey_pattern(&request).unwrap();
+    let entry = center().enu_to(&waypoints(&pattern.items)[0]);
+
+    assert_close(entry.east, east * 135.0, 0.01);
+    assert_close(entry.north, north * 175.0, 0.01);
+}
+
+#[test]
+fn test_exit_corner() {
+    // Odd number of passes ends on the opposite side of the area
+    let request = SurveyRequest { exit: Some(corner(-300.0, 300.0)), ..request() };
+    let pattern = survey_pattern(&request).unwrap();
+    let waypoints = waypoints(&pattern.items);
+    let exit = center().enu_to(waypoints.last().unwrap());
+
+    assert_close(exit.east, -135.0, 0.01);
+    assert_close(exit.north, 175.0, 0.01);
+}
+
+#[test]
+fn test_triangle_passes_shrink() {
+    let request = SurveyRequest {
+        polygon: vec![corner(-155.0, -155.0), corner(0.0, 155.0), corner(155.0, -155.0)],
+        turnaround: 0.0,
+        ..request()
+    };
+    let pattern = survey_pattern(&request).unwrap();
+    let waypoints = waypoints(&pattern.items);
+
+    assert_eq!(pattern.lines, 7);
+    let lengths: Vec<f64> = waypoints.chunks(2).map(|pass| pass[0].distance_to(&pass[1])).collect();
+    assert_close(lengths[0], 40.0, 0.5);
+    assert_close(lengths[3], 310.0, 0.5);
+    assert_close(lengths[6], 40.0, 0.5);
+}
+
+#[test_case(SurveyRequest { polygon: vec![corner(0.0, 0.0), corner(10.0, 10.0)], ..request() }; "too few vertices")]
+#[test_case(SurveyRequest { side_overlap: 100.0, ..request() }; "full overlap")]
+#[test_case(SurveyRequest { camera: SurveyCamera { focal_length: 0.0, ..camera() }, ..request() }; "no focal length")]
+#[test_case(SurveyRequest { turnaround: -1.0, ..request() }; "negative turnaround")]
+#[test_case(SurveyRequest { resolution: SurveyResolution::Gsd { gsd: 0.0 }, ..request() }; "zero gsd")]
+#[test_case(SurveyRequest { resolution: SurveyResolution::Altitude { altitude: 0.1 }, ..request() }; "too many lines")]
+fn test_invalid_request(request: SurveyRequest) {
+    assert!(survey_pattern(&request).is_err());
+}
@@ -0,0 +1,225 @@
+use crate::dal::dal;
+use crate::models::mission_validation::*;
+use crate::models::missions::{MissionId, MissionRouteItem};
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use crate::models::vehicles::VehicleType;
+use crate::services::terrain::provider::Terrain;
+
+// Live vehicle state the route is validated against
+pub struct ValidationState {
+    pub home: Option<Geodetic>,
+    pub battery_remaining: Option<i8>,
+    pub terrain: Terrain,
+    pub now: i64
+}
+
+fn issue(kind: MissionIssueKind, severity: MissionIssueSeverity, index: Option<usize>, description: String) -> MissionIssue {
+    MissionIssue { kind, severity, index: index.map(|index| index as u16), description }
+}
+
+fn item_position(item: &MissionRouteItem) -> Option<&Geodetic> {
+    match item {
+        MissionRouteItem::Waypoint { position, .. } |
+        MissionRouteItem::Takeoff { position, .. } |
+        MissionRouteItem::Landing { position, .. } |
+        MissionRouteItem::VtolTakeoff { position, .. } |
+        MissionRouteItem::VtolLand { position, .. } |
+        MissionRouteItem::LoiterTrn { position, .. } |
+        MissionRouteItem::LoiterAlt { position, .. } => Some(position),
+        _ => None
+    }
+}
+
+// Zero coordinates mean the current vehicle position, e.g. for takeoff
+fn is_located(position: &Geodetic) -> bool {
+    position.latitude != 0.0 || position.longitude != 0.0
+}
+
+fn height_above_ground(position: &Geodetic, state: &ValidationState) -> Option<f32> {
+    let home = state.home.as_ref();
+    if let Some(converted) = state.terrain.convert(position, GeodeticFrame::Wgs84AboveTerrain, home) {
+        return Some(converted.altitude);
+    }
+    // Without terrain data, home is taken as the ground level
+    match position.frame {
+        GeodeticFrame::Wgs84RelativeHome | GeodeticFrame::Wgs84AboveTerrain => Some(position.altitude),
+        GeodeticFrame::Wgs84AboveSeaLevel => match home {
+            Some(home) if home.frame == GeodeticFrame::Wgs84AboveSeaLevel => Some(position.altitude - home.altitude),
+            _ => None
+        },
+        GeodeticFrame::None => None
+    }
+}
+
+fn item_delay(item: &MissionRouteItem, cruise_speed: f32) -> f32 {
+    match item {
+        MissionRouteItem::Waypoint { hold, .. } => *hold as f32,
+        MissionRouteItem::LoiterTrn { radius, turns, .. } if cruise_speed > 0.0 =>
+            *turns as f32 * 2.0 * std::f32::consts::PI * radius.abs() / cruise_speed,
+        MissionRouteItem::Delay { seconds } => *seconds,
+        _ => 0.0
+    }
+}
+
+fn check_structure(items: &[MissionRouteItem], vehicle_type: &VehicleType, issues: &mut Vec<MissionIssue>) {
+    for (index, item) in items.iter().enumerate() {
+        if let MissionRouteItem::Gap {} = item {
+            issues.push(issue(MissionIssueKind::Gap, MissionIssueSeverity::Error, Some(index),
+                "Route has an empty item".into()));
+        }
+    }
+
+    if *vehicle_type == VehicleType::Copter {
+        let first = items.iter().position(|item| *item != MissionRouteItem::Gap {});
+        if !matches!(first.map(|index| &items[index]), Some(MissionRouteItem::Takeoff { .. } | MissionRouteItem::VtolTakeoff { .. })) {
+            issues.push(issue(MissionIssueKind::NoTakeoff, MissionIssueSeverity::Error, first,
+                "Copter route must start with a takeoff".into()));
+        }
+    }
+
+    // Returning to launch lands at home
+    if !matches!(items.last(), Some(MissionRouteItem::Landing { .. } | MissionRouteItem::VtolLand { .. } |
+        MissionRouteItem::ReturnToLaunch {})) {
+        issues.push(issue(MissionIssueKind::NoLanding, MissionIssueSeverity::Warning, None,
+            "Route doesn't end with a landing".into()));
+    }
+}
+
+fn check_positions(
+    items: &[MissionRouteItem],
+    settings: &MissionValidationSettings,
+    state: &ValidationState,
+    issues: &mut Vec<MissionIssue>
+) -> f64 {
+    let mut frame: Option<&GeodeticFrame> = None;
+    let mut previous = state.home.as_ref().filter(|home| is_located(home));
+    let mut distance = 0.0;
+
+    for (index, item) in items.iter().enumerate() {
+        let position = match item_position(item) {
+            Some(position) => position,
+            None => continue
+        };
+
+        match frame {
+            Some(frame) if *frame != position.frame => {
+                issues.push(issue(MissionIssueKind::MixedFrames, MissionIssueSeverity::Warning, Some(index),
+                    format!("Altitude frame {:?} differs from {:?}", position.frame, frame)));
+            },
+            Some(_) => {},
+            None => frame = Some(&position.frame)
+        }
+
+        let checks_altitude = !matches!(item, MissionRouteItem::Landing { .. } | MissionRouteItem::VtolLand { .. });
+        if let Some(height) = height_above_ground(position, state).filter(|_| checks_altitude) {
+            if height < settings.min_altitude {
+                issues.push(issue(MissionIssueKind::LowAltitude, MissionIssueSeverity::Error, Some(index),
+                    format!("Altitude {:.0}m is below {:.0}m above ground", height, settings.min_altitude)));
+            }
+        }
+
+        if !is_located(position) {
+            continue;
+        }
+        if let Some(previous) = previous {
+            let leg = previous.distance_to(position);
+            if leg > settings.max_leg_length as f64 {
+                issues.push(issue(MissionIssueKind::LongLeg, MissionIssueSeverity::Error, Some(index),
+                    format!("Leg of {:.0}m is longer than {:.0}m", leg, settings.max_leg_length)));
+            }
+            distance += leg;
+        }
+        previous = Some(position);
+    }
+    distance
+}
+
+fn estimate(items: &[MissionRouteItem], distance: f64, settings: &MissionValidationSettings) -> MissionEstimate {
+    let travel = if settings.cruise_speed > 0.0 { distance as f32 / settings.cruise_speed } else { 0.0 };
+    let duration = travel + items.iter().map(|item| item_delay(item, settings.cruise_speed)).sum::<f32>();
+    MissionEstimate {
+        distance,
+        duration,
+        energy: settings.cruise_power * duration / 3600.0
+    }
+}
+
+fn check_energy(
+    estimate: &MissionEstimate,
+    settings: &MissionValidationSettings,
+    state: &ValidationState,
+    issues: &mut Vec<MissionIssue>
+) {
+    if settings.cruise_power <= 0.0 || settings.battery_energy <= 0.0 {
+        return;
+    }
+    // Negative remaining means it is not estimated by the autopilot, so battery is taken as full
+    let remaining = state.battery_remaining.filter(|remaining| *remaining >= 0).unwrap_or(100).min(100);
+    let available = settings.battery_energy * (remaining - settings.battery_reserve) as f32 / 100.0;
+    if estimate.energy > available {
+        issues.push(issue(MissionIssueKind::InsufficientEnergy, MissionIssueSeverity::Error, None,
+            format!("Route needs {:.0}Wh, {:.0}Wh available above {}% reserve",
+                estimate.energy, available.max(0.0), settings.battery_reserve)));
+    }
+}
+
+pub fn validate(
+    mission_id: &MissionId,
+    items: &[MissionRouteItem],
+    vehicle_type: &VehicleType,
+    settings: &MissionValidationSettings,
+    state: &ValidationState
+) -> MissionValidationReport {
+    let mut issues = Vec::new();
+    let mut estimate = MissionEstimate { distance: 0.0, duration: 0.0, energy: 0.0 };
+
+    if items.is_empty() {
+        issues.push(issue(MissionIssueKind::EmptyRoute, MissionIssueSeverity::Error, None, "Route is empty".into()));
+    } else {
+        check_structure(items, vehicle_type, &mut issues);
+        let distance = check_positions(items, settings, state, &mut issues);
+        estimate = self::estimate(items, distance, settings);
+        check_energy(&estimate, settings, state, &mut issues);
+    }
+
+    MissionValidationReport {
+        id: mission_id.clone(),
+        timestamp: state.now,
+        passed: issues.iter().all(|issue| issue.severity != MissionIssueSeverity::Error),
+        issues,
+        estimate
+    }
+}
+
+pub async fn run_validation(dal: &dal::Dal, mission_id: &MissionId) -> anyhow::Result<(MissionValidationReport, MissionValidationSettings)> {
+    let mission = dal.mission(mission_id).await?;
+    // Mission may be not bound to a vehicle
+    let vehicle_type = match dal.vehicle(&mission.vehicle_id).await {
+        Ok(vehicle) => vehicle.vehicle_type,
+        Err(_) => VehicleType::Unknown
+    };
+    let settings = dal.mission_validation_settings(&vehicle_type).await?;
+
+    let state = ValidationState {
+        home: dal.telemetry_navigation(&mission.vehicle_id).await.ok().map(|navigation| navigation.home_position),
+        battery_remaining: dal.telemetry_system(&mission.vehicle_id).await.ok().map(|system| system.battery_remaining),
+        terrain: dal.terrain.clone(),
+        now: chrono::Utc::now().timestamp_millis()
+    };
+
+    // Terrain checks may read tiles from disk
+    let (id, validation_settings) = (mission_id.clone(), settings.clone());
+    let report = tokio::task::spawn_blocking(move ||
+        validate(&id, &mission.route.items, &vehicle_type, &validation_settings, &state)).await?;
+    let report = dal.save_mission_validation_report(report).await?;
+    Ok((report, settings))
+}
+
+// Returns the failed report, if the upload must be blocked
+pub async fn check_upload(dal: &dal::Dal, mission_id: &MissionId) -> anyhow::Result<Option<MissionValidationReport>> {
+    let (report, settings) = run_validation(dal, mission_id).await?;
+    if report.passed || !settings.blocking {
+        return Ok(None);
+    }
+    Ok(Some(report))
+}
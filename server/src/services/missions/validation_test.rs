@@ -0,0 +1,232 @@
+use test_case::test_case;
+
+use crate::models::mission_validation::*;
+use crate::models::missions::MissionRouteItem;
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use crate::models::vehicles::VehicleType;
+use crate::services::terrain::{dem::DemTile, provider::Terrain};
+use super::validation::{validate, ValidationState};
+
+const NOW: i64 = 1_700_000_000_000;
+const METERS_PER_DEGREE: f64 = 111_195.0;
+
+fn home() -> Geodetic {
+    Geodetic { latitude: 55.97, longitude: 37.41, altitude: 190.0, frame: GeodeticFrame::Wgs84AboveSeaLevel }
+}
+
+// Point to the north from home
+fn at(north: f64, altitude: f32, frame: GeodeticFrame) -> Geodetic {
+    Geodetic { latitude: 55.97 + north / METERS_PER_DEGREE, longitude: 37.41, altitude, frame }
+}
+
+fn takeoff(altitude: f32) -> MissionRouteItem {
+    MissionRouteItem::Takeoff { position: at(0.0, altitude, GeodeticFrame::Wgs84RelativeHome), pitch: 15.0, yaw: None }
+}
+
+fn waypoint(position: Geodetic, hold: u16) -> MissionRouteItem {
+    MissionRouteItem::Waypoint { position, hold, pass_radius: 0.0, accept_radius: 5.0, yaw: None }
+}
+
+fn landing() -> MissionRouteItem {
+    MissionRouteItem::Landing { position: at(0.0, 0.0, GeodeticFrame::Wgs84RelativeHome), abort_altitude: None, yaw: None }
+}
+
+fn good_route() -> Vec<MissionRouteItem> {
+    vec![
+        takeoff(30.0),
+        waypoint(at(1000.0, 50.0, GeodeticFrame::Wgs84RelativeHome), 0),
+        waypoint(at(2000.0, 50.0, GeodeticFrame::Wgs84RelativeHome), 10),
+        landing()
+    ]
+}
+
+fn state() -> ValidationState {
+    ValidationState { home: Some(home()), battery_remaining: Some(100), terrain: Terrain::from_tiles(vec![]), now: NOW }
+}
+
+// Flat hill of the given height around the route
+fn hill(elevation: f32) -> Terrain {
+    Terrain::from_tiles(vec![DemTile {
+        north: 56.0,
+        west: 37.4,
+        lat_step: 0.05,
+        lon_step: 0.05,
+        rows: 3,
+        cols: 3,
+        heights: vec![elevation; 9]
+    }])
+}
+
+fn settings() -> MissionValidationSettings {
+    MissionValidationSettings::default_for_type(&VehicleType::Copter)
+}
+
+fn issue_kinds(items: &[MissionRouteItem], vehicle_type: VehicleType, settings: &MissionValidationSettings) -> Vec<(MissionIssueKind, Option<u16>)> {
+    validate(&"mission".to_string(), items, &vehicle_type, settings, &state()).issues.into_iter()
+        .map(|issue| (issue.kind, issue.index))
+        .collect()
+}
+
+#[test]
+fn test_good_route() {
+    let report = validate(&"mission".to_string(), &good_route(), &VehicleType::Copter, &settings(), &state());
+    assert!(report.passed);
+    assert!(report.issues.is_empty());
+    assert_eq!(report.timestamp, NOW);
+
+    // Home -> takeoff at home -> 1km -> 1km -> back home
+    assert!((report.estimate.distance - 4000.0).abs() < 1.0, "distance {}", report.estimate.distance);
+    // 10 m/s cruise and 10s hold
+    assert!((report.estimate.duration - 410.0).abs() < 0.5, "duration {}", report.estimate.duration);
+    assert_eq!(report.estimate.energy, 0.0);
+}
+
+#[test]
+fn test_empty_route() {
+    let report = validate(&"mission".to_string(), &[], &VehicleType::Copter, &settings(), &state());
+    assert!(!report.passed);
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].kind, MissionIssueKind::EmptyRoute);
+}
+
+#[test_case(VehicleType::Copter, vec![(MissionIssueKind::NoTakeoff, Some(0))]; "copter")]
+#[test_case(VehicleType::FixedWing, vec![]; "plane")]
+fn test_takeoff_first(vehicle_type: VehicleType, expected: Vec<(MissionIssueKind, Option<u16>)>) {
+    let mut settings = settings();
+    settings.min_altitude = 10.0;
+    let items = good_route()[1..].to_vec();
+    assert_eq!(issue_kinds(&items, vehicle_type, &settings), expected);
+}
+
+#[test]
+fn test_route_issues() {
+    let mut items = good_route();
+    items.insert(2, MissionRouteItem::Gap {});
+    items.pop();
+
+    let report = validate(&"mission".to_string(), &items, &VehicleType::Copter, &settings(), &state());
+    assert!(!report.passed);
+    let kinds: Vec<(MissionIssueKind, MissionIssueSeverity, Option<u16>)> = report.issues.into_iter()
+        .map(|issue| (issue.kind, issue.severity, issue.index))
+        .collect();
+    assert_eq!(kinds, vec![
+        (MissionIssueKind::Gap, MissionIssueSeverity::Error, Some(2)),
+        (MissionIssueKind::NoLanding, MissionIssueSeverity::Warning, None)
+    ]);
+}
+
+#[test]
+fn test_mixed_frames_warning() {
+    let mut items = good_route();
+    items[2] = waypoint(at(2000.0, 240.0, GeodeticFrame::Wgs84AboveSeaLevel), 0);
+
+    let report = validate(&"mission".to_string(), &items, &VehicleType::Copter, &settings(), &state());
+    assert!(report.passed);
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].kind, MissionIssueKind::MixedFrames);
+    assert_eq!(report.issues[0].index, Some(2));
+}
+
+#[test_case(at(1000.0, 5.0, GeodeticFrame::Wgs84RelativeHome), true; "relative")]
+#[test_case(at(1000.0, 8.0, GeodeticFrame::Wgs84AboveTerrain), true; "terrain")]
+#[test_case(at(1000.0, 195.0, GeodeticFrame::Wgs84AboveSeaLevel), true; "above sea level")]
+#[test_case(at(1000.0, 250.0, GeodeticFrame::Wgs84AboveSeaLevel), false; "high above sea level")]
+#[test_case(at(1000.0, 0.0, GeodeticFrame::None), false; "unknown frame")]
+fn test_low_altitude(position: Geodetic, low: bool) {
+    let mut items = good_route();
+    items[1] = waypoint(position, 0);
+    let low_altitude = issue_kinds(&items, VehicleType::Copter, &settings()).into_iter()
+        .any(|(kind, index)| kind == MissionIssueKind::LowAltitude && index == Some(1));
+    assert_eq!(low_altitude, low);
+}
+
+#[test_case(at(1000.0, 50.0, GeodeticFrame::Wgs84RelativeHome), true; "relative")]
+#[test_case(at(1000.0, 30.0, GeodeticFrame::Wgs84AboveTerrain), false; "terrain")]
+#[test_case(at(1000.0, 250.0, GeodeticFrame::Wgs84AboveSeaLevel), true; "above sea level")]
+#[test_case(at(1000.0, 300.0, GeodeticFrame::Wgs84AboveSeaLevel), false; "high above sea level")]
+fn test_low_altitude_over_terrain(position: Geodetic, low: bool) {
+    let mut items = good_route();
+    items[1] = waypoint(position, 0);
+    let state = ValidationState { terrain: hill(260.0), ..state() };
+
+    let report = validate(&"mission".to_string(), &items, &VehicleType::Copter, &settings(), &state);
+    let low_altitude = report.issues.iter()
+        .any(|issue| issue.kind == MissionIssueKind::LowAltitude && issue.index == Some(1));
+    assert_eq!(low_altitude, low);
+}
+
+#[test]
+fn test_long_leg() {
+    let mut settings = settings();
+    settings.max_leg_length = 1500.0;
+
+    let mut items = good_route();
+    items[2] = waypoint(at(3000.0, 50.0, GeodeticFrame::Wgs84RelativeHome), 0);
+    assert_eq!(issue_kinds(&items, VehicleType::Copter, &settings), vec![
+        (MissionIssueKind::LongLeg, Some(2)),
+        (MissionIssueKind::LongLeg, Some(3))
+    ]);
+}
+
+#[test_case(Some(100), true; "full battery")]
+#[test_case(Some(40), false; "low battery")]
+#[test_case(Some(-1), true; "unknown remaining")]
+#[test_case(None, true; "no telemetry")]
+fn test_energy(battery_remaining: Option<i8>, passed: bool) {
+    let mut settings = settings();
+    settings.cruise_power = 600.0; // 410s -> ~68Wh
+    settings.battery_energy = 150.0;
+    settings.battery_reserve = 20;
+
+    let state = ValidationState { battery_remaining, ..state() };
+    let report = validate(&"mission".to_string(), &good_route(), &VehicleType::Copter, &settings, &state);
+    assert!((report.estimate.energy - 68.3).abs() < 0.1, "energy {}", report.estimate.energy);
+    assert_eq!(report.passed, passed);
+    if !passed {
+        assert_eq!(report.issues[0].kind, MissionIssueKind::InsufficientEnergy);
+    }
+}
+
+#[test]
+fn test_loiter_turns_duration() {
+    let mut items = good_route();
+    items[2] = MissionRouteItem::LoiterTrn {
+        position: at(2000.0, 50.0, GeodeticFrame::Wgs84RelativeHome),
+        heading_required: false,
+        radius: 50.0,
+        turns: 2,
+        clockwise: true
+    };
+    let report = validate(&"mission".to_string(), &items, &VehicleType::Copter, &settings(), &state());
+    // 400s of legs and 2 turns of 314m at 10 m/s
+    assert!((report.estimate.duration - 462.8).abs() < 0.5, "duration {}", report.estimate.duration);
+}
+
+#[test]
+fn test_vtol_route() {
+    let items = vec![
+        MissionRouteItem::VtolTakeoff { position: at(0.0, 30.0, GeodeticFrame::Wgs84RelativeHome), yaw: None },
+        waypoint(at(1000.0, 50.0, GeodeticFrame::Wgs84RelativeHome), 0),
+        MissionRouteItem::VtolLand {
+            position: at(0.0, 0.0, GeodeticFrame::Wgs84RelativeHome),
+            approach_altitude: Some(30.0),
+            yaw: None
+        }
+    ];
+    assert_eq!(issue_kinds(&items, VehicleType::Copter, &settings()), vec![]);
+}
+
+#[test]
+fn test_return_to_launch_ends_route() {
+    let mut items = good_route();
+    *items.last_mut().unwrap() = MissionRouteItem::ReturnToLaunch {};
+    assert_eq!(issue_kinds(&items, VehicleType::Copter, &settings()), vec![]);
+}
+
+#[test]
+fn test_delay_duration() {
+    let mut items = good_route();
+    items.insert(2, MissionRouteItem::Delay { seconds: 30.0 });
+    let report = validate(&"mission".to_string(), &items, &VehicleType::Copter, &settings(), &state());
+    assert!((report.estimate.duration - 440.0).abs() < 0.5, "duration {}", report.estimate.duration);
+}
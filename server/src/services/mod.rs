@@ -1 +1,9 @@
 pub mod communication;
+pub mod capabilities;
+pub mod preflight;
+pub mod failsafe;
+pub mod flights;
+pub mod maintenance;
+pub mod missions;
+pub mod spatial;
+pub mod terrain;
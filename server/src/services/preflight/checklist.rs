@@ -0,0 +1,152 @@
+use crate::dal::dal;
+use crate::models::commands::{Command, CommandExecutor, CommandRejection, ExecuteCommandRequest};
+use crate::models::missions::MissionUpdateState;
+use crate::models::preflight::*;
+use crate::models::telemetry::{Navigation, RawSns, System};
+use crate::models::vehicles::{VehicleId, VehicleMode, VehicleStatus};
+
+const LINK_ONLINE_TIMEOUT_MS: i64 = 3000;
+
+// Live vehicle state the checklist is evaluated against
+pub struct PreflightState {
+    pub status: Option<VehicleStatus>,
+    pub system: Option<System>,
+    pub raw_sns: Option<RawSns>,
+    pub navigation: Option<Navigation>,
+    pub mission_state: Option<MissionUpdateState>,
+    pub now: i64
+}
+
+fn check_result(check: PreflightCheck, failure: Option<String>) -> PreflightCheckResult {
+    PreflightCheckResult { check, passed: failure.is_none(), reason: failure }
+}
+
+fn evaluate_check(check: &PreflightCheck, settings: &PreflightSettings, state: &PreflightState) -> Option<String> {
+    match check {
+        PreflightCheck::LinkOnline => match &state.status {
+            Some(status) if state.now - status.last_heartbeat < LINK_ONLINE_TIMEOUT_MS => None,
+            _ => Some("Vehicle is offline".into())
+        },
+        PreflightCheck::Sensors => match &state.system {
+            Some(system) => {
+                let unhealthy: Vec<&str> = system.sensors.iter()
+                    .filter(|sensor| sensor.enabled && !sensor.health)
+                    .map(|sensor| sensor.name.as_str())
+                    .collect();
+                if unhealthy.is_empty() {
+                    None
+                } else {
+                    Some(format!("Unhealthy sensors: {}", unhealthy.join(", ")))
+                }
+            },
+            None => Some("No system telemetry".into())
+        },
+        PreflightCheck::ArmReady => match &state.system {
+            Some(system) if system.arm_ready => None,
+            Some(_) => Some("Vehicle is not ready to arm".into()),
+            None => Some("No system telemetry".into())
+        },
+        PreflightCheck::Battery => match &state.system {
+            Some(system) => {
+                if system.battery_voltage < settings.min_battery_voltage {
+                    Some(format!("Battery voltage {:.1}V is below {:.1}V",
+                        system.battery_voltage, settings.min_battery_voltage))
+                // Negative remaining means it is not estimated by the autopilot
+                } else if system.battery_remaining >= 0 && system.battery_remaining < settings.min_battery_remaining {
+                    Some(format!("Battery remaining {}% is below {}%",
+                        system.battery_remaining, settings.min_battery_remaining))
+                } else {
+                    None
+                }
+            },
+            None => Some("No system telemetry".into())
+        },
+        PreflightCheck::SnsFix => match &state.raw_sns {
+            Some(raw_sns) => {
+                if raw_sns.fix < settings.min_sns_fix {
+                    Some(format!("SNS fix type {} is below {}", raw_sns.fix, settings.min_sns_fix))
+                } else if raw_sns.satellites_visible < settings.min_satellites {
+                    Some(format!("{} satellites visible, {} required",
+                        raw_sns.satellites_visible, settings.min_satellites))
+                } else {
+                    None
+                }
+            },
+            None => Some("No SNS telemetry".into())
+        },
+        PreflightCheck::HomePosition => match &state.navigation {
+            Some(navigation) if navigation.home_position.latitude != 0.0 ||
+                navigation.home_position.longitude != 0.0 => None,
+            _ => Some("Home position is not set".into())
+        },
+        PreflightCheck::MissionActual => match &state.mission_state {
+            Some(MissionUpdateState::Actual { .. }) => None,
+            Some(_) => Some("Mission on the vehicle is not actual".into()),
+            None => Some("No mission for the vehicle".into())
+        },
+    }
+}
+
+pub fn evaluate(vehicle_id: &VehicleId, settings: &PreflightSettings, state: &PreflightState) -> PreflightReport {
+    let results: Vec<PreflightCheckResult> = settings.checks.iter()
+        .map(|check| check_result(check.clone(), evaluate_check(check, settings, state)))
+        .collect();
+
+    PreflightReport {
+        id: vehicle_id.clone(),
+        timestamp: state.now,
+        passed: results.iter().all(|result| result.passed),
+        results
+    }
+}
+
+pub fn rejection_reason(report: &PreflightReport) -> String {
+    let reasons: Vec<String> = report.results.iter()
+        .filter_map(|result| result.reason.clone())
+        .collect();
+    format!("Preflight checks failed: {}", reasons.join("; "))
+}
+
+pub async fn run_preflight(dal: &dal::Dal, vehicle_id: &VehicleId) -> anyhow::Result<(PreflightReport, PreflightSettings)> {
+    let description = dal.vehicle(vehicle_id).await?;
+    let settings = dal.preflight_settings(&description.vehicle_type).await?;
+
+    let mission_state = match dal.active_mission_assignment(vehicle_id).await? {
+        Some(assignment) => dal.mission_status(&assignment.mission_id).await.ok().map(|status| status.state),
+        None => None
+    };
+    let state = PreflightState {
+        status: dal.vehcile_status(vehicle_id).await.ok(),
+        system: dal.telemetry_system(vehicle_id).await.ok(),
+        raw_sns: dal.telemetry_raw_sns(vehicle_id).await.ok(),
+        navigation: dal.telemetry_navigation(vehicle_id).await.ok(),
+        mission_state,
+        now: chrono::Utc::now().timestamp_millis()
+    };
+
+    let report = dal.save_preflight_report(evaluate(vehicle_id, &settings, &state)).await?;
+    Ok((report, settings))
+}
+
+// Returns a rejection, if the command must be blocked by the preflight checklist
+pub async fn check_command(dal: &dal::Dal, request: &ExecuteCommandRequest) -> anyhow::Result<Option<CommandRejection>> {
+    let vehicle_id = match &request.executor {
+        CommandExecutor::Vehicle { vehicle_id } => vehicle_id,
+        CommandExecutor::Payload { .. } => return Ok(None)
+    };
+    let requires_preflight = match &request.command {
+        Command::ArmDisarm { arm } => *arm,
+        Command::SetMode { mode } => *mode == VehicleMode::Mission,
+        _ => false
+    };
+    if !requires_preflight {
+        return Ok(None);
+    }
+
+    let (report, settings) = run_preflight(dal, vehicle_id).await?;
+    if report.passed || !settings.blocking {
+        return Ok(None);
+    }
+    log::warn!("{}", rejection_reason(&report));
+    Ok(Some(CommandRejection::PreflightFailed { report }))
+}
@@ -0,0 +1,119 @@
+use test_case::test_case;
+
+use crate::models::missions::MissionUpdateState;
+use crate::models::preflight::*;
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use crate::models::telemetry::{Navigation, RawSns, Sensor, SensorType, System};
+use crate::models::vehicles::{VehicleStatus, VehicleType};
+use super::checklist::{evaluate, rejection_reason, PreflightState};
+
+const NOW: i64 = 1_700_000_000_000;
+
+fn good_state() -> PreflightState {
+    let vehicle_id = "mav_1".to_string();
+
+    let mut status = VehicleStatus::default_for_id(&vehicle_id);
+    status.last_heartbeat = NOW - 500;
+
+    let mut system = System::default_for_id(&vehicle_id);
+    system.arm_ready = true;
+    system.battery_voltage = 12.4;
+    system.battery_remaining = 90;
+    system.sensors = vec![Sensor { name: "GPS".into(), sensor: SensorType::Sns, enabled: true, health: true }];
+
+    let mut raw_sns = RawSns::default_for_id(&vehicle_id);
+    raw_sns.fix = 3;
+    raw_sns.satellites_visible = 12;
+
+    let mut navigation = Navigation::default_for_id(&vehicle_id);
+    navigation.home_position = Geodetic {
+        latitude: 55.97,
+        longitude: 37.41,
+        altitude: 190.0,
+        frame: GeodeticFrame::Wgs84AboveSeaLevel
+    };
+
+    PreflightState {
+        status: Some(status),
+        system: Some(system),
+        raw_sns: Some(raw_sns),
+        navigation: Some(navigation),
+        mission_state: Some(MissionUpdateState::Actual { total: 5 }),
+        now: NOW
+    }
+}
+
+fn settings() -> PreflightSettings {
+    let mut settings = PreflightSettings::default_for_type(&VehicleType::FixedWing);
+    settings.min_battery_voltage = 11.1;
+    settings
+}
+
+fn failed_checks(state: &PreflightState) -> Vec<PreflightCheck> {
+    evaluate(&"mav_1".to_string(), &settings(), state).results.into_iter()
+        .filter(|result| !result.passed)
+        .map(|result| result.check)
+        .collect()
+}
+
+#[test]
+fn test_all_checks_passed() {
+    let report = evaluate(&"mav_1".to_string(), &settings(), &good_state());
+    assert!(report.passed);
+    assert_eq!(report.results.len(), settings().checks.len());
+    assert!(report.results.iter().all(|result| result.reason.is_none()));
+}
+
+#[test_case(|state| state.status.as_mut().unwrap().last_heartbeat = NOW - 5000, PreflightCheck::LinkOnline; "stale heartbeat")]
+#[test_case(|state| state.status = None, PreflightCheck::LinkOnline; "no status")]
+#[test_case(|state| state.system.as_mut().unwrap().sensors[0].health = false, PreflightCheck::Sensors; "unhealthy sensor")]
+#[test_case(|state| state.system.as_mut().unwrap().arm_ready = false, PreflightCheck::ArmReady; "not arm ready")]
+#[test_case(|state| state.system.as_mut().unwrap().battery_voltage = 10.5, PreflightCheck::Battery; "low voltage")]
+#[test_case(|state| state.system.as_mut().unwrap().battery_remaining = 10, PreflightCheck::Battery; "low remaining")]
+#[test_case(|state| state.raw_sns.as_mut().unwrap().fix = 2, PreflightCheck::SnsFix; "2d fix")]
+#[test_case(|state| state.raw_sns.as_mut().unwrap().satellites_visible = 4, PreflightCheck::SnsFix; "few satellites")]
+#[test_case(|state| state.navigation = None, PreflightCheck::HomePosition; "no home")]
+#[test_case(|state| state.mission_state = Some(MissionUpdateState::NotActual {}), PreflightCheck::MissionActual; "mission not actual")]
+#[test_case(|state| state.mission_state = None, PreflightCheck::MissionActual; "no mission")]
+fn test_single_check_failed(spoil: fn(&mut PreflightState), check: PreflightCheck) {
+    let mut state = good_state();
+    spoil(&mut state);
+    assert_eq!(failed_checks(&state), vec![check]);
+}
+
+#[test]
+fn test_unknown_battery_remaining_ignored() {
+    let mut state = good_state();
+    state.system.as_mut().unwrap().battery_remaining = -1;
+    assert!(failed_checks(&state).is_empty());
+}
+
+#[test]
+fn test_disabled_sensor_ignored() {
+    let mut state = good_state();
+    let sensor = &mut state.system.as_mut().unwrap().sensors[0];
+    sensor.enabled = false;
+    sensor.health = false;
+    assert!(failed_checks(&state).is_empty());
+}
+
+#[test]
+fn test_checks_configured_per_type() {
+    let mut state = good_state();
+    state.mission_state = None;
+
+    let copter_settings = PreflightSettings::default_for_type(&VehicleType::Copter);
+    assert!(evaluate(&"mav_1".to_string(), &copter_settings, &state).passed);
+}
+
+#[test]
+fn test_rejection_reason() {
+    let mut state = good_state();
+    state.system.as_mut().unwrap().arm_ready = false;
+    state.raw_sns.as_mut().unwrap().satellites_visible = 4;
+
+    let report = evaluate(&"mav_1".to_string(), &settings(), &state);
+    assert!(!report.passed);
+    assert_eq!(rejection_reason(&report),
+        "Preflight checks failed: Vehicle is not ready to arm; 4 satellites visible, 6 required");
+}
@@ -0,0 +1,3 @@
+pub mod checklist;
+#[cfg(test)]
+mod checklist_test;
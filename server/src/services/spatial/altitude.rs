@@ -0,0 +1,51 @@
+use crate::dal::dal;
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use crate::models::vehicles::VehicleId;
+
+// Converts altitude between frames, none if home or ground needed for that is unknown.
+// Ground is the terrain elevation above sea level at the given latitude and longitude
+pub fn convert_altitude<F>(position: &Geodetic, frame: GeodeticFrame, home: Option<&Geodetic>, ground: F) -> Option<Geodetic>
+where F: Fn(f64, f64) -> Option<f32> {
+    if position.frame == frame {
+        return Some(position.clone());
+    }
+
+    let above_sea_level = match position.frame {
+        GeodeticFrame::Wgs84AboveSeaLevel => position.altitude,
+        GeodeticFrame::Wgs84RelativeHome => home_above_sea_level(home, &ground)? + position.altitude,
+        GeodeticFrame::Wgs84AboveTerrain => ground(position.latitude, position.longitude)? + position.altitude,
+        GeodeticFrame::None => return None
+    };
+    let altitude = match frame {
+        GeodeticFrame::Wgs84AboveSeaLevel => above_sea_level,
+        GeodeticFrame::Wgs84RelativeHome => above_sea_level - home_above_sea_level(home, &ground)?,
+        GeodeticFrame::Wgs84AboveTerrain => above_sea_level - ground(position.latitude, position.longitude)?,
+        GeodeticFrame::None => return None
+    };
+    Some(Geodetic { altitude, frame, ..position.clone() })
+}
+
+// Home relative to itself makes no sense, so it must be reported in an absolute frame
+fn home_above_sea_level<F>(home: Option<&Geodetic>, ground: &F) -> Option<f32>
+where F: Fn(f64, f64) -> Option<f32> {
+    let home = home?;
+    match home.frame {
+        GeodeticFrame::Wgs84AboveSeaLevel => Some(home.altitude),
+        GeodeticFrame::Wgs84AboveTerrain => Some(ground(home.latitude, home.longitude)? + home.altitude),
+        _ => None
+    }
+}
+
+// Converts using the vehicle home position and local terrain
+pub async fn convert_for_vehicle(
+    dal: &dal::Dal,
+    vehicle_id: &VehicleId,
+    position: &Geodetic,
+    frame: GeodeticFrame
+) -> anyhow::Result<Option<Geodetic>> {
+    let home = dal.telemetry_navigation(vehicle_id).await.ok()
+        .map(|navigation| navigation.home_position)
+        .filter(|home| home.latitude != 0.0 || home.longitude != 0.0);
+    let position = position.clone();
+    dal.terrain.query(move |terrain| terrain.convert(&position, frame, home.as_ref())).await
+}
@@ -0,0 +1,94 @@
+use test_case::test_case;
+
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use super::altitude::convert_altitude;
+
+const GROUND: f32 = 200.0;
+
+fn at(altitude: f32, frame: GeodeticFrame) -> Geodetic {
+    Geodetic { latitude: 55.97, longitude: 37.41, altitude, frame }
+}
+
+fn flat(_: f64, _: f64) -> Option<f32> {
+    Some(GROUND)
+}
+
+fn unknown(_: f64, _: f64) -> Option<f32> {
+    None
+}
+
+// Terrain rises to the north by a meter per 0.001 degree
+fn slope(latitude: f64, _: f64) -> Option<f32> {
+    Some(GROUND + ((latitude - 55.97) * 1000.0) as f32)
+}
+
+fn home() -> Geodetic {
+    at(190.0, GeodeticFrame::Wgs84AboveSeaLevel)
+}
+
+#[test_case(at(300.0, GeodeticFrame::Wgs84AboveSeaLevel), GeodeticFrame::Wgs84RelativeHome, Some(110.0); "sea level to home")]
+#[test_case(at(300.0, GeodeticFrame::Wgs84AboveSeaLevel), GeodeticFrame::Wgs84AboveTerrain, Some(100.0); "sea level to terrain")]
+#[test_case(at(50.0, GeodeticFrame::Wgs84RelativeHome), GeodeticFrame::Wgs84AboveSeaLevel, Some(240.0); "home to sea level")]
+#[test_case(at(50.0, GeodeticFrame::Wgs84RelativeHome), GeodeticFrame::Wgs84AboveTerrain, Some(40.0); "home to terrain")]
+#[test_case(at(50.0, GeodeticFrame::Wgs84AboveTerrain), GeodeticFrame::Wgs84AboveSeaLevel, Some(250.0); "terrain to sea level")]
+#[test_case(at(50.0, GeodeticFrame::Wgs84AboveTerrain), GeodeticFrame::Wgs84RelativeHome, Some(60.0); "terrain to home")]
+#[test_case(at(-20.0, GeodeticFrame::Wgs84RelativeHome), GeodeticFrame::Wgs84AboveTerrain, Some(-30.0); "below ground")]
+#[test_case(at(50.0, GeodeticFrame::Wgs84AboveTerrain), GeodeticFrame::Wgs84AboveTerrain, Some(50.0); "same frame")]
+#[test_case(at(50.0, GeodeticFrame::None), GeodeticFrame::Wgs84AboveSeaLevel, None; "from unknown frame")]
+#[test_case(at(50.0, GeodeticFrame::Wgs84AboveSeaLevel), GeodeticFrame::None, None; "to unknown frame")]
+fn test_convert(position: Geodetic, frame: GeodeticFrame, expected: Option<f32>) {
+    let converted = convert_altitude(&position, frame.clone(), Some(&home()), flat);
+
+    assert_eq!(converted.as_ref().map(|converted| converted.altitude), expected);
+    if let Some(converted) = converted {
+        assert_eq!(converted.frame, frame);
+        assert_eq!((converted.latitude, converted.longitude), (position.latitude, position.longitude));
+    }
+}
+
+#[test_case(GeodeticFrame::Wgs84AboveSeaLevel; "sea level")]
+#[test_case(GeodeticFrame::Wgs84RelativeHome; "home")]
+#[test_case(GeodeticFrame::Wgs84AboveTerrain; "terrain")]
+fn test_round_trip(frame: GeodeticFrame) {
+    let position = at(123.5, GeodeticFrame::Wgs84AboveSeaLevel);
+    let converted = convert_altitude(&position, frame, Some(&home()), flat).unwrap();
+    let back = convert_altitude(&converted, GeodeticFrame::Wgs84AboveSeaLevel, Some(&home()), flat).unwrap();
+
+    assert!((back.altitude - position.altitude).abs() < 1e-4);
+}
+
+#[test]
+fn test_terrain_at_position() {
+    // Home is on lower ground, so a point to the north is lower above terrain than above home
+    let position = Geodetic { latitude: 56.07, ..at(100.0, GeodeticFrame::Wgs84RelativeHome) };
+    let converted = convert_altitude(&position, GeodeticFrame::Wgs84AboveTerrain, Some(&home()), slope).unwrap();
+
+    assert!((converted.altitude - (190.0 + 100.0 - 300.0)).abs() < 1e-3, "altitude {}", converted.altitude);
+}
+
+#[test]
+fn test_home_above_terrain() {
+    let home = at(2.0, GeodeticFrame::Wgs84AboveTerrain);
+    let converted = convert_altitude(&at(30.0, GeodeticFrame::Wgs84RelativeHome), GeodeticFrame::Wgs84AboveSeaLevel, Some(&home), flat);
+
+    assert_eq!(converted.map(|converted| converted.altitude), Some(232.0));
+}
+
+#[test_case(at(30.0, GeodeticFrame::Wgs84RelativeHome), GeodeticFrame::Wgs84AboveSeaLevel, None; "no home")]
+#[test_case(at(30.0, GeodeticFrame::Wgs84RelativeHome), GeodeticFrame::Wgs84AboveSeaLevel, Some(at(0.0, GeodeticFrame::Wgs84RelativeHome)); "relative home")]
+#[test_case(at(30.0, GeodeticFrame::Wgs84AboveSeaLevel), GeodeticFrame::Wgs84RelativeHome, Some(at(0.0, GeodeticFrame::None)); "home without frame")]
+fn test_convert_without_home(position: Geodetic, frame: GeodeticFrame, home: Option<Geodetic>) {
+    assert_eq!(convert_altitude(&position, frame, home.as_ref(), flat), None);
+}
+
+#[test_case(GeodeticFrame::Wgs84AboveTerrain, GeodeticFrame::Wgs84AboveSeaLevel; "from terrain")]
+#[test_case(GeodeticFrame::Wgs84AboveSeaLevel, GeodeticFrame::Wgs84AboveTerrain; "to terrain")]
+fn test_convert_without_terrain(from: GeodeticFrame, to: GeodeticFrame) {
+    assert_eq!(convert_altitude(&at(30.0, from), to, Some(&home()), unknown), None);
+}
+
+#[test]
+fn test_home_conversion_without_terrain() {
+    let converted = convert_altitude(&at(30.0, GeodeticFrame::Wgs84RelativeHome), GeodeticFrame::Wgs84AboveSeaLevel, Some(&home()), unknown);
+    assert_eq!(converted.map(|converted| converted.altitude), Some(220.0));
+}
@@ -0,0 +1,101 @@
+use crate::models::spatial::Geodetic;
+
+// Great-circle geodesy on a sphere of the mean earth radius, within 0.5% of the ellipsoid
+pub const EARTH_RADIUS: f64 = 6371008.8; // mean radius, meters
+
+// Degrees from north clockwise in [0, 360)
+pub fn normalize_bearing(bearing: f64) -> f64 {
+    let bearing = bearing.rem_euclid(360.0);
+    if bearing >= 360.0 { 0.0 } else { bearing }
+}
+
+// Degrees in [-180, 180)
+pub fn normalize_longitude(longitude: f64) -> f64 {
+    (longitude + 180.0).rem_euclid(360.0) - 180.0
+}
+
+impl Geodetic {
+    // Central angle to the other point, radians
+    fn angle_to(&self, other: &Geodetic) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * a.sqrt().min(1.0).asin()
+    }
+
+    // Great-circle distance in meters, altitude is ignored
+    pub fn distance_to(&self, other: &Geodetic) -> f64 {
+        EARTH_RADIUS * self.angle_to(other)
+    }
+
+    // Initial bearing to the other point, degrees
+    pub fn bearing_to(&self, other: &Geodetic) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let y = d_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_lon.cos();
+        normalize_bearing(y.atan2(x).to_degrees())
+    }
+
+    // Point at the distance along the initial bearing, altitude and frame are kept
+    pub fn destination(&self, bearing: f64, distance: f64) -> Geodetic {
+        let lat1 = self.latitude.to_radians();
+        let lon1 = self.longitude.to_radians();
+        let bearing = bearing.to_radians();
+        let angle = distance / EARTH_RADIUS;
+
+        let lat2 = (lat1.sin() * angle.cos() + lat1.cos() * angle.sin() * bearing.cos()).clamp(-1.0, 1.0).asin();
+        let lon2 = lon1 + (bearing.sin() * angle.sin() * lat1.cos()).atan2(angle.cos() - lat1.sin() * lat2.sin());
+        Geodetic {
+            latitude: lat2.to_degrees(),
+            longitude: normalize_longitude(lon2.to_degrees()),
+            ..self.clone()
+        }
+    }
+
+    // Point on the great circle at the fraction of the way, altitude is interpolated linearly
+    pub fn intermediate(&self, other: &Geodetic, fraction: f64) -> Geodetic {
+        let altitude = self.altitude + (other.altitude - self.altitude) * fraction as f32;
+        let angle = self.angle_to(other);
+        if angle < f64::EPSILON {
+            return Geodetic { altitude, ..self.clone() };
+        }
+
+        let (lat1, lon1) = (self.latitude.to_radians(), self.longitude.to_radians());
+        let (lat2, lon2) = (other.latitude.to_radians(), other.longitude.to_radians());
+        let a = ((1.0 - fraction) * angle).sin() / angle.sin();
+        let b = (fraction * angle).sin() / angle.sin();
+
+        let x = a * lat1.cos() * lon1.cos() + b * lat2.cos() * lon2.cos();
+        let y = a * lat1.cos() * lon1.sin() + b * lat2.cos() * lon2.sin();
+        let z = a * lat1.sin() + b * lat2.sin();
+        Geodetic {
+            latitude: z.atan2(x.hypot(y)).to_degrees(),
+            longitude: normalize_longitude(y.atan2(x).to_degrees()),
+            altitude,
+            frame: self.frame.clone()
+        }
+    }
+
+    // Distance to the great circle through start and end, positive to the right of the path
+    pub fn cross_track_distance(&self, start: &Geodetic, end: &Geodetic) -> f64 {
+        let angle = start.angle_to(self);
+        let course = (start.bearing_to(self) - start.bearing_to(end)).to_radians();
+        EARTH_RADIUS * (angle.sin() * course.sin()).clamp(-1.0, 1.0).asin()
+    }
+
+    // Distance from start to the closest point of the path, negative if it is behind start
+    pub fn along_track_distance(&self, start: &Geodetic, end: &Geodetic) -> f64 {
+        let angle = start.angle_to(self);
+        let course = (start.bearing_to(self) - start.bearing_to(end)).to_radians();
+        let cross_track = self.cross_track_distance(start, end) / EARTH_RADIUS;
+
+        let along_track = (angle.cos() / cross_track.cos()).clamp(-1.0, 1.0).acos();
+        EARTH_RADIUS * along_track * course.cos().signum()
+    }
+}
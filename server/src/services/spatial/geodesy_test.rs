@@ -0,0 +1,148 @@
+use test_case::test_case;
+
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use super::geodesy::*;
+
+fn point(latitude: f64, longitude: f64) -> Geodetic {
+    Geodetic { latitude, longitude, altitude: 0.0, frame: GeodeticFrame::Wgs84AboveSeaLevel }
+}
+
+// Land's End and John o' Groats
+fn lands_end() -> Geodetic {
+    point(50.066_389, -5.714_722)
+}
+
+fn john_o_groats() -> Geodetic {
+    point(58.643_889, -3.07)
+}
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!((actual - expected).abs() <= tolerance, "{} != {} within {}", actual, expected, tolerance);
+}
+
+#[test_case(0.0, 0.0; "zero")]
+#[test_case(360.0, 0.0; "full turn")]
+#[test_case(-90.0, 270.0; "negative")]
+#[test_case(725.0, 5.0; "two turns")]
+#[test_case(-1e-15, 0.0; "tiny negative")]
+fn test_normalize_bearing(bearing: f64, expected: f64) {
+    assert_close(normalize_bearing(bearing), expected, 1e-9);
+}
+
+#[test_case(180.0, -180.0; "antimeridian")]
+#[test_case(181.0, -179.0; "over antimeridian")]
+#[test_case(-181.0, 179.0; "under antimeridian")]
+#[test_case(37.5, 37.5; "inside")]
+fn test_normalize_longitude(longitude: f64, expected: f64) {
+    assert_close(normalize_longitude(longitude), expected, 1e-9);
+}
+
+#[test_case(point(0.0, 0.0), point(0.0, 1.0), 111_195.08; "degree on equator")]
+#[test_case(point(0.0, 0.0), point(1.0, 0.0), 111_195.08; "degree on meridian")]
+#[test_case(point(89.0, 0.0), point(89.0, 180.0), 222_390.16; "over the pole")]
+#[test_case(point(0.0, 179.5), point(0.0, -179.5), 111_195.08; "over antimeridian")]
+#[test_case(point(0.0, 0.0), point(0.0, 180.0), 20_015_114.4; "antipodal")]
+#[test_case(lands_end(), john_o_groats(), 968_854.9; "reference")]
+#[test_case(point(55.97, 37.41), point(55.97, 37.41), 0.0; "same point")]
+fn test_distance(from: Geodetic, to: Geodetic, expected: f64) {
+    assert_close(from.distance_to(&to), expected, 0.1);
+    assert_close(to.distance_to(&from), expected, 0.1);
+}
+
+#[test_case(point(0.0, 0.0), point(1.0, 0.0), 0.0; "north")]
+#[test_case(point(0.0, 0.0), point(0.0, 1.0), 90.0; "east")]
+#[test_case(point(0.0, 0.0), point(-1.0, 0.0), 180.0; "south")]
+#[test_case(point(0.0, 0.0), point(0.0, -1.0), 270.0; "west")]
+#[test_case(point(0.0, 179.5), point(0.0, -179.5), 90.0; "east over antimeridian")]
+#[test_case(lands_end(), john_o_groats(), 9.119_8; "reference")]
+#[test_case(john_o_groats(), lands_end(), 191.275_2; "reference back")]
+fn test_bearing(from: Geodetic, to: Geodetic, expected: f64) {
+    assert_close(from.bearing_to(&to), expected, 1e-3);
+}
+
+#[test_case(point(53.320_6, -1.729_7), 96.021_7, 124_800.0, 53.188_3, 0.133_3; "reference")]
+#[test_case(point(0.0, 0.0), 0.0, 111_195.08, 1.0, 0.0; "north")]
+#[test_case(point(0.0, 179.5), 90.0, 111_195.08, 0.0, -179.5; "over antimeridian")]
+#[test_case(point(55.97, 37.41), 45.0, 0.0, 55.97, 37.41; "zero distance")]
+fn test_destination(from: Geodetic, bearing: f64, distance: f64, latitude: f64, longitude: f64) {
+    let destination = from.destination(bearing, distance);
+
+    assert_close(destination.latitude, latitude, 1e-4);
+    assert_close(destination.longitude, longitude, 1e-4);
+    assert_eq!(destination.frame, from.frame);
+}
+
+#[test_case(10.0; "short")]
+#[test_case(5_000.0; "middle")]
+#[test_case(2_000_000.0; "long")]
+fn test_destination_inverse(distance: f64) {
+    let from = point(55.97, 37.41);
+    for bearing in [0.0, 30.0, 135.0, 200.0, 315.0] {
+        let to = from.destination(bearing, distance);
+        assert_close(from.distance_to(&to), distance, distance * 1e-9 + 1e-6);
+        assert_close(from.bearing_to(&to), bearing, 1e-6);
+    }
+}
+
+#[test]
+fn test_intermediate() {
+    let middle = lands_end().intermediate(&john_o_groats(), 0.5);
+
+    assert_close(middle.latitude, 54.362_3, 1e-4);
+    assert_close(middle.longitude, -4.530_7, 1e-4);
+    assert_close(lands_end().distance_to(&middle), middle.distance_to(&john_o_groats()), 1e-6);
+}
+
+#[test_case(0.0; "start")]
+#[test_case(0.25; "quarter")]
+#[test_case(1.0; "end")]
+fn test_intermediate_fraction(fraction: f64) {
+    let mut from = point(55.97, 37.41);
+    from.altitude = 100.0;
+    let mut to = point(56.5, 38.2);
+    to.altitude = 300.0;
+    let total = from.distance_to(&to);
+
+    let point = from.intermediate(&to, fraction);
+    assert_close(from.distance_to(&point), total * fraction, 1e-6);
+    assert_close(point.altitude as f64, 100.0 + 200.0 * fraction, 1e-3);
+}
+
+#[test]
+fn test_intermediate_same_point() {
+    let from = point(55.97, 37.41);
+    assert_eq!(from.intermediate(&from, 0.5), from);
+}
+
+#[test]
+fn test_cross_track_reference() {
+    let start = point(53.320_6, -1.729_7);
+    let end = point(53.188_7, 0.133_3);
+    let position = point(53.261_1, -0.797_2);
+
+    assert_close(position.cross_track_distance(&start, &end), -307.98, 0.1);
+    assert_close(position.along_track_distance(&start, &end), 62_331.58, 0.1);
+}
+
+#[test_case(100.0, 100.0; "right")]
+#[test_case(-100.0, -100.0; "left")]
+#[test_case(0.0, 0.0; "on track")]
+fn test_cross_track_side(offset: f64, expected: f64) {
+    // Path to the north, offset to the east is on the right
+    let start = point(55.0, 37.0);
+    let end = start.destination(0.0, 10_000.0);
+    let position = start.destination(0.0, 3_000.0).destination(90.0, offset);
+
+    assert_close(position.cross_track_distance(&start, &end), expected, 0.01);
+    assert_close(position.along_track_distance(&start, &end), 3_000.0, 0.01);
+}
+
+#[test]
+fn test_along_track_behind_start() {
+    let start = point(55.0, 37.0);
+    let end = start.destination(90.0, 10_000.0);
+    let position = start.destination(270.0, 500.0);
+
+    assert_close(position.along_track_distance(&start, &end), -500.0, 0.01);
+    assert_close(position.cross_track_distance(&start, &end), 0.0, 0.01);
+}
@@ -0,0 +1,68 @@
+use crate::models::spatial::{Enu, Geodetic, Ned};
+
+// WGS84 ellipsoid, local offsets go through earth-centered coordinates to stay exact
+const WGS84_A: f64 = 6378137.0;
+const WGS84_F: f64 = 1.0 / 298.257223563;
+const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F);
+const ECEF_ITERATIONS: usize = 5;
+
+fn to_ecef(position: &Geodetic) -> [f64; 3] {
+    let (lat, lon) = (position.latitude.to_radians(), position.longitude.to_radians());
+    let height = position.altitude as f64;
+    let n = WGS84_A / (1.0 - WGS84_E2 * lat.sin().powi(2)).sqrt();
+    [
+        (n + height) * lat.cos() * lon.cos(),
+        (n + height) * lat.cos() * lon.sin(),
+        (n * (1.0 - WGS84_E2) + height) * lat.sin()
+    ]
+}
+
+// Latitude, longitude in degrees and height
+fn from_ecef([x, y, z]: [f64; 3]) -> (f64, f64, f64) {
+    let p = x.hypot(y);
+    let mut lat = z.atan2(p * (1.0 - WGS84_E2));
+    for _ in 0..ECEF_ITERATIONS {
+        let n = WGS84_A / (1.0 - WGS84_E2 * lat.sin().powi(2)).sqrt();
+        let height = p * lat.cos() + z * lat.sin() - WGS84_A * WGS84_A / n;
+        lat = z.atan2(p * (1.0 - WGS84_E2 * n / (n + height)));
+    }
+    let n = WGS84_A / (1.0 - WGS84_E2 * lat.sin().powi(2)).sqrt();
+    let height = p * lat.cos() + z * lat.sin() - WGS84_A * WGS84_A / n;
+    (lat.to_degrees(), y.atan2(x).to_degrees(), height)
+}
+
+impl Geodetic {
+    // Offset of the other point in the local plane here, altitudes are taken as in the same frame
+    pub fn enu_to(&self, other: &Geodetic) -> Enu {
+        let origin = to_ecef(self);
+        let target = to_ecef(other);
+        let [dx, dy, dz] = [target[0] - origin[0], target[1] - origin[1], target[2] - origin[2]];
+        let (lat, lon) = (self.latitude.to_radians(), self.longitude.to_radians());
+
+        Enu {
+            east: -lon.sin() * dx + lon.cos() * dy,
+            north: -lat.sin() * lon.cos() * dx - lat.sin() * lon.sin() * dy + lat.cos() * dz,
+            up: lat.cos() * lon.cos() * dx + lat.cos() * lon.sin() * dy + lat.sin() * dz
+        }
+    }
+
+    pub fn ned_to(&self, other: &Geodetic) -> Ned {
+        self.enu_to(other).into()
+    }
+
+    // Point at the local offset from here, in the same altitude frame
+    pub fn offset_enu(&self, offset: &Enu) -> Geodetic {
+        let origin = to_ecef(self);
+        let (lat, lon) = (self.latitude.to_radians(), self.longitude.to_radians());
+        let dx = -lon.sin() * offset.east - lat.sin() * lon.cos() * offset.north + lat.cos() * lon.cos() * offset.up;
+        let dy = lon.cos() * offset.east - lat.sin() * lon.sin() * offset.north + lat.cos() * lon.sin() * offset.up;
+        let dz = lat.cos() * offset.north + lat.sin() * offset.up;
+
+        let (latitude, longitude, height) = from_ecef([origin[0] + dx, origin[1] + dy, origin[2] + dz]);
+        Geodetic { latitude, longitude, altitude: height as f32, frame: self.frame.clone() }
+    }
+
+    pub fn offset_ned(&self, offset: &Ned) -> Geodetic {
+        self.offset_enu(&offset.clone().into())
+    }
+}
@@ -0,0 +1,86 @@
+use test_case::test_case;
+
+use crate::models::spatial::{Enu, Geodetic, GeodeticFrame, Ned};
+
+fn point(latitude: f64, longitude: f64, altitude: f32) -> Geodetic {
+    Geodetic { latitude, longitude, altitude, frame: GeodeticFrame::Wgs84AboveSeaLevel }
+}
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!((actual - expected).abs() <= tolerance, "{} != {} within {}", actual, expected, tolerance);
+}
+
+fn assert_enu(actual: &Enu, east: f64, north: f64, up: f64, tolerance: f64) {
+    assert_close(actual.east, east, tolerance);
+    assert_close(actual.north, north, tolerance);
+    assert_close(actual.up, up, tolerance);
+}
+
+#[test]
+fn test_same_point() {
+    let origin = point(55.97, 37.41, 150.0);
+    assert_enu(&origin.enu_to(&origin), 0.0, 0.0, 0.0, 1e-9);
+}
+
+#[test_case(point(0.0, 1.0, 0.0), 111_313.839, 0.0, -971.421; "degree east on equator")]
+#[test_case(point(0.01, 0.0, 0.0), 0.0, 1_105.743, -0.096; "north on equator")]
+#[test_case(point(0.0, 0.0, 100.0), 0.0, 0.0, 100.0; "above")]
+fn test_enu_on_equator(target: Geodetic, east: f64, north: f64, up: f64) {
+    assert_enu(&point(0.0, 0.0, 0.0).enu_to(&target), east, north, up, 1e-3);
+}
+
+#[test]
+fn test_enu_reference() {
+    let origin = point(55.97, 37.41, 100.0);
+    let target = point(55.98, 37.42, 150.0);
+
+    assert_enu(&origin.enu_to(&target), 624.265, 1_113.485, 49.872, 1e-3);
+}
+
+#[test]
+fn test_ned_is_enu_swapped() {
+    let origin = point(55.97, 37.41, 100.0);
+    let target = point(55.98, 37.42, 150.0);
+    let enu = origin.enu_to(&target);
+    let ned = origin.ned_to(&target);
+
+    assert_eq!((ned.north, ned.east, ned.down), (enu.north, enu.east, -enu.up));
+    assert_eq!(Enu::from(ned), enu);
+}
+
+#[test_case(Enu { east: 0.0, north: 0.0, up: 0.0 }; "zero")]
+#[test_case(Enu { east: 100.0, north: -250.0, up: 30.0 }; "short")]
+#[test_case(Enu { east: -20_000.0, north: 35_000.0, up: -50.0 }; "long")]
+fn test_offset_round_trip(offset: Enu) {
+    let origin = point(55.97, 37.41, 150.0);
+    let target = origin.offset_enu(&offset);
+
+    assert_eq!(target.frame, origin.frame);
+    assert_enu(&origin.enu_to(&target), offset.east, offset.north, offset.up, 1e-3);
+}
+
+#[test]
+fn test_offset_ned() {
+    let origin = point(-33.9, 151.2, 50.0);
+    let target = origin.offset_ned(&Ned { north: 500.0, east: 200.0, down: 20.0 });
+    let ned = origin.ned_to(&target);
+
+    assert_close(ned.north, 500.0, 1e-3);
+    assert_close(ned.east, 200.0, 1e-3);
+    assert_close(ned.down, 20.0, 1e-3);
+    assert!(target.latitude > origin.latitude && target.longitude > origin.longitude);
+}
+
+#[test_case(0.0; "north")]
+#[test_case(60.0; "north east")]
+#[test_case(225.0; "south west")]
+fn test_local_matches_great_circle(bearing: f64) {
+    // Sphere and ellipsoid agree within half a percent over short distances
+    let origin = point(55.97, 37.41, 0.0);
+    let target = origin.destination(bearing, 1_000.0);
+    let enu = origin.enu_to(&target);
+
+    assert_close(enu.east.hypot(enu.north), 1_000.0, 5.0);
+    let bearing_error = (enu.east.atan2(enu.north).to_degrees() - bearing + 180.0).rem_euclid(360.0) - 180.0;
+    assert_close(bearing_error, 0.0, 0.2);
+}
@@ -0,0 +1,9 @@
+pub mod geodesy;
+#[cfg(test)]
+mod geodesy_test;
+pub mod local;
+#[cfg(test)]
+mod local_test;
+pub mod altitude;
+#[cfg(test)]
+mod altitude_test;
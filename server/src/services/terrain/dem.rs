@@ -0,0 +1,172 @@
+use std::io::Cursor;
+use tiff::decoder::{Decoder, DecodingResult};
+use tiff::tags::Tag;
+
+const HGT_VOID: i16 = -32768;
+
+// GeoKeys, see GeoTIFF specification 6.3
+const GEO_KEY_MODEL_TYPE: u16 = 1024;
+const GEO_KEY_RASTER_TYPE: u16 = 1025;
+const MODEL_TYPE_GEOGRAPHIC: u16 = 2;
+const RASTER_PIXEL_IS_POINT: u16 = 2;
+
+// Regular grid of elevation samples in WGS84 degrees, rows go from north to south
+#[derive(Clone, Debug, PartialEq)]
+pub struct DemTile {
+    pub north: f64,         // Latitude of the first row samples
+    pub west: f64,          // Longitude of the first column samples
+    pub lat_step: f64,
+    pub lon_step: f64,
+    pub rows: usize,
+    pub cols: usize,
+    pub heights: Vec<f32>   // Meters above sea level, NaN for voids
+}
+
+impl DemTile {
+    pub fn south(&self) -> f64 {
+        self.north - self.lat_step * (self.rows - 1) as f64
+    }
+
+    pub fn east(&self) -> f64 {
+        self.west + self.lon_step * (self.cols - 1) as f64
+    }
+
+    pub fn contains(&self, latitude: f64, longitude: f64) -> bool {
+        latitude <= self.north && latitude >= self.south() && longitude >= self.west && longitude <= self.east()
+    }
+
+    fn height(&self, row: usize, col: usize) -> f32 {
+        self.heights[row * self.cols + col]
+    }
+
+    // Bilinear interpolation, void samples are left out of the weighting unless nearest one is void
+    pub fn elevation(&self, latitude: f64, longitude: f64) -> Option<f32> {
+        if !self.contains(latitude, longitude) {
+            return None;
+        }
+        let row = ((self.north - latitude) / self.lat_step).clamp(0.0, (self.rows - 1) as f64);
+        let col = ((longitude - self.west) / self.lon_step).clamp(0.0, (self.cols - 1) as f64);
+
+        let top = (row.floor() as usize).min(self.rows - 2);
+        let left = (col.floor() as usize).min(self.cols - 2);
+        let dy = row - top as f64;
+        let dx = col - left as f64;
+
+        if self.height(row.round() as usize, col.round() as usize).is_nan() {
+            return None;
+        }
+        let corners = [
+            (self.height(top, left), (1.0 - dx) * (1.0 - dy)),
+            (self.height(top, left + 1), dx * (1.0 - dy)),
+            (self.height(top + 1, left), (1.0 - dx) * dy),
+            (self.height(top + 1, left + 1), dx * dy)
+        ];
+        let (sum, weights) = corners.iter()
+            .filter(|(height, weight)| !height.is_nan() && *weight > 0.0)
+            .fold((0.0, 0.0), |(sum, weights), (height, weight)| (sum + *height as f64 * weight, weights + weight));
+        if weights > 0.0 { Some((sum / weights) as f32) } else { None }
+    }
+}
+
+// SRTM tile name is the south-west corner, e.g. N55E037.hgt
+pub fn hgt_tile_name(latitude: f64, longitude: f64) -> String {
+    let lat = latitude.floor() as i32;
+    let lon = longitude.floor() as i32;
+    format!("{}{:02}{}{:03}.hgt",
+        if lat < 0 { 'S' } else { 'N' }, lat.abs(),
+        if lon < 0 { 'W' } else { 'E' }, lon.abs())
+}
+
+fn parse_hgt_name(name: &str) -> anyhow::Result<(i32, i32)> {
+    let stem = name.split('.').next().unwrap_or_default().to_uppercase();
+    if stem.len() != 7 || !stem.is_ascii() {
+        return Err(anyhow::anyhow!("Invalid HGT tile name {}", name));
+    }
+    let lat: i32 = stem[1..3].parse()?;
+    let lon: i32 = stem[4..7].parse()?;
+    let lat = match &stem[0..1] { "N" => lat, "S" => -lat, _ => return Err(anyhow::anyhow!("Invalid HGT tile name {}", name)) };
+    let lon = match &stem[3..4] { "E" => lon, "W" => -lon, _ => return Err(anyhow::anyhow!("Invalid HGT tile name {}", name)) };
+    Ok((lat, lon))
+}
+
+// One degree square of big-endian i16 samples, edges are shared with neighbour tiles
+pub fn parse_hgt(name: &str, bytes: &[u8]) -> anyhow::Result<DemTile> {
+    let (lat, lon) = parse_hgt_name(name)?;
+    let size = ((bytes.len() / 2) as f64).sqrt() as usize;
+    if size < 2 || size * size * 2 != bytes.len() {
+        return Err(anyhow::anyhow!("HGT tile {} is not a square grid: {} bytes", name, bytes.len()));
+    }
+    let heights = bytes.chunks_exact(2)
+        .map(|sample| i16::from_be_bytes([sample[0], sample[1]]))
+        .map(|height| if height == HGT_VOID { f32::NAN } else { height as f32 })
+        .collect();
+    let step = 1.0 / (size - 1) as f64;
+    Ok(DemTile {
+        north: (lat + 1) as f64,
+        west: lon as f64,
+        lat_step: step,
+        lon_step: step,
+        rows: size,
+        cols: size,
+        heights
+    })
+}
+
+fn geo_key(directory: &[u16], key: u16) -> Option<u16> {
+    // Header of four shorts, then entries of key, location, count and value
+    directory.get(4..)?.chunks_exact(4)
+        .find(|entry| entry[0] == key && entry[1] == 0)
+        .map(|entry| entry[3])
+}
+
+// Single band GeoTIFF in geographic coordinates, north-up without rotation
+pub fn parse_geotiff(bytes: &[u8]) -> anyhow::Result<DemTile> {
+    let mut decoder = Decoder::new(Cursor::new(bytes))?;
+    let (cols, rows) = decoder.dimensions()?;
+    let (cols, rows) = (cols as usize, rows as usize);
+    if cols < 2 || rows < 2 {
+        return Err(anyhow::anyhow!("GeoTIFF is too small: {}x{}", cols, rows));
+    }
+
+    let scale = decoder.get_tag_f64_vec(Tag::ModelPixelScaleTag)?;
+    let tiepoint = decoder.get_tag_f64_vec(Tag::ModelTiepointTag)?;
+    if scale.len() < 2 || tiepoint.len() < 6 || scale[0] <= 0.0 || scale[1] <= 0.0 {
+        return Err(anyhow::anyhow!("GeoTIFF has invalid georeferencing"));
+    }
+    let geo_keys = decoder.get_tag_u16_vec(Tag::GeoKeyDirectoryTag).unwrap_or_default();
+    if geo_key(&geo_keys, GEO_KEY_MODEL_TYPE).is_some_and(|model| model != MODEL_TYPE_GEOGRAPHIC) {
+        return Err(anyhow::anyhow!("Only geographic GeoTIFF is supported"));
+    }
+    let no_data = decoder.get_tag_ascii_string(Tag::GdalNodata).ok()
+        .and_then(|value| value.trim_matches(char::from(0)).trim().parse::<f64>().ok());
+
+    let heights: Vec<f64> = match decoder.read_image()? {
+        DecodingResult::I16(data) => data.into_iter().map(f64::from).collect(),
+        DecodingResult::U16(data) => data.into_iter().map(f64::from).collect(),
+        DecodingResult::I32(data) => data.into_iter().map(f64::from).collect(),
+        DecodingResult::F32(data) => data.into_iter().map(f64::from).collect(),
+        DecodingResult::F64(data) => data,
+        _ => return Err(anyhow::anyhow!("Unsupported GeoTIFF sample format"))
+    };
+    if heights.len() != cols * rows {
+        return Err(anyhow::anyhow!("GeoTIFF must have a single band"));
+    }
+
+    // Tiepoint raster coordinates may point to any pixel, pixel-is-area refers to its corner
+    let (lon_step, lat_step) = (scale[0], scale[1]);
+    let offset = if geo_key(&geo_keys, GEO_KEY_RASTER_TYPE) == Some(RASTER_PIXEL_IS_POINT) { 0.0 } else { 0.5 };
+    let west = tiepoint[3] + (offset - tiepoint[0]) * lon_step;
+    let north = tiepoint[4] - (offset - tiepoint[1]) * lat_step;
+
+    Ok(DemTile {
+        north,
+        west,
+        lat_step,
+        lon_step,
+        rows,
+        cols,
+        heights: heights.into_iter()
+            .map(|height| if Some(height) == no_data { f32::NAN } else { height as f32 })
+            .collect()
+    })
+}
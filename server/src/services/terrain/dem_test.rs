@@ -0,0 +1,78 @@
+use test_case::test_case;
+
+use super::dem::{hgt_tile_name, parse_geotiff, parse_hgt};
+
+const HGT_FIXTURE: &[u8] = include_bytes!("../../../tests/fixtures/terrain/N55E037.hgt");
+const GEOTIFF_FIXTURE: &[u8] = include_bytes!("../../../tests/fixtures/terrain/N56E038_area.tif");
+
+fn assert_elevation(actual: Option<f32>, expected: Option<f32>) {
+    match (actual, expected) {
+        (Some(actual), Some(expected)) => assert!((actual - expected).abs() < 0.01, "elevation {} != {}", actual, expected),
+        _ => assert_eq!(actual, expected)
+    }
+}
+
+#[test_case(55.97, 37.41, "N55E037.hgt"; "north east")]
+#[test_case(-33.5, -70.6, "S34W071.hgt"; "south west")]
+#[test_case(0.5, -0.5, "N00W001.hgt"; "around zero")]
+#[test_case(55.0, 37.0, "N55E037.hgt"; "corner")]
+fn test_hgt_tile_name(latitude: f64, longitude: f64, expected: &str) {
+    assert_eq!(hgt_tile_name(latitude, longitude), expected);
+}
+
+#[test]
+fn test_parse_hgt_bounds() {
+    let tile = parse_hgt("N55E037.hgt", HGT_FIXTURE).unwrap();
+
+    assert_eq!((tile.rows, tile.cols), (11, 11));
+    assert_eq!((tile.north, tile.west), (56.0, 37.0));
+    assert!((tile.south() - 55.0).abs() < 1e-9);
+    assert!((tile.east() - 38.0).abs() < 1e-9);
+}
+
+#[test_case(56.0, 37.0, Some(200.0); "north west sample")]
+#[test_case(55.0, 38.0, Some(250.0); "south east sample")]
+#[test_case(55.95, 37.05, Some(202.5); "between samples")]
+#[test_case(55.5, 37.25, Some(200.0); "inside")]
+#[test_case(56.0, 38.0, None; "void sample")]
+#[test_case(55.95, 37.94, Some(289.375); "next to void")]
+#[test_case(54.9, 37.5, None; "outside")]
+fn test_hgt_elevation(latitude: f64, longitude: f64, expected: Option<f32>) {
+    let tile = parse_hgt("N55E037.hgt", HGT_FIXTURE).unwrap();
+    assert_elevation(tile.elevation(latitude, longitude), expected);
+}
+
+#[test_case("N55E037.hgt", &[0; 10]; "not square")]
+#[test_case("N55E037.hgt", &[0; 2]; "single sample")]
+#[test_case("X55E037.hgt", &[0; 8]; "bad hemisphere")]
+#[test_case("tile.hgt", &[0; 8]; "bad name")]
+fn test_parse_hgt_invalid(name: &str, bytes: &[u8]) {
+    assert!(parse_hgt(name, bytes).is_err());
+}
+
+#[test]
+fn test_parse_geotiff_bounds() {
+    let tile = parse_geotiff(GEOTIFF_FIXTURE).unwrap();
+
+    // Pixel is area, so samples are at pixel centers
+    assert_eq!((tile.rows, tile.cols), (4, 5));
+    assert!((tile.north - 55.995).abs() < 1e-9);
+    assert!((tile.west - 38.005).abs() < 1e-9);
+    assert!((tile.south() - 55.965).abs() < 1e-9);
+    assert!((tile.east() - 38.045).abs() < 1e-9);
+}
+
+#[test_case(55.995, 38.005, Some(300.0); "first sample")]
+#[test_case(55.975, 38.025, Some(306.0); "inner sample")]
+#[test_case(55.99, 38.01, Some(301.5); "between samples")]
+#[test_case(55.965, 38.045, None; "no data")]
+#[test_case(55.999, 38.005, None; "outside samples")]
+fn test_geotiff_elevation(latitude: f64, longitude: f64, expected: Option<f32>) {
+    let tile = parse_geotiff(GEOTIFF_FIXTURE).unwrap();
+    assert_elevation(tile.elevation(latitude, longitude), expected);
+}
+
+#[test]
+fn test_parse_geotiff_invalid() {
+    assert!(parse_geotiff(&HGT_FIXTURE[..64]).is_err());
+}
@@ -0,0 +1,6 @@
+pub mod dem;
+#[cfg(test)]
+mod dem_test;
+pub mod provider;
+#[cfg(test)]
+mod provider_test;
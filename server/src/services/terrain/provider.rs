@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use crate::models::terrain::{TerrainElevation, TerrainProfile, TerrainProfilePoint};
+use crate::services::spatial::altitude;
+use super::dem::{self, DemTile};
+
+pub const TERRAIN_DIRECTORY: &str = "terrain"; // relative to the working directory
+const MAX_PROFILE_POINTS: usize = 10000;
+
+#[derive(Default)]
+struct TileCache {
+    // By tile name, none if there is no such tile or it's broken
+    hgt: HashMap<String, Option<Arc<DemTile>>>,
+    // GeoTIFF tiles have arbitrary bounds, so all of them are loaded on first need
+    geotiffs: Option<Vec<Arc<DemTile>>>
+}
+
+// Elevation from local DEM tiles, missing tiles just give no elevation
+#[derive(Clone)]
+pub struct Terrain {
+    directory: Option<PathBuf>,
+    cache: Arc<Mutex<TileCache>>
+}
+
+impl Terrain {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: Some(directory.into()), cache: Arc::new(Mutex::new(TileCache::default())) }
+    }
+
+    #[cfg(test)]
+    pub fn from_tiles(tiles: Vec<DemTile>) -> Self {
+        let cache = TileCache { hgt: HashMap::new(), geotiffs: Some(tiles.into_iter().map(Arc::new).collect()) };
+        Self { directory: None, cache: Arc::new(Mutex::new(cache)) }
+    }
+
+    // Tiles are read from disk on first need, async code has to query terrain through this
+    pub async fn query<R, F>(&self, query: F) -> anyhow::Result<R>
+    where R: Send + 'static, F: FnOnce(&Terrain) -> R + Send + 'static {
+        let terrain = self.clone();
+        Ok(tokio::task::spawn_blocking(move || query(&terrain)).await?)
+    }
+
+    pub fn elevation(&self, latitude: f64, longitude: f64) -> Option<f32> {
+        let name = dem::hgt_tile_name(latitude, longitude);
+        if let Some(elevation) = self.hgt_tile(&name).and_then(|tile| tile.elevation(latitude, longitude)) {
+            return Some(elevation);
+        }
+        self.geotiff_tiles().iter().find_map(|tile| tile.elevation(latitude, longitude))
+    }
+
+    pub fn point_elevation(&self, latitude: f64, longitude: f64) -> TerrainElevation {
+        TerrainElevation { latitude, longitude, elevation: self.elevation(latitude, longitude) }
+    }
+
+    // Samples each leg every step meters, vertices are always included, zero step gives only them
+    pub fn profile(&self, path: &[Geodetic], step: f32) -> TerrainProfile {
+        let total: f64 = path.windows(2).map(|leg| leg[0].distance_to(&leg[1])).sum();
+        let step = if step > 0.0 { (step as f64).max(total / MAX_PROFILE_POINTS as f64) } else { 0.0 };
+
+        let mut points = Vec::new();
+        let mut distance = 0.0;
+        for (index, vertex) in path.iter().enumerate() {
+            let next = match path.get(index + 1) {
+                Some(next) => next,
+                None => {
+                    points.push(self.profile_point(vertex.latitude, vertex.longitude, distance));
+                    break;
+                }
+            };
+            let length = vertex.distance_to(next);
+            let samples = if step > 0.0 { (length / step).ceil().max(1.0) as usize } else { 1 };
+            for sample in 0..samples {
+                let fraction = sample as f64 / samples as f64;
+                let point = vertex.intermediate(next, fraction);
+                points.push(self.profile_point(point.latitude, point.longitude, distance + length * fraction));
+            }
+            distance += length;
+        }
+
+        let elevations = points.iter().filter_map(|point| point.elevation);
+        TerrainProfile {
+            min_elevation: elevations.clone().reduce(f32::min),
+            max_elevation: elevations.reduce(f32::max),
+            points
+        }
+    }
+
+    pub fn convert(&self, position: &Geodetic, frame: GeodeticFrame, home: Option<&Geodetic>) -> Option<Geodetic> {
+        altitude::convert_altitude(position, frame, home, |latitude, longitude| self.elevation(latitude, longitude))
+    }
+
+    fn profile_point(&self, latitude: f64, longitude: f64, distance: f64) -> TerrainProfilePoint {
+        TerrainProfilePoint { latitude, longitude, distance, elevation: self.elevation(latitude, longitude) }
+    }
+
+    // Files are read without holding the cache, so loaded tiles stay available meanwhile
+    fn hgt_tile(&self, name: &str) -> Option<Arc<DemTile>> {
+        if let Some(tile) = self.cache.lock().unwrap().hgt.get(name) {
+            return tile.clone();
+        }
+        let tile = self.load_hgt(name);
+        self.cache.lock().unwrap().hgt.entry(name.into()).or_insert(tile).clone()
+    }
+
+    fn geotiff_tiles(&self) -> Vec<Arc<DemTile>> {
+        if let Some(tiles) = self.cache.lock().unwrap().geotiffs.as_ref() {
+            return tiles.clone();
+        }
+        let tiles = self.load_geotiffs();
+        self.cache.lock().unwrap().geotiffs.get_or_insert(tiles).clone()
+    }
+
+    fn load_hgt(&self, name: &str) -> Option<Arc<DemTile>> {
+        let path = self.directory.as_ref()?.join(name);
+        let bytes = std::fs::read(&path).ok()?;
+        match dem::parse_hgt(name, &bytes) {
+            Ok(tile) => Some(Arc::new(tile)),
+            Err(err) => {
+                log::warn!("Terrain tile {} error: {}", path.display(), err);
+                None
+            }
+        }
+    }
+
+    fn load_geotiffs(&self) -> Vec<Arc<DemTile>> {
+        let entries = match self.directory.as_ref().map(std::fs::read_dir) {
+            Some(Ok(entries)) => entries,
+            _ => return Vec::new()
+        };
+
+        let mut tiles = Vec::new();
+        for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+            let is_geotiff = path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| matches!(extension.to_lowercase().as_str(), "tif" | "tiff"));
+            if !is_geotiff {
+                continue;
+            }
+            match std::fs::read(&path).map_err(anyhow::Error::from).and_then(|bytes| dem::parse_geotiff(&bytes)) {
+                Ok(tile) => tiles.push(Arc::new(tile)),
+                Err(err) => log::warn!("Terrain tile {} error: {}", path.display(), err)
+            }
+        }
+        log::info!("Loaded {} GeoTIFF terrain tiles", tiles.len());
+        tiles
+    }
+}
@@ -0,0 +1,139 @@
+use test_case::test_case;
+
+use crate::models::spatial::{Geodetic, GeodeticFrame};
+use super::provider::Terrain;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/terrain");
+
+fn terrain() -> Terrain {
+    Terrain::new(FIXTURES)
+}
+
+fn at(latitude: f64, longitude: f64, altitude: f32, frame: GeodeticFrame) -> Geodetic {
+    Geodetic { latitude, longitude, altitude, frame }
+}
+
+fn home() -> Geodetic {
+    at(55.5, 37.0, 190.0, GeodeticFrame::Wgs84AboveSeaLevel)
+}
+
+#[test_case(55.5, 37.25, Some(200.0); "hgt tile")]
+#[test_case(55.975, 38.025, Some(306.0); "geotiff tile")]
+#[test_case(55.99, 38.5, None; "no tile")]
+#[test_case(-10.5, 20.5, None; "no hgt and geotiff")]
+fn test_elevation_from_directory(latitude: f64, longitude: f64, expected: Option<f32>) {
+    let terrain = terrain();
+
+    // Second query is served from the cache
+    for _ in 0..2 {
+        assert_eq!(terrain.elevation(latitude, longitude), expected);
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_queries() {
+    let terrain = terrain();
+
+    let (hgt, geotiff, other_hgt, none) = tokio::join!(
+        terrain.query(|terrain| terrain.elevation(55.5, 37.25)),
+        terrain.query(|terrain| terrain.elevation(55.975, 38.025)),
+        terrain.query(|terrain| terrain.elevation(55.5, 37.25)),
+        terrain.query(|terrain| terrain.elevation(55.99, 38.5))
+    );
+    assert_eq!(hgt.expect("Error querying terrain"), Some(200.0));
+    assert_eq!(geotiff.expect("Error querying terrain"), Some(306.0));
+    assert_eq!(other_hgt.expect("Error querying terrain"), Some(200.0));
+    assert_eq!(none.expect("Error querying terrain"), None);
+}
+
+#[test]
+fn test_missing_directory() {
+    let terrain = Terrain::new("/nonexistent/terrain");
+    assert_eq!(terrain.point_elevation(55.5, 37.25).elevation, None);
+}
+
+#[test]
+fn test_profile_samples_legs() {
+    let path = vec![
+        at(55.5, 37.1, 0.0, GeodeticFrame::None),
+        at(55.5, 37.3, 0.0, GeodeticFrame::None)
+    ];
+    let profile = terrain().profile(&path, 5000.0);
+
+    let elevations: Vec<f32> = profile.points.iter().map(|point| point.elevation.unwrap()).collect();
+    let expected = [185.0, 191.667, 198.333, 205.0];
+    assert_eq!(elevations.len(), expected.len());
+    for (elevation, expected) in elevations.iter().zip(expected) {
+        assert!((elevation - expected).abs() < 0.01, "elevation {} != {}", elevation, expected);
+    }
+
+    let length = path[0].distance_to(&path[1]);
+    assert_eq!(profile.points[0].distance, 0.0);
+    assert!((profile.points[3].distance - length).abs() < 1e-6);
+    assert_eq!(profile.points[3].longitude, 37.3);
+    assert_eq!((profile.min_elevation, profile.max_elevation), (Some(185.0), Some(205.0)));
+}
+
+#[test]
+fn test_profile_outside_tiles() {
+    let path = vec![
+        at(55.5, 37.1, 0.0, GeodeticFrame::None),
+        at(55.5, 36.9, 0.0, GeodeticFrame::None)
+    ];
+    let profile = terrain().profile(&path, 0.0);
+
+    assert_eq!(profile.points.len(), 2);
+    assert_eq!(profile.points[1].elevation, None);
+    assert_eq!((profile.min_elevation, profile.max_elevation), (Some(185.0), Some(185.0)));
+}
+
+#[test]
+fn test_profile_limits_points() {
+    let path = vec![
+        at(55.5, 37.1, 0.0, GeodeticFrame::None),
+        at(55.5, 37.3, 0.0, GeodeticFrame::None)
+    ];
+    let profile = terrain().profile(&path, 0.01);
+
+    assert!(profile.points.len() <= 10001, "points {}", profile.points.len());
+}
+
+#[test]
+fn test_empty_profile() {
+    let profile = terrain().profile(&[], 100.0);
+
+    assert!(profile.points.is_empty());
+    assert_eq!(profile.min_elevation, None);
+}
+
+#[test_case(GeodeticFrame::Wgs84AboveSeaLevel, 300.0, GeodeticFrame::Wgs84AboveTerrain, true, Some(100.0); "sea level to terrain")]
+#[test_case(GeodeticFrame::Wgs84AboveTerrain, 50.0, GeodeticFrame::Wgs84AboveSeaLevel, true, Some(250.0); "terrain to sea level")]
+#[test_case(GeodeticFrame::Wgs84AboveTerrain, 50.0, GeodeticFrame::Wgs84RelativeHome, true, Some(60.0); "terrain to home")]
+#[test_case(GeodeticFrame::Wgs84RelativeHome, 30.0, GeodeticFrame::Wgs84AboveSeaLevel, true, Some(220.0); "home to sea level")]
+#[test_case(GeodeticFrame::Wgs84RelativeHome, 30.0, GeodeticFrame::Wgs84AboveTerrain, true, Some(20.0); "home to terrain")]
+#[test_case(GeodeticFrame::Wgs84RelativeHome, 30.0, GeodeticFrame::Wgs84AboveSeaLevel, false, None; "no home")]
+#[test_case(GeodeticFrame::Wgs84RelativeHome, 30.0, GeodeticFrame::Wgs84RelativeHome, false, Some(30.0); "same frame")]
+#[test_case(GeodeticFrame::None, 30.0, GeodeticFrame::Wgs84AboveSeaLevel, true, None; "no frame")]
+fn test_convert_altitude(
+    frame: GeodeticFrame,
+    altitude: f32,
+    target: GeodeticFrame,
+    with_home: bool,
+    expected: Option<f32>
+) {
+    let home = home();
+    let position = at(55.5, 37.25, altitude, frame);
+    let converted = terrain().convert(&position, target.clone(), Some(&home).filter(|_| with_home));
+
+    assert_eq!(converted.as_ref().map(|converted| converted.altitude), expected);
+    if let Some(converted) = converted {
+        assert_eq!(converted.frame, target);
+        assert_eq!((converted.latitude, converted.longitude), (position.latitude, position.longitude));
+    }
+}
+
+#[test]
+fn test_convert_without_terrain() {
+    let position = at(55.99, 38.5, 100.0, GeodeticFrame::Wgs84AboveSeaLevel);
+    assert_eq!(terrain().convert(&position, GeodeticFrame::Wgs84AboveTerrain, Some(&home())), None);
+}